@@ -0,0 +1,416 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+
+#[program]
+pub mod lendana {
+    use super::*;
+
+    // ONE-TIME BOOTSTRAP OF THE ADMIN, TRUSTED ROLES, COUNTERS AND TIMELOCK BY THE UPGRADE AUTHORITY
+    pub fn bootstrap(ctx: Context<BootstrapProtocol>, admin_address: Pubkey, timelock_delay: i64) -> Result<()> {
+
+        instructions::admin_operations::bootstrap_protocol(ctx, admin_address, timelock_delay)?;
+        Ok(())
+    }
+
+    // OPEN AN ISOLATED MARKET THROUGH A MATURED PROPOSAL
+    pub fn create_market(ctx: Context<CreateMarket>, market_id: u64, name: String) -> Result<()> {
+
+        instructions::admin_operations::open_market(ctx, market_id, name)?;
+        Ok(())
+    }
+
+    // OPEN A CURATED MARKET, PERMISSIONLESSLY, BY POSTING THE CREATION DEPOSIT
+    pub fn create_curated_market(ctx: Context<CreateCuratedMarket>, market_id: u64, name: String, curator: Pubkey,
+        lending_durations: Vec<u64>) -> Result<()> {
+
+        instructions::market_operations::open_curated_market(ctx, market_id, name, curator, lending_durations)?;
+        Ok(())
+    }
+
+    // HAND A CURATED MARKET TO A NEW CURATOR
+    pub fn set_market_curator(ctx: Context<SetMarketCurator>, new_curator: Pubkey) -> Result<()> {
+
+        instructions::market_operations::change_market_curator(ctx, new_curator)?;
+        Ok(())
+    }
+
+    // CLOSE AN EMPTY CURATED MARKET, REFUNDING ITS CREATION DEPOSIT
+    pub fn close_curated_market(ctx: Context<CloseCuratedMarket>) -> Result<()> {
+
+        instructions::market_operations::close_market(ctx)?;
+        Ok(())
+    }
+
+    // QUEUE A PARAMETER CHANGE BEHIND THE TIMELOCK
+    pub fn queue_proposal(ctx: Context<QueueTimelockProposal>, action: GovernanceAction) -> Result<()> {
+
+        instructions::admin_operations::queue_timelock_proposal(ctx, action)?;
+        Ok(())
+    }
+
+    // CANCEL A QUEUED PARAMETER CHANGE
+    pub fn cancel_proposal(ctx: Context<CancelTimelockProposal>) -> Result<()> {
+
+        instructions::admin_operations::cancel_timelock_proposal(ctx)?;
+        Ok(())
+    }
+
+    // CURRENT ADMIN CREATES THE M-OF-N ADMIN MULTISIG
+    pub fn create_admin_multisig(ctx: Context<CreateAdminMultisig>, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+
+        instructions::multisig_operations::initialize_admin_multisig(ctx, owners, threshold)?;
+        Ok(())
+    }
+
+    // THE MULTISIG CHANGES ITS OWN OWNERS OR THRESHOLD
+    pub fn set_multisig_owners(ctx: Context<UpdateAdminMultisig>, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+
+        instructions::multisig_operations::update_admin_multisig(ctx, owners, threshold)?;
+        Ok(())
+    }
+
+    // A MULTISIG OWNER PROPOSES A LENDANA INSTRUCTION FOR THE MULTISIG TO SIGN
+    pub fn propose_multisig_transaction(ctx: Context<ProposeMultisigTransaction>, accounts: Vec<TransactionAccount>, data: Vec<u8>) -> Result<()> {
+
+        instructions::multisig_operations::create_multisig_transaction(ctx, accounts, data)?;
+        Ok(())
+    }
+
+    // A MULTISIG OWNER APPROVES A PROPOSED TRANSACTION
+    pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+
+        instructions::multisig_operations::sign_multisig_transaction(ctx)?;
+        Ok(())
+    }
+
+    // EXECUTE A MULTISIG TRANSACTION THAT REACHED ITS THRESHOLD
+    pub fn execute_multisig_transaction<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteMultisigTransaction<'info>>) -> Result<()> {
+
+        instructions::multisig_operations::run_multisig_transaction(ctx)?;
+        Ok(())
+    }
+
+    // SET UP OR RETUNE TOKEN-HOLDER LISTING GOVERNANCE THROUGH A MATURED PROPOSAL
+    pub fn configure_listing_governance(ctx: Context<ConfigureListingGovernance>, quorum: u64, voting_period: i64) -> Result<()> {
+
+        instructions::governance_operations::set_listing_governance(ctx, quorum, voting_period)?;
+        Ok(())
+    }
+
+    // ANYONE PROPOSES LISTING A TOKEN WITH ITS PRICE FEED AND RISK PARAMETERS
+    pub fn propose_listing(ctx: Context<ProposeListing>, price_feed_id: String, risk_parameters: RiskParameters,
+        isolation_debt_ceiling: Option<u64>) -> Result<()> {
+
+        instructions::governance_operations::propose_token_listing(ctx, price_feed_id, risk_parameters, isolation_debt_ceiling)?;
+        Ok(())
+    }
+
+    // LOCK GOVERNANCE TOKENS TO VOTE FOR OR AGAINST A LISTING
+    pub fn vote_on_listing<'info>(ctx: Context<'_, '_, '_, 'info, VoteOnListing<'info>>, support: bool, amount: u64) -> Result<()> {
+
+        instructions::governance_operations::cast_listing_vote(ctx, support, amount)?;
+        Ok(())
+    }
+
+    // TAKE BACK LOCKED GOVERNANCE TOKENS ONCE VOTING ENDS
+    pub fn withdraw_listing_vote<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawListingVote<'info>>) -> Result<()> {
+
+        instructions::governance_operations::withdraw_vote_weight(ctx)?;
+        Ok(())
+    }
+
+    // SETTLE A LISTING VOTE, QUEUEING A PASSED LISTING ON THE TIMELOCK
+    pub fn queue_listing(ctx: Context<QueueListing>) -> Result<()> {
+
+        instructions::governance_operations::settle_listing_vote(ctx)?;
+        Ok(())
+    }
+
+    // EXECUTE A QUEUED LISTING THROUGH WHITELIST_TOKEN, SET_RISK_CONFIG AND ADD_PRICE
+    pub fn execute_listing(ctx: Context<ExecuteListing>) -> Result<()> {
+
+        instructions::governance_operations::execute_token_listing(ctx)?;
+        Ok(())
+    }
+
+    // CHANGE THE TIMELOCK DELAY THROUGH A MATURED PROPOSAL
+    pub fn set_timelock_delay(ctx: Context<UpdateTimelockDelay>, delay: i64) -> Result<()> {
+
+        instructions::admin_operations::update_timelock_delay(ctx, delay)?;
+        Ok(())
+    }
+
+    // PROPOSE A NEW ADMIN, WHO MUST ACCEPT BEFORE TAKING OVER
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+
+        instructions::admin_operations::propose_admin_transfer(ctx, new_admin)?;
+        Ok(())
+    }
+
+    // CANCEL A PENDING ADMIN TRANSFER
+    pub fn cancel_admin_proposal(ctx: Context<TransferAdmin>) -> Result<()> {
+
+        instructions::admin_operations::cancel_admin_transfer(ctx)?;
+        Ok(())
+    }
+
+    // ACCEPT THE ADMIN ROLE AS THE PENDING ADMIN
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+
+        instructions::admin_operations::accept_admin_transfer(ctx)?;
+        Ok(())
+    }
+
+    // GRANT A ROLE TO AN ADDRESS
+    pub fn grant_role(ctx: Context<ManageRole>, role: Role, member: Pubkey) -> Result<()> {
+
+        instructions::admin_operations::grant_trusted_role(ctx, role, member)?;
+        Ok(())
+    }
+
+    // REVOKE A ROLE FROM AN ADDRESS
+    pub fn revoke_role(ctx: Context<ManageRole>, role: Role, member: Pubkey) -> Result<()> {
+
+        instructions::admin_operations::revoke_trusted_role(ctx, role, member)?;
+        Ok(())
+    }
+
+    // MOVE A ROLE FROM ONE ADDRESS TO ANOTHER
+    pub fn rotate_role(ctx: Context<ManageRole>, role: Role, previous_member: Pubkey, new_member: Pubkey) -> Result<()> {
+
+        instructions::admin_operations::rotate_trusted_role(ctx, role, previous_member, new_member)?;
+        Ok(())
+    }
+
+    // LIST THE ADDRESSES HOLDING A ROLE
+    pub fn list_role_members(ctx: Context<ListRoleMembers>, role: Role) -> Result<Vec<Pubkey>> {
+
+        instructions::admin_operations::list_trusted_role_members(ctx, role)
+    }
+
+    // WHITELIST A TOKEN BY THE WHITELISTER ROLE
+    pub fn whitelist_token(ctx: Context<WhitelistToken>, token_mint: Pubkey, isolation_debt_ceiling: Option<u64>) -> Result<()> {
+
+        instructions::admin_operations::token_whitelist(ctx, token_mint, isolation_debt_ceiling)?;
+        Ok(())
+    }
+
+    // WHITELISTER STARTS DELISTING A TOKEN, OPTIONALLY WINDING DOWN ITS MAX LTV
+    pub fn delist_token(ctx: Context<DelistToken>, token_mint: Pubkey, target_max_ltv_bps: Option<u64>, wind_down_duration: u64) -> Result<()> {
+
+        instructions::admin_operations::start_token_delisting(ctx, token_mint, target_max_ltv_bps, wind_down_duration)?;
+        Ok(())
+    }
+
+    // WHITELISTER REMOVES A FULLY WOUND DOWN TOKEN AND RECLAIMS ITS ACCOUNTS' RENT
+    pub fn finalize_delisting<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeTokenDelisting<'info>>) -> Result<()> {
+
+        instructions::admin_operations::finalize_token_delisting(ctx)?;
+        Ok(())
+    }
+
+    // ORACLE MANAGER ADDS A PRICE FEED FOR A WHITELISTED TOKEN
+    pub fn add_price(ctx: Context<AddTokenPriceMapping>, token_mint: Pubkey, price_feed_id: String) -> Result<()> {
+
+        instructions::admin_operations::add_token_prices(ctx, token_mint, price_feed_id)?;
+        Ok(())
+    }
+
+    // ORACLE MANAGER POINTS A TOKEN AT A NEW PRICE FEED
+    pub fn update_price(ctx: Context<ManageTokenPriceMapping>, token_mint: Pubkey, price_feed_id: String) -> Result<()> {
+
+        instructions::admin_operations::update_token_price_feed(ctx, token_mint, price_feed_id)?;
+        Ok(())
+    }
+
+    // ORACLE MANAGER CLEARS A TOKEN'S PRICE FEED
+    pub fn remove_price(ctx: Context<ManageTokenPriceMapping>, token_mint: Pubkey) -> Result<()> {
+
+        instructions::admin_operations::remove_token_price_feed(ctx, token_mint)?;
+        Ok(())
+    }
+
+    // RISK MANAGER SETS THE RISK PARAMETERS OF A TOKEN
+    pub fn set_risk_config(ctx: Context<SetTokenRiskConfig>, token_mint: Pubkey, risk_parameters: RiskParameters) -> Result<()> {
+
+        instructions::admin_operations::set_token_risk_config(ctx, token_mint, risk_parameters)?;
+        Ok(())
+    }
+
+    // RISK MANAGER PUTS A TOKEN IN OR OUT OF ISOLATION MODE
+    pub fn set_isolation_mode(ctx: Context<SetIsolationMode>, token_mint: Pubkey, isolation_debt_ceiling: Option<u64>) -> Result<()> {
+
+        instructions::admin_operations::update_isolation_mode(ctx, token_mint, isolation_debt_ceiling)?;
+        Ok(())
+    }
+
+    // RISK MANAGER CREATES OR UPDATES AN E-MODE CATEGORY
+    pub fn set_emode_category(ctx: Context<SetEModeCategory>, category_id: u8, name: String, emode_parameters: EModeParameters, token_mints: Vec<Pubkey>) -> Result<()> {
+
+        instructions::admin_operations::update_emode_category(ctx, category_id, name, emode_parameters, token_mints)?;
+        Ok(())
+    }
+
+    // PAUSER BLOCKS OR ALLOWS NEW LENDS AND BORROWS ACROSS THE PROTOCOL
+    pub fn set_protocol_pause(ctx: Context<SetProtocolPause>, paused: bool) -> Result<()> {
+
+        instructions::admin_operations::update_protocol_pause(ctx, paused)?;
+        Ok(())
+    }
+
+    // PAUSER BLOCKS OR ALLOWS NEW LENDS AND BORROWS OF A SINGLE TOKEN
+    pub fn set_token_pause(ctx: Context<SetTokenPause>, token_mint: Pubkey, paused: bool) -> Result<()> {
+
+        instructions::admin_operations::update_token_pause(ctx, token_mint, paused)?;
+        Ok(())
+    }
+
+    // WITHDRAW COLLECTED PROTOCOL FEES FROM A TOKEN TREASURY
+    pub fn withdraw_treasury<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawTreasury<'info>>, amount: u64) -> Result<()> {
+
+        instructions::admin_operations::withdraw_treasury_fees(ctx, amount)?;
+        Ok(())
+    }
+
+    // LEND A TOKEN
+    pub fn lend_token<'info>(ctx: Context<'_, '_, '_, 'info, LenderPositionInfo<'info>>, amount_to_lend: u64, loan_terms: LoanTerms) -> Result<()> {
+
+        instructions::lender_operations::create_lending_order(ctx, amount_to_lend, loan_terms)?;
+        Ok(())
+    }
+
+    // MODIFY LENDER POSITION
+    pub fn update_lender_position<'info>(ctx: Context<'_, '_, '_, 'info, ModifyLenderPosition<'info>>, new_loan_terms: LoanTerms, add_lending_amount: u64) -> Result<()> {
+
+        instructions::lender_operations::modify_lender_position(ctx, new_loan_terms, add_lending_amount)?;
+        Ok(())
+    }
+
+    // CANCEL LENDING ORDER
+    pub fn cancel_lend_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelLendingOrder<'info>>) -> Result<()> {
+
+        instructions::lender_operations::cancel_lending_order(ctx)?;
+        Ok(())
+    }
+
+    // BORROW A TOKEN
+    pub fn borrow_token<'info>(ctx: Context<'_, '_, '_, 'info, BorrowerPositionInfo<'info>>, collateral_token: Pubkey, borrowing_token: Pubkey, borrowing_amount: u64, loan_terms: LoanTerms) -> Result<()> {
+
+        instructions::borrower_operations::create_borrowing_order(ctx, collateral_token, borrowing_token, borrowing_amount, loan_terms)?;
+        Ok(())
+    }
+
+    // MODIFY BORROWER POSITION
+    pub fn update_borrower_position<'info>(ctx: Context<'_, '_, '_, 'info, ModifyBorrowerPosition<'info>>, new_borrowing_terms: LoanTerms, additional_borrow_amount: u64) -> Result<()> {
+
+        instructions::borrower_operations::modify_borrowing_order(ctx, new_borrowing_terms, additional_borrow_amount)?;
+        Ok(())
+    }
+
+    // CANCEL BORROW ORDER
+    pub fn cancel_borrow_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelBorrowOrder<'info>>) -> Result<()> {
+
+        instructions::borrower_operations::cancel_borrowing_order(ctx)?;
+        Ok(())
+    }
+
+    // LIQUIDATE AN UNHEALTHY OR EXPIRED BORROW POSITION
+    pub fn liquidate_position<'info>(ctx: Context<'_, '_, '_, 'info, LiquidateBorrowPosition<'info>>) -> Result<()> {
+
+        instructions::liquidator_operations::liquidate_borrow_position(ctx)?;
+        Ok(())
+    }
+
+    // DEPOSIT COLLATERAL INTO A MULTI-COLLATERAL OBLIGATION
+    pub fn deposit_collateral<'info>(ctx: Context<'_, '_, '_, 'info, DepositObligationCollateral<'info>>, amount: u64) -> Result<()> {
+
+        instructions::obligation_operations::deposit_obligation_collateral(ctx, amount)?;
+        Ok(())
+    }
+
+    // WITHDRAW COLLATERAL FROM AN OBLIGATION
+    pub fn withdraw_collateral<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawObligationCollateral<'info>>, amount: u64) -> Result<()> {
+
+        instructions::obligation_operations::withdraw_obligation_collateral(ctx, amount)?;
+        Ok(())
+    }
+
+    // BORROW AGAINST AN OBLIGATION'S COLLATERAL BASKET
+    pub fn borrow_from_obligation<'info>(ctx: Context<'_, '_, '_, 'info, BorrowAgainstObligation<'info>>, borrowing_amount: u64, loan_terms: LoanTerms) -> Result<()> {
+
+        instructions::obligation_operations::borrow_against_obligation(ctx, borrowing_amount, loan_terms)?;
+        Ok(())
+    }
+
+    // REPAY AN OBLIGATION'S DEBT
+    pub fn repay_obligation<'info>(ctx: Context<'_, '_, '_, 'info, RepayObligationDebt<'info>>, repay_amount: u64) -> Result<()> {
+
+        instructions::obligation_operations::repay_obligation_debt(ctx, repay_amount)?;
+        Ok(())
+    }
+
+    // LIQUIDATE AN UNHEALTHY OR EXPIRED OBLIGATION
+    pub fn liquidate_obligation_position<'info>(ctx: Context<'_, '_, '_, 'info, LiquidateObligation<'info>>, repay_amount: u64) -> Result<()> {
+
+        instructions::liquidator_operations::liquidate_obligation(ctx, repay_amount)?;
+        Ok(())
+    }
+
+    // DEPOSIT COLLATERAL INTO A CROSS-MARGINED ACCOUNT
+    pub fn margin_deposit<'info>(ctx: Context<'_, '_, '_, 'info, DepositMarginCollateral<'info>>, amount: u64) -> Result<()> {
+
+        instructions::margin_operations::deposit_margin_collateral(ctx, amount)?;
+        Ok(())
+    }
+
+    // WITHDRAW COLLATERAL FROM A MARGIN ACCOUNT
+    pub fn margin_withdraw<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawMarginCollateral<'info>>, amount: u64) -> Result<()> {
+
+        instructions::margin_operations::withdraw_margin_collateral(ctx, amount)?;
+        Ok(())
+    }
+
+    // BORROW A TOKEN AGAINST A MARGIN ACCOUNT
+    pub fn margin_borrow<'info>(ctx: Context<'_, '_, '_, 'info, BorrowFromMarginAccount<'info>>, borrowing_amount: u64, loan_terms: LoanTerms) -> Result<()> {
+
+        instructions::margin_operations::borrow_from_margin_account(ctx, borrowing_amount, loan_terms)?;
+        Ok(())
+    }
+
+    // REPAY ONE OF A MARGIN ACCOUNT'S BORROWS
+    pub fn margin_repay<'info>(ctx: Context<'_, '_, '_, 'info, RepayMarginDebt<'info>>, repay_amount: u64) -> Result<()> {
+
+        instructions::margin_operations::repay_margin_debt(ctx, repay_amount)?;
+        Ok(())
+    }
+
+    // LIQUIDATE AN UNHEALTHY OR EXPIRED MARGIN ACCOUNT
+    pub fn liquidate_margin_position<'info>(ctx: Context<'_, '_, '_, 'info, LiquidateMarginAccount<'info>>, repay_amount: u64) -> Result<()> {
+
+        instructions::liquidator_operations::liquidate_margin_account(ctx, repay_amount)?;
+        Ok(())
+    }
+
+    // FLASH BORROW A VAULT'S IDLE LIQUIDITY, REPAID BY FLASH REPAY LATER IN THE SAME TRANSACTION
+    pub fn flash_borrow<'info>(ctx: Context<'_, '_, '_, 'info, FlashBorrowFromVault<'info>>, amount: u64) -> Result<()> {
+
+        instructions::flash_loan_operations::flash_borrow_from_vault(ctx, amount)?;
+        Ok(())
+    }
+
+    // REPAY THE OUTSTANDING FLASH LOAN PLUS ITS FEE
+    pub fn flash_repay<'info>(ctx: Context<'_, '_, '_, 'info, FlashRepayToVault<'info>>) -> Result<()> {
+
+        instructions::flash_loan_operations::flash_repay_to_vault(ctx)?;
+        Ok(())
+    }
+
+    // MIGRATE ANY VERSIONED ACCOUNT, NAMED BY ITS TYPE, TO THE CURRENT LAYOUT
+    pub fn migrate_account<'info>(ctx: Context<'_, '_, '_, 'info, MigrateAccount<'info>>, kind: MigratableAccount) -> Result<()> {
+
+        instructions::migration_operations::migrate_account_layout(ctx, kind)?;
+        Ok(())
+    }
+}
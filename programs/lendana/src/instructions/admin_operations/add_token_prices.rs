@@ -1,12 +1,69 @@
 use anchor_lang::prelude::*;
 
 
-use crate::states::contexts::*;
+use crate::{states::{accounts::*, contexts::*, errors::*, events::*}, utils::{consume_matured_proposal, parse_price_feed_id, require_proposal_executor}};
 
 
+/*
+@note The Hex Feed ID Is Validated And Stored As 32 Bytes On The Token's Config, So It Is Never Re-Parsed When Pricing
+*/
 pub fn add_token_prices(ctx: Context<AddTokenPriceMapping>, token_mint: Pubkey, price_feed_id: String) -> Result<()> {
 
-    // Let's Call The Method
-    ctx.accounts.add_token_price_to_registry(token_mint, price_feed_id)?;
+    let price_feed_id = parse_price_feed_id(&price_feed_id)?;
+    require_proposal_executor(&ctx.accounts.timelock_proposal, &ctx.accounts.oracle_manager.key())?;
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::AddPriceFeed { market: ctx.accounts.market.key(), token_mint, price_feed_id })?;
+
+    // A Token Is Mapped To One Feed Only
+    let token_config = &mut ctx.accounts.token_config;
+    require!(token_config.price_feed_id.is_none(), LendanaError::TokenPriceAlreadyExists);
+    token_config.price_feed_id = Some(price_feed_id);
+
+    emit_cpi!(PriceFeedMapped {
+        token_mint,
+        price_feed_id,
+        oracle_manager: ctx.accounts.oracle_manager.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/*
+@note Points The Token At A New Feed, e.g. When Pyth Migrates One; Prices Read From Then On Use The New Feed
+*/
+pub fn update_token_price_feed(ctx: Context<ManageTokenPriceMapping>, token_mint: Pubkey, price_feed_id: String) -> Result<()> {
+
+    let price_feed_id = parse_price_feed_id(&price_feed_id)?;
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::UpdatePriceFeed { market: ctx.accounts.market.key(), token_mint, price_feed_id })?;
+
+    let token_config = &mut ctx.accounts.token_config;
+    let previous_price_feed_id = token_config.price_feed_id.ok_or(LendanaError::PriceFeedIDNotFound)?;
+    token_config.price_feed_id = Some(price_feed_id);
+
+    emit_cpi!(PriceFeedUpdated {
+        token_mint,
+        previous_price_feed_id,
+        price_feed_id,
+        oracle_manager: ctx.accounts.oracle_manager.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/*
+@note Until A New Feed Is Added, Anything That Prices The Token, Including Liquidations, Fails With PriceFeedIDNotFound
+*/
+pub fn remove_token_price_feed(ctx: Context<ManageTokenPriceMapping>, token_mint: Pubkey) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::RemovePriceFeed { market: ctx.accounts.market.key(), token_mint })?;
+
+    let token_config = &mut ctx.accounts.token_config;
+    let previous_price_feed_id = token_config.price_feed_id.take().ok_or(LendanaError::PriceFeedIDNotFound)?;
+
+    emit_cpi!(PriceFeedRemoved {
+        token_mint,
+        previous_price_feed_id,
+        oracle_manager: ctx.accounts.oracle_manager.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
-}
\ No newline at end of file
+}
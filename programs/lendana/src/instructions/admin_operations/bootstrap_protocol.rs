@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{constants::*, contexts::*, errors::*, events::*};
+
+
+/* BOOTSTRAP THE PROTOCOL
+1. Only the program's upgrade authority can call it, so nobody can front-run deployment and claim the admin role
+2. It runs once: every account it creates is `init`, so a second call fails
+3. The trusted roles registry starts empty; the admin grants every role afterwards, through the timelock
+4. `timelock_delay` is the initial delay of timelocked changes; later changes to it are timelocked too
+5. Markets, each with its own position counters, are opened afterwards by the admin */
+pub fn bootstrap_protocol(ctx: Context<BootstrapProtocol>, admin_address: Pubkey, timelock_delay: i64) -> Result<()> {
+
+    require!((0..=MAX_TIMELOCK_DELAY).contains(&timelock_delay), LendanaError::InvalidTimelockDelay);
+
+    // Initialize Admin Account
+    let admin_info = &mut ctx.accounts.admin_account;
+    admin_info.admin_address = admin_address;
+    admin_info.pending_admin = None;
+    admin_info.admin_bump = ctx.bumps.admin_account;
+    admin_info.version = ACCOUNT_VERSION;
+
+    // Initialize Global Trusted Roles
+    let global_trusted = &mut ctx.accounts.trusted_roles;
+    global_trusted.trusted_roles = Vec::new();
+    global_trusted.trusted_entities_bump = ctx.bumps.trusted_roles;
+    global_trusted.version = ACCOUNT_VERSION;
+
+    // Initializing Protocol State, Unpaused
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    protocol_state.paused = false;
+    protocol_state.protocol_state_bump = ctx.bumps.protocol_state;
+    protocol_state.version = ACCOUNT_VERSION;
+
+    // Initializing Timelock
+    let timelock = &mut ctx.accounts.timelock;
+    timelock.delay = timelock_delay;
+    timelock.next_proposal_id = 0;
+    timelock.timelock_bump = ctx.bumps.timelock;
+    timelock.version = ACCOUNT_VERSION;
+
+    emit_cpi!(ProtocolBootstrapped {
+        admin: admin_address,
+        upgrade_authority: ctx.accounts.upgrade_authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
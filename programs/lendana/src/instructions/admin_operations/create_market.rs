@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, contexts::*, errors::*, events::*}, utils::consume_matured_proposal};
+
+
+/* OPEN AN ISOLATED MARKET
+1. The admin opens it through a matured timelock proposal, under an id of their choosing
+2. Tokens are then listed, priced and risk-configured per market, and every escrow, vault, treasury, e-mode category
+and position is seeded under the market, so markets share neither liquidity nor risk
+3. Each market counts its own lender and borrower position ids
+4. Admin markets have no curator: the protocol's roles run them, offering every supported loan duration */
+pub fn open_market(ctx: Context<CreateMarket>, market_id: u64, name: String) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::CreateMarket { market_id, name: name.clone() })?;
+
+    require!(name.len() <= MAX_MARKET_NAME_LEN, LendanaError::InvalidMarketName);
+
+    ctx.accounts.market.set_inner(Market {
+        market_id,
+        name: name.clone(),
+        creator: ctx.accounts.admin.key(),
+        curator: None,
+        lending_durations: SUPPORTED_LENDING_DURATIONS.to_vec(),
+        creation_deposit: 0,
+        listed_tokens: 0,
+        is_closed: false,
+        market_bump: ctx.bumps.market,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    // Initializing The Market's Lender Position ID Counter
+    let lender_position_counter = &mut ctx.accounts.lenders_position_id_counter;
+    lender_position_counter.lenders_current_position_id = 0;
+    lender_position_counter.lender_position_id_bump = ctx.bumps.lenders_position_id_counter;
+    lender_position_counter.version = ACCOUNT_VERSION;
+
+    // Initializing The Market's Borrower Position ID Counter
+    let borrower_position_counter = &mut ctx.accounts.borrowers_position_id_counter;
+    borrower_position_counter.borrowers_current_position_id = 0;
+    borrower_position_counter.borrower_position_id_bump = ctx.bumps.borrowers_position_id_counter;
+    borrower_position_counter.version = ACCOUNT_VERSION;
+
+    emit_cpi!(MarketCreated {
+        market: ctx.accounts.market.key(),
+        market_id,
+        name,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
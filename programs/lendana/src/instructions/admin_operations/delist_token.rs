@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, contexts::*, errors::*, events::*}, utils::consume_matured_proposal};
+
+
+/* START DELISTING A TOKEN
+1. New lends and borrows of the token are blocked for good; existing loans run to maturity, repay or get liquidated,
+and lenders can still cancel unmatched orders
+2. Optionally lowers the token's max LTV linearly to `target_max_ltv_bps` over `wind_down_duration` seconds,
+shrinking how much can be borrowed against it as collateral */
+pub fn start_token_delisting(ctx: Context<DelistToken>, token_mint: Pubkey, target_max_ltv_bps: Option<u64>, wind_down_duration: u64) -> Result<()> {
+
+    consume_matured_proposal(
+        &mut ctx.accounts.timelock_proposal,
+        &GovernanceAction::DelistToken { market: ctx.accounts.market.key(), token_mint, target_max_ltv_bps, wind_down_duration },
+    )?;
+
+    ctx.accounts.token_escrow.is_delisted = true;
+
+    let now = Clock::get()?.unix_timestamp;
+    let token_config = &mut ctx.accounts.token_config;
+
+    if let Some(target_max_ltv_bps) = target_max_ltv_bps {
+        let start_max_ltv_bps = token_config.risk_parameters.max_ltv_bps;
+        if target_max_ltv_bps == 0 || target_max_ltv_bps > start_max_ltv_bps {
+            return Err(LendanaError::InvalidLtvWindDown.into());
+        }
+
+        token_config.ltv_wind_down = Some(LtvWindDown {
+            start_max_ltv_bps,
+            target_max_ltv_bps,
+            start_time: now,
+            end_time: now.checked_add(wind_down_duration as i64).ok_or(LendanaError::MathOverflow)?,
+        });
+    }
+
+    emit_cpi!(TokenDelistingStarted {
+        token_mint,
+        whitelister: ctx.accounts.whitelister_role.key(),
+        ltv_wind_down: ctx.accounts.token_config.ltv_wind_down,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/* FINALIZE A DELISTING
+1. Only once the escrow records nothing lent, borrowed or flash loaned in the token and its treasury fees have been withdrawn;
+vault balances are not checked, so tokens sent straight to a vault cannot hold finalization up
+2. Whatever is left in either vault, such as repaid interest or donations, is swept to whoever runs the market:
+the curator of a curated market, whose lenders and borrowers paid it, or the admin for admin markets
+3. Marks the token config as no longer whitelisted and closes the escrow, treasury and both vaults, returning their rent to the whitelister;
+the config is kept, so the token can be whitelisted again afterwards with its risk parameters and price feed */
+pub fn finalize_token_delisting<'info>(ctx: Context<'_, '_, '_, 'info, FinalizeTokenDelisting<'info>>) -> Result<()> {
+
+    let token_escrow = &ctx.accounts.token_escrow;
+    let wound_down = token_escrow.total_lent_tokens == 0
+        && token_escrow.total_borrowed_tokens == 0
+        && token_escrow.flash_loan_amount == 0
+        && token_escrow.treasury_fees == 0;
+    require!(wound_down, LendanaError::DelistingNotComplete);
+
+    let swept_amount = ctx.accounts.sweep_leftovers(ctx.remaining_accounts)?;
+    ctx.accounts.close_token_vaults()?;
+
+    let token_mint = ctx.accounts.mint_token.key();
+    ctx.accounts.token_config.is_whitelisted = false;
+    let market = &mut ctx.accounts.market;
+    market.listed_tokens = market.listed_tokens.checked_sub(1).ok_or(LendanaError::MathOverflow)?;
+
+    emit_cpi!(TokenDelistingFinalized {
+        token_mint,
+        whitelister: ctx.accounts.whitelister_role.key(),
+        swept_amount,
+        swept_to: ctx.accounts.leftovers_token_account.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, contexts::*, errors::*, events::*}, utils::{consume_matured_proposal, has_role, role_members}};
+
+
+/* GRANT A ROLE
+1. The admin grants one role to one address at a time; an address may hold several roles
+2. Granting the first Liquidator role turns liquidations from permissionless into allowlisted */
+pub fn grant_trusted_role(ctx: Context<ManageRole>, role: Role, member: Pubkey) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::GrantRole { role, member })?;
+
+    let trusted_roles = &mut ctx.accounts.trusted_roles;
+
+    require!(!has_role(trusted_roles, role, &member), LendanaError::RoleAlreadyGranted);
+    require!(trusted_roles.trusted_roles.len() < MAX_ROLE_ASSIGNMENTS, LendanaError::TooManyRoleAssignments);
+
+    trusted_roles.trusted_roles.push(RoleAssignment { role, member });
+
+    emit_cpi!(RoleGranted {
+        role,
+        member,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* REVOKE A ROLE
+1. Takes effect as soon as the matured proposal is executed: the next instruction gated by the role rejects the address */
+pub fn revoke_trusted_role(ctx: Context<ManageRole>, role: Role, member: Pubkey) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::RevokeRole { role, member })?;
+
+    let trusted_roles = &mut ctx.accounts.trusted_roles;
+
+    let assignment_index = trusted_roles.trusted_roles
+        .iter()
+        .position(|assignment| assignment.role == role && assignment.member == member)
+        .ok_or(LendanaError::RoleNotGranted)?;
+    trusted_roles.trusted_roles.remove(assignment_index);
+
+    emit_cpi!(RoleRevoked {
+        role,
+        member,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* ROTATE A ROLE
+1. Hands a role from one address to another in a single instruction, so a compromised key can be swapped out
+without a window where nobody holds the role */
+pub fn rotate_trusted_role(ctx: Context<ManageRole>, role: Role, previous_member: Pubkey, new_member: Pubkey) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::RotateRole { role, previous_member, new_member })?;
+
+    let trusted_roles = &mut ctx.accounts.trusted_roles;
+
+    require!(!has_role(trusted_roles, role, &new_member), LendanaError::RoleAlreadyGranted);
+
+    let assignment = trusted_roles.trusted_roles
+        .iter_mut()
+        .find(|assignment| assignment.role == role && assignment.member == previous_member)
+        .ok_or(LendanaError::RoleNotGranted)?;
+    assignment.member = new_member;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    emit_cpi!(RoleRevoked {
+        role,
+        member: previous_member,
+        admin: ctx.accounts.admin.key(),
+        timestamp,
+    });
+
+    emit_cpi!(RoleGranted {
+        role,
+        member: new_member,
+        admin: ctx.accounts.admin.key(),
+        timestamp,
+    });
+
+    Ok(())
+}
+
+/* LIST THE MEMBERS OF A ROLE
+1. Read-only; the addresses come back as the instruction's return data */
+pub fn list_trusted_role_members(ctx: Context<ListRoleMembers>, role: Role) -> Result<Vec<Pubkey>> {
+
+    Ok(role_members(&ctx.accounts.trusted_roles, role))
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, contexts::*, errors::*, events::*}, utils::{can_propose, consume_matured_proposal, governance_action_hash}};
+
+
+/* QUEUE A TIMELOCKED CHANGE
+1. The admin queues admin changes; whitelisting, price feed and risk changes are queued by the role that makes them,
+or by the curator of a curated market, passing that market
+2. The change can be executed once the delay in force now has passed, by calling its instruction with this proposal,
+and expires a grace period after that
+3. Pauses are not timelocked and never need a proposal */
+pub fn queue_timelock_proposal(ctx: Context<QueueTimelockProposal>, action: GovernanceAction) -> Result<()> {
+
+    let proposer = ctx.accounts.proposer.key();
+    require!(
+        can_propose(&action, &ctx.accounts.trusted_roles, &ctx.accounts.admin_account, ctx.accounts.market.as_deref(), &proposer),
+        LendanaError::NotAllowedToPropose
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let timelock = &mut ctx.accounts.timelock;
+    let proposal_id = timelock.next_proposal_id;
+    let eta = now.checked_add(timelock.delay).ok_or(LendanaError::MathOverflow)?;
+    timelock.next_proposal_id = proposal_id.checked_add(1).ok_or(LendanaError::MathOverflow)?;
+
+    ctx.accounts.timelock_proposal.set_inner(TimelockProposal {
+        proposal_id,
+        proposer,
+        action_hash: governance_action_hash(&action)?,
+        queued_at: now,
+        eta,
+        status: ProposalStatus::Queued,
+        proposal_bump: ctx.bumps.timelock_proposal,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    emit_cpi!(TimelockProposalQueued {
+        proposal_id,
+        proposer,
+        action,
+        eta,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/* CANCEL A QUEUED CHANGE
+1. Either the proposer or the admin can cancel, as long as it has not been executed */
+pub fn cancel_timelock_proposal(ctx: Context<CancelTimelockProposal>) -> Result<()> {
+
+    let timelock_proposal = &mut ctx.accounts.timelock_proposal;
+    require!(timelock_proposal.status == ProposalStatus::Queued, LendanaError::ProposalNotQueued);
+    timelock_proposal.status = ProposalStatus::Cancelled;
+
+    emit_cpi!(TimelockProposalCancelled {
+        proposal_id: timelock_proposal.proposal_id,
+        canceller: ctx.accounts.canceller.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* CHANGE THE TIMELOCK DELAY
+1. Only applies to proposals queued afterwards; already queued proposals keep their execution time */
+pub fn update_timelock_delay(ctx: Context<UpdateTimelockDelay>, delay: i64) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::SetTimelockDelay { delay })?;
+    require!((0..=MAX_TIMELOCK_DELAY).contains(&delay), LendanaError::InvalidTimelockDelay);
+
+    let timelock = &mut ctx.accounts.timelock;
+    let previous_delay = timelock.delay;
+    timelock.delay = delay;
+
+    emit_cpi!(TimelockDelayUpdated {
+        previous_delay,
+        delay,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
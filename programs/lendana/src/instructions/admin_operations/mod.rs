@@ -1,13 +1,27 @@
-pub mod initialize_whitelister;
-pub mod admin_initialize;
-pub mod initialize_trusted_roles;
-pub mod init_tokens_registry_prices_and_counters;
+pub mod bootstrap_protocol;
 pub mod token_whitelist;
 pub mod add_token_prices;
+pub mod set_token_risk_config;
+pub mod update_isolation_mode;
+pub mod update_emode_category;
+pub mod withdraw_treasury_fees;
+pub mod transfer_admin;
+pub mod manage_roles;
+pub mod update_pause;
+pub mod delist_token;
+pub mod manage_timelock;
+pub mod create_market;
 
-pub use admin_initialize::*;
-pub use initialize_whitelister::*;
-pub use initialize_trusted_roles::*;
-pub use init_tokens_registry_prices_and_counters::*;
+pub use bootstrap_protocol::*;
 pub use token_whitelist::*;
-pub use add_token_prices::*;
\ No newline at end of file
+pub use add_token_prices::*;
+pub use set_token_risk_config::*;
+pub use update_isolation_mode::*;
+pub use update_emode_category::*;
+pub use withdraw_treasury_fees::*;
+pub use transfer_admin::*;
+pub use manage_roles::*;
+pub use update_pause::*;
+pub use delist_token::*;
+pub use manage_timelock::*;
+pub use create_market::*;
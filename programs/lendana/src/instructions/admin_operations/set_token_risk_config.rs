@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, contexts::*, events::*}, utils::{consume_matured_proposal, require_proposal_executor}, validate_risk_parameters};
+
+
+pub fn set_token_risk_config(ctx: Context<SetTokenRiskConfig>, token_mint: Pubkey, risk_parameters: RiskParameters) -> Result<()> {
+
+    require_proposal_executor(&ctx.accounts.timelock_proposal, &ctx.accounts.risk_manager_role.key())?;
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::SetRiskConfig { market: ctx.accounts.market.key(), token_mint, risk_parameters })?;
+
+    // Validate Bounds Before Storing
+    validate_risk_parameters::validate_risk_parameters(&risk_parameters)?;
+
+    let token_config = &mut ctx.accounts.token_config;
+    // Whitelist Status, Price Feed, Escrow Pointers And Any Wind-Down Belong To Other Roles And Are Left As They Are
+    token_config.market = ctx.accounts.market.key();
+    token_config.token_mint = token_mint;
+    token_config.decimals = ctx.accounts.mint_token.decimals;
+    token_config.risk_parameters = risk_parameters;
+    token_config.token_config_bump = ctx.bumps.token_config;
+    token_config.version = ACCOUNT_VERSION;
+
+    emit_cpi!(RiskConfigUpdated {
+        token_mint,
+        risk_parameters,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,22 @@
 use anchor_lang::prelude::*;
 
-use crate::{states::contexts::*, LentBorrowedTokenEscrow};
+use crate::{states::{accounts::*, constants::*, contexts::*, errors::*, events::*}, utils::{consume_matured_proposal, require_proposal_executor, validate_mint_extensions}};
 
 
-pub fn token_whitelist(ctx: Context<WhitelistToken>, token_mint: Pubkey) -> Result<()> {
+/*
+@note Passing An Isolation Debt Ceiling Lists The Token In Isolation Mode: it can only back borrows of
+tokens allowed in isolation, up to that much USD debt in total
+@note Token-2022 Mints With A Permanent Delegate, Non-Transferable Or Confidential Balances Are Rejected
+*/
+pub fn token_whitelist(ctx: Context<WhitelistToken>, token_mint: Pubkey, isolation_debt_ceiling: Option<u64>) -> Result<()> {
+
+    require_proposal_executor(&ctx.accounts.timelock_proposal, &ctx.accounts.whitelister_role.key())?;
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::WhitelistToken { market: ctx.accounts.market.key(), token_mint, isolation_debt_ceiling })?;
+
+    validate_mint_extensions(&ctx.accounts.mint_token.to_account_info())?;
+
+    let market = &mut ctx.accounts.market;
+    market.listed_tokens = market.listed_tokens.checked_add(1).ok_or(LendanaError::MathOverflow)?;
 
     // Set the lent token vault details
     let lent_tokens = &mut ctx.accounts.token_escrow;
@@ -12,10 +25,43 @@ pub fn token_whitelist(ctx: Context<WhitelistToken>, token_mint: Pubkey) -> Resu
         total_lent_tokens: 0,
         total_borrowed_tokens: 0,
         is_active: true,
-        token_vault_bump: ctx.bumps.token_escrow
+        is_delisted: false,
+        token_vault_bump: ctx.bumps.token_escrow,
+        isolation_mode: isolation_debt_ceiling.is_some(),
+        isolation_debt_ceiling: isolation_debt_ceiling.unwrap_or(0),
+        isolation_debt: 0,
+        treasury_fees: 0,
+        flash_loan_amount: 0,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
     });
 
-    let all_tokens = &mut ctx.accounts.all_whitelisted_tokens;
-    all_tokens.tokens_whitelisted.push(token_mint);
+    // Set the token treasury details
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.set_inner(TokenTreasury {
+        token_mint: ctx.accounts.mint_token.key(),
+        treasury_bump: ctx.bumps.treasury,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    // List The Token On Its Config; Risk Parameters And Price Feed Set Earlier Are Kept
+    let token_config = &mut ctx.accounts.token_config;
+    token_config.market = ctx.accounts.market.key();
+    token_config.token_mint = token_mint;
+    token_config.is_whitelisted = true;
+    token_config.decimals = ctx.accounts.mint_token.decimals;
+    token_config.ltv_wind_down = None;
+    token_config.token_escrow = ctx.accounts.token_escrow.key();
+    token_config.treasury = ctx.accounts.treasury.key();
+    token_config.token_config_bump = ctx.bumps.token_config;
+    token_config.version = ACCOUNT_VERSION;
+
+    emit_cpi!(TokenWhitelisted {
+        token_mint,
+        whitelister: ctx.accounts.whitelister_role.key(),
+        isolation_debt_ceiling,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, contexts::*, errors::*, events::*}, utils::consume_matured_proposal};
+
+
+/* PROPOSE A NEW ADMIN
+1. The current admin only nominates the next one; the role moves once the nominee accepts
+2. Proposing again replaces any earlier nominee
+3. Needs a matured timelock proposal; cancelling a nominee does not */
+pub fn propose_admin_transfer(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::ProposeAdmin { new_admin })?;
+
+    ctx.accounts.admin_account.pending_admin = Some(new_admin);
+
+    emit_cpi!(AdminTransferProposed {
+        admin: ctx.accounts.admin.key(),
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* CANCEL A PENDING ADMIN TRANSFER */
+pub fn cancel_admin_transfer(ctx: Context<TransferAdmin>) -> Result<()> {
+
+    let cancelled_admin = ctx.accounts.admin_account.pending_admin
+        .take()
+        .ok_or(LendanaError::NoPendingAdmin)?;
+
+    emit_cpi!(AdminTransferCancelled {
+        admin: ctx.accounts.admin.key(),
+        cancelled_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* ACCEPT THE ADMIN ROLE
+1. Only the pending admin can accept, which proves the new key is controlled before the old one loses the role */
+pub fn accept_admin_transfer(ctx: Context<AcceptAdmin>) -> Result<()> {
+
+    let admin_account = &mut ctx.accounts.admin_account;
+    let previous_admin = admin_account.admin_address;
+
+    admin_account.admin_address = ctx.accounts.pending_admin.key();
+    admin_account.pending_admin = None;
+
+    emit_cpi!(AdminTransferred {
+        previous_admin,
+        new_admin: ctx.accounts.pending_admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
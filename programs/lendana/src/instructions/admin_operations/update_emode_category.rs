@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, contexts::*, constants::*, errors::*, events::*}, utils::consume_matured_proposal, validate_risk_parameters};
+
+
+/*
+@note Category 0 Is Reserved For Positions Outside E-Mode
+@note Removing A Token From A Category Moves Its Open Positions Back To The Collateral Token's Own Parameters
+*/
+pub fn update_emode_category(ctx: Context<SetEModeCategory>, category_id: u8, name: String,
+    emode_parameters: EModeParameters, token_mints: Vec<Pubkey>) -> Result<()> {
+
+    consume_matured_proposal(
+        &mut ctx.accounts.timelock_proposal,
+        &GovernanceAction::SetEModeCategory { market: ctx.accounts.market.key(), category_id, name: name.clone(), emode_parameters, token_mints: token_mints.clone() },
+    )?;
+
+    require!(category_id > 0, LendanaError::InvalidEModeCategory);
+    require!(name.len() <= MAX_EMODE_NAME_LEN, LendanaError::EModeNameTooLong);
+    require!(token_mints.len() <= MAX_EMODE_CATEGORY_TOKENS, LendanaError::TooManyEModeTokens);
+
+    // Validate Bounds Before Storing
+    validate_risk_parameters::validate_emode_parameters(&emode_parameters)?;
+
+    let emode_category = &mut ctx.accounts.emode_category;
+    emode_category.set_inner(EModeCategory {
+        category_id,
+        name: name.clone(),
+        emode_parameters,
+        token_mints: token_mints.clone(),
+        emode_bump: ctx.bumps.emode_category,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    emit_cpi!(EModeCategoryUpdated {
+        category_id,
+        name,
+        emode_parameters,
+        token_mints,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, contexts::*, events::*}, utils::consume_matured_proposal};
+
+
+/*
+@note Some(ceiling) Puts The Token In Isolation Mode Or Updates Its Ceiling; None Lifts Isolation Mode
+@note Lowering The Ceiling Below The Current Isolated Debt Only Blocks New Borrows Against The Token
+*/
+pub fn update_isolation_mode(ctx: Context<SetIsolationMode>, token_mint: Pubkey, isolation_debt_ceiling: Option<u64>) -> Result<()> {
+
+    consume_matured_proposal(&mut ctx.accounts.timelock_proposal, &GovernanceAction::SetIsolationMode { market: ctx.accounts.market.key(), token_mint, isolation_debt_ceiling })?;
+
+    let token_escrow = &mut ctx.accounts.token_escrow;
+    token_escrow.isolation_mode = isolation_debt_ceiling.is_some();
+    token_escrow.isolation_debt_ceiling = isolation_debt_ceiling.unwrap_or(0);
+
+    emit_cpi!(IsolationModeUpdated {
+        token_mint,
+        isolation_debt_ceiling,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
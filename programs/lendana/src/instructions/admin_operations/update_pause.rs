@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, events::*};
+
+
+/* PAUSE OR UNPAUSE THE PROTOCOL
+1. Takes effect immediately and only blocks new lends and borrows, including flash loans
+2. Repayments, collateral top-ups and withdrawals, cancels and liquidations keep working so positions can unwind */
+pub fn update_protocol_pause(ctx: Context<SetProtocolPause>, paused: bool) -> Result<()> {
+
+    ctx.accounts.protocol_state.paused = paused;
+
+    emit_cpi!(ProtocolPauseUpdated {
+        pauser: ctx.accounts.pauser.key(),
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* PAUSE OR UNPAUSE A SINGLE TOKEN
+1. Same scope as the protocol pause, limited to lending or borrowing this token */
+pub fn update_token_pause(ctx: Context<SetTokenPause>, token_mint: Pubkey, paused: bool) -> Result<()> {
+
+    ctx.accounts.token_escrow.is_active = !paused;
+
+    emit_cpi!(TokenPauseUpdated {
+        pauser: ctx.accounts.pauser.key(),
+        token_mint,
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{contexts::*, errors::*, events::*};
+
+
+/*
+@note Only Fees Recorded On The Token Escrow Can Be Withdrawn, To Any Token Account Of The Mint
+*/
+pub fn withdraw_treasury_fees<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawTreasury<'info>>, amount: u64) -> Result<()> {
+
+    require!(amount > 0, LendanaError::ZeroAmount);
+    require!(amount <= ctx.accounts.token_escrow.treasury_fees, LendanaError::InsufficientTreasuryFees);
+
+    ctx.accounts.transfer_from_treasury(amount, ctx.remaining_accounts)?;
+
+    let token_escrow = &mut ctx.accounts.token_escrow;
+    token_escrow.treasury_fees -= amount;
+
+    emit_cpi!(TreasuryWithdrawn {
+        token_mint: ctx.accounts.token_mint.key(),
+        recipient: ctx.accounts.recipient_token_account.key(),
+        amount,
+        remaining_fees: ctx.accounts.token_escrow.treasury_fees,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,32 +1,43 @@
 
 use anchor_lang::prelude::*;
 
-use crate::states::{contexts::*, errors::*};
+use crate::{states::{contexts::*, errors::*, events::*}, release_isolated_debt, NATIVE_SOL_MINT_ADDRESS};
 
 
 
-pub fn cancel_borrowing_order(ctx: Context<CancelBorrowOrder>) -> Result<()> {
+pub fn cancel_borrowing_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelBorrowOrder<'info>>) -> Result<()> {
 
     // Ensure Order Is Not Matched
-    require!(ctx.accounts.borrower_position.is_matched == false, LendanaError::OrderAlreadyMatched);
+    require!(!ctx.accounts.borrower_position.is_matched, LendanaError::OrderAlreadyMatched);
 
     // Refund Borrowed Tokens Back Into The Vault
-    ctx.accounts.refund_tokens_to_borrow_vault()?;
+    ctx.accounts.refund_tokens_to_borrow_vault(ctx.remaining_accounts)?;
 
     // Refund Borrower's Collateral
-    ctx.accounts.unlock_borrower_collateral()?;
+    ctx.accounts.unlock_borrower_collateral(ctx.remaining_accounts)?;
 
-    // Update Collateral and Borrowing Token Escrows
+    // Update Collateral and Borrowing Token Escrows: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
     let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
-    collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
-        .checked_sub(ctx.accounts.borrower_position.collateral_amount)
-        .ok_or(LendanaError::InsufficientLentTokens)?;
+    if ctx.accounts.token_collateral.key() != NATIVE_SOL_MINT_ADDRESS {
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_sub(ctx.accounts.borrower_position.collateral_amount)
+            .ok_or(LendanaError::InsufficientLentTokens)?;
+    }
+    release_isolated_debt(collateral_token_escrow, ctx.accounts.borrower_position.isolated_debt);
     
     let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
     borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
         .checked_sub(ctx.accounts.borrower_position.borrowing_amount)
         .ok_or(LendanaError::InsufficientBorrowedTokens)?;
 
+    emit_cpi!(BorrowOrderCancelled {
+        borrower: ctx.accounts.borrower.key(),
+        borrower_position_id: ctx.accounts.borrower_position.borrower_position_id,
+        returned_borrow_amount: ctx.accounts.borrower_position.borrowing_amount,
+        refunded_collateral_amount: ctx.accounts.borrower_position.collateral_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     // Update Borrower Position
     let borrower_position = &mut ctx.accounts.borrower_position;
     borrower_position.borrowing_amount = 0;
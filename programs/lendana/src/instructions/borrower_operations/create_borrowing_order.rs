@@ -2,25 +2,44 @@
 
 use anchor_lang::prelude::*;
 
-use crate::{states::{accounts::*, contexts::*, errors::*}, utils::validate_loan_terms, LoanTerms, NATIVE_SOL_MINT_ADDRESS};
+use crate::{states::{accounts::*, contexts::*, errors::*, events::*}, utils::{validate_loan_terms, ensure_open_for_orders, ensure_within_cap, require_emode_membership, get_mint_price, origination_fee, record_treasury_fees, required_sol_escrow, bind_sol_escrow}, LoanTerms, ACCOUNT_VERSION, NATIVE_SOL_MINT_ADDRESS};
 
 
 /* CREATE BORROWING ORDER
 1. Need to ensure that the ltv is at least 80% */
-pub fn create_borrowing_order(ctx: Context<BorrowerPositionInfo>, collateral_token: Pubkey, borrowing_token: Pubkey, borrowing_amount: u64, loan_terms: LoanTerms) 
+pub fn create_borrowing_order<'info>(ctx: Context<'_, '_, '_, 'info, BorrowerPositionInfo<'info>>, collateral_token: Pubkey, borrowing_token: Pubkey, borrowing_amount: u64, loan_terms: LoanTerms) 
 -> Result<()> {
 
     // Non-Zero Amount To Borrow
     require!(borrowing_amount > 0, LendanaError::ZeroAmount);
 
     // Validate Loan Terms
-    validate_loan_terms::validate_loan(loan_terms)?;
+    validate_loan_terms::validate_loan(loan_terms, &ctx.accounts.market)?;
+
+    // Opting Into E-Mode Requires Both Tokens To Be In The Category
+    let emode_category = require_emode_membership(ctx.accounts.emode_category.as_deref(), &collateral_token, &borrowing_token)?;
+
+    // No New Borrows While The Protocol Or The Borrowed Token Is Paused Or Delisting
+    ensure_open_for_orders(&ctx.accounts.protocol_state, &ctx.accounts.borrowing_token_escrow)?;
+
+    // Respect The Borrowing Token Borrow Cap
+    ensure_within_cap(
+        ctx.accounts.borrowing_token_escrow.total_borrowed_tokens,
+        borrowing_amount,
+        ctx.accounts.borrowing_token_config.risk_parameters.borrow_cap,
+        LendanaError::BorrowCapExceeded,
+    )?;
+
+    // Count The Borrow Against The Collateral's Isolation Debt Ceiling, If Isolated
+    let isolated_debt = ctx.accounts.track_isolated_debt(borrowing_amount)?;
 
     // Lock Borrower's Collateral
-    let collateral_amount = ctx.accounts.lock_borrower_collateral(borrowing_amount)?;
+    let collateral_amount = ctx.accounts.lock_borrower_collateral(borrowing_amount, ctx.remaining_accounts)?;
 
-    // Transfer Borrow Tokens To Borrower
-    ctx.accounts.transfer_tokens_to_borrower(borrowing_amount)?;
+    // Transfer Borrow Tokens To Borrower, Less The Origination Fee Which Goes To The Treasury
+    let origination_fee = origination_fee(borrowing_amount, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.transfer_tokens_to_borrower(borrowing_amount - origination_fee, ctx.remaining_accounts)?;
+    let treasury_credit = ctx.accounts.transfer_origination_fee_to_treasury(origination_fee, ctx.remaining_accounts)?;
 
     // Get Borrower Position and Position Counter
     let borrower_position = &mut ctx.accounts.borrower_position;
@@ -33,9 +52,10 @@ pub fn create_borrowing_order(ctx: Context<BorrowerPositionInfo>, collateral_tok
     borrower_postion_counter.borrowers_current_position_id += 1;
 
     // Create Borrower Position
+    let timestamp = Clock::get()?.unix_timestamp;
     borrower_position.set_inner(BorrowerPosition {
-        collateral_token: collateral_token,
-        borrowing_token: borrowing_token,
+        collateral_token,
+        borrowing_token,
         collateral_amount,
         borrower_pubkey: ctx.accounts.borrower.key(),
         borrowing_amount,
@@ -43,8 +63,12 @@ pub fn create_borrowing_order(ctx: Context<BorrowerPositionInfo>, collateral_tok
         borrowing_terms: loan_terms,
         is_position_active: true,
         is_matched: false,
-        borrowing_start: Clock::get()?.unix_timestamp,
+        borrowing_start: timestamp,
         borrower_position_bump: ctx.bumps.borrower_position,
+        isolated_debt,
+        emode_category,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
     });
 
     // Update Borrowing Token Escrow
@@ -54,18 +78,38 @@ pub fn create_borrowing_order(ctx: Context<BorrowerPositionInfo>, collateral_tok
         .checked_add(borrowing_amount)
         .ok_or(LendanaError::TokenAdditionOverflow)?;
 
-    // Update Collateral Token Escrow: could be a SOL Collateral or Collateral Token
+    record_treasury_fees(borrowing_token_escrow, treasury_credit)?;
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
     if collateral_token == NATIVE_SOL_MINT_ADDRESS {
-        let sol_collateral_vault = &mut ctx.accounts.sol_collateral_vault;
-        sol_collateral_vault.vault_balance = sol_collateral_vault.vault_balance
-            .checked_add(collateral_amount)
-            .ok_or(LendanaError::TokenAdditionOverflow)?;
+        let position_key = ctx.accounts.borrower_position.key();
+        let sol_collateral_escrow = required_sol_escrow(&mut ctx.accounts.sol_collateral_escrow)?;
+        bind_sol_escrow(sol_collateral_escrow, position_key, ctx.accounts.borrower.key(), ctx.bumps.sol_collateral_escrow.unwrap_or_default());
     } else {
         let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
         collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
             .checked_add(collateral_amount)
             .ok_or(LendanaError::TokenAdditionOverflow)?;
     }
-    
+
+    // Prices Used To Size The Collateral
+    let collateral_price = get_mint_price(&ctx.accounts.collateral_token_config, &ctx.accounts.collateral_price_update, &ctx.accounts.token_collateral.to_account_info())?;
+    let borrowing_price = get_mint_price(&ctx.accounts.borrowing_token_config, &ctx.accounts.borrowing_price_update, &ctx.accounts.token_to_borrow.to_account_info())?;
+
+    emit_cpi!(BorrowOrderCreated {
+        borrower: ctx.accounts.borrower.key(),
+        borrower_position_id,
+        collateral_token,
+        borrowing_token,
+        collateral_amount,
+        borrowing_amount,
+        borrowing_terms: loan_terms,
+        emode_category,
+        collateral_price: collateral_price.into(),
+        borrowing_price: borrowing_price.into(),
+        origination_fee,
+        timestamp,
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 
 use anchor_lang::prelude::*;
 
-use crate::{states::{contexts::*, constants::*, errors::*}, validate_loan_terms, LoanTerms};
+use crate::{states::{contexts::*, constants::*, errors::*, events::*}, validate_loan_terms, ensure_open_for_orders, ensure_within_cap, require_position_emode, get_mint_price, origination_fee, record_treasury_fees, LoanTerms};
 
 
 
@@ -13,28 +13,57 @@ use crate::{states::{contexts::*, constants::*, errors::*}, validate_loan_terms,
 @note If Changing both loan terms and topping up lending amount, set new values according to standard
 */
 
-pub fn modify_borrowing_order(ctx: Context<ModifyBorrowerPosition>, new_loan_terms: LoanTerms, additional_borrow_amount: u64) -> Result<()> {
+pub fn modify_borrowing_order<'info>(ctx: Context<'_, '_, '_, 'info, ModifyBorrowerPosition<'info>>, new_loan_terms: LoanTerms, additional_borrow_amount: u64) -> Result<()> {
 
     // Get Borrower Position For Validation And Checks
     let borrower_position = &ctx.accounts.borrower_position;
 
     let mut new_collateral_to_lock = 0;
+    let mut new_isolated_debt = 0;
+    let mut prices_used = None;
+    let mut fee_charged = 0;
+    let mut treasury_credit = 0;
 
     // First Ensure Borrowing Order Is Not Matched
-    require!(borrower_position.is_matched == false, LendanaError::OrderAlreadyMatched);
+    require!(!borrower_position.is_matched, LendanaError::OrderAlreadyMatched);
+
+    // Additional Collateral Is Sized With The Same E-Mode Category The Position Was Opened In
+    require_position_emode(borrower_position.emode_category, ctx.accounts.emode_category.as_deref())?;
 
     // If Modifying Loan Terms, We Validate The New Loan Terms. If Not, We Just Skip It Due To Previous Validation
     if borrower_position.borrowing_terms != new_loan_terms {
-        validate_loan_terms::validate_loan(new_loan_terms)?;
+        validate_loan_terms::validate_loan(new_loan_terms, &ctx.accounts.market)?;
     }
 
     // If Borrowing More Tokens, Lock Additional Collateral Based On New Borrowing Amount, transfer the new tokens to the Borrower And Update Borrower Position
     if additional_borrow_amount > 0 {
-        // lock Additional Collateral
-        new_collateral_to_lock = ctx.accounts.lock_borrower_collateral(additional_borrow_amount)?;
+        // No New Borrows While The Protocol Or The Borrowed Token Is Paused Or Delisting
+        ensure_open_for_orders(&ctx.accounts.protocol_state, &ctx.accounts.borrowing_token_escrow)?;
+
+        // Respect The Borrowing Token Borrow Cap
+        ensure_within_cap(
+            ctx.accounts.borrowing_token_escrow.total_borrowed_tokens,
+            additional_borrow_amount,
+            ctx.accounts.borrowing_token_config.risk_parameters.borrow_cap,
+            LendanaError::BorrowCapExceeded,
+        )?;
+
+        // Count The Additional Borrow Against The Collateral's Isolation Debt Ceiling, If Isolated
+        new_isolated_debt = ctx.accounts.track_isolated_debt(additional_borrow_amount)?;
 
-        // Transfer New Borrow Tokens To Borrower
-        ctx.accounts.transfer_tokens_to_borrower(additional_borrow_amount)?;
+        // lock Additional Collateral
+        new_collateral_to_lock = ctx.accounts.lock_borrower_collateral(additional_borrow_amount, ctx.remaining_accounts)?;
+
+        // Transfer New Borrow Tokens To Borrower, Less The Origination Fee Which Goes To The Treasury
+        fee_charged = origination_fee(additional_borrow_amount, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+        ctx.accounts.transfer_tokens_to_borrower(additional_borrow_amount - fee_charged, ctx.remaining_accounts)?;
+        treasury_credit = ctx.accounts.transfer_origination_fee_to_treasury(fee_charged, ctx.remaining_accounts)?;
+
+        // Prices Used To Size The Additional Collateral
+        prices_used = Some((
+            get_mint_price(&ctx.accounts.collateral_token_config, &ctx.accounts.collateral_price_update, &ctx.accounts.token_collateral.to_account_info())?,
+            get_mint_price(&ctx.accounts.borrowing_token_config, &ctx.accounts.borrowing_price_update, &ctx.accounts.token_to_borrow.to_account_info())?,
+        ));
     }
 
     // Update Borrower Position Here
@@ -46,6 +75,9 @@ pub fn modify_borrowing_order(ctx: Context<ModifyBorrowerPosition>, new_loan_ter
         borrower_position.collateral_amount = borrower_position.collateral_amount.checked_add(new_collateral_to_lock)
             .ok_or(LendanaError::TokenAdditionOverflow)?;
 
+        borrower_position.isolated_debt = borrower_position.isolated_debt.checked_add(new_isolated_debt)
+            .ok_or(LendanaError::TokenAdditionOverflow)?;
+
         borrower_position.borrowing_terms = new_loan_terms;
     }
 
@@ -56,19 +88,30 @@ pub fn modify_borrowing_order(ctx: Context<ModifyBorrowerPosition>, new_loan_ter
         .checked_add(additional_borrow_amount)
         .ok_or(LendanaError::TokenAdditionOverflow)?;
 
-    // Update Collateral Token Escrow: could be a SOL Collateral or Collateral Token
-    if ctx.accounts.token_collateral.key() == NATIVE_SOL_MINT_ADDRESS {
-        let sol_collateral_vault = &mut ctx.accounts.sol_collateral_vault;
-        sol_collateral_vault.vault_balance = sol_collateral_vault.vault_balance
-            .checked_add(new_collateral_to_lock)
-            .ok_or(LendanaError::TokenAdditionOverflow)?;
-    } else {
+    record_treasury_fees(borrowing_token_escrow, treasury_credit)?;
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if ctx.accounts.token_collateral.key() != NATIVE_SOL_MINT_ADDRESS {
         let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
         collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
             .checked_add(new_collateral_to_lock)
             .ok_or(LendanaError::TokenAdditionOverflow)?;
     }
-        
-    
+
+    let borrower_position = &ctx.accounts.borrower_position;
+    emit_cpi!(BorrowOrderModified {
+        borrower: ctx.accounts.borrower.key(),
+        borrower_position_id: borrower_position.borrower_position_id,
+        additional_borrow_amount,
+        additional_collateral_amount: new_collateral_to_lock,
+        borrowing_amount: borrower_position.borrowing_amount,
+        collateral_amount: borrower_position.collateral_amount,
+        borrowing_terms: new_loan_terms,
+        collateral_price: prices_used.map(|(collateral_price, _)| collateral_price.into()),
+        borrowing_price: prices_used.map(|(_, borrowing_price)| borrowing_price.into()),
+        origination_fee: fee_charged,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::{prelude::*, Discriminator, solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked}};
+
+use crate::{states::{contexts::*, errors::*, events::*}, instruction::{FlashBorrow, FlashRepay}, utils::ensure_open_for_orders};
+
+
+/* FLASH BORROW FROM VAULT
+1. Must be a top-level instruction, so the instructions sysvar describes the real transaction
+2. Blocked while the protocol or the token is paused, or the token is delisting
+3. A flash_repay for the same token escrow must follow later in the transaction, with no other flash_borrow before it
+4. The outstanding amount is recorded on the escrow, and cleared by flash_repay */
+pub fn flash_borrow_from_vault<'info>(ctx: Context<'_, '_, '_, 'info, FlashBorrowFromVault<'info>>, amount: u64) -> Result<()> {
+
+    require!(amount > 0, LendanaError::ZeroAmount);
+    require!(ctx.accounts.token_escrow.flash_loan_amount == 0, LendanaError::FlashLoanActive);
+    ensure_open_for_orders(&ctx.accounts.protocol_state, &ctx.accounts.token_escrow)?;
+
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)? as usize;
+
+    // Called Through CPI, The Current Top-Level Instruction Belongs To Another Program
+    let current_instruction = load_instruction_at_checked(current_index, &instructions_sysvar)?;
+    require_keys_eq!(current_instruction.program_id, crate::ID, LendanaError::FlashLoanCpiNotAllowed);
+
+    // Find The Matching Flash Repay Later In The Transaction
+    let token_escrow = ctx.accounts.token_escrow.key();
+    let mut next_index = current_index + 1;
+    loop {
+        let instruction = load_instruction_at_checked(next_index, &instructions_sysvar)
+            .map_err(|_| LendanaError::MissingFlashRepay)?;
+        next_index += 1;
+
+        if instruction.program_id != crate::ID {
+            continue;
+        }
+        if instruction.data.starts_with(FlashBorrow::DISCRIMINATOR) {
+            return Err(LendanaError::MissingFlashRepay.into());
+        }
+        if instruction.data.starts_with(FlashRepay::DISCRIMINATOR)
+            && instruction.accounts.iter().any(|account| account.pubkey == token_escrow) {
+            break;
+        }
+    }
+
+    // Lend The Vault Tokens
+    ctx.accounts.transfer_flash_loan_to_borrower(amount, ctx.remaining_accounts)?;
+    ctx.accounts.token_escrow.flash_loan_amount = amount;
+
+    emit_cpi!(FlashBorrowed {
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, events::*}, utils::{flash_loan_fee, record_treasury_fees}};
+
+
+/* FLASH REPAY TO VAULT
+1. Repays the outstanding flash loan plus the flash loan fee
+2. The whole fee goes to the treasury: lender positions earn their fixed loan terms, so there is no share to credit them */
+pub fn flash_repay_to_vault<'info>(ctx: Context<'_, '_, '_, 'info, FlashRepayToVault<'info>>) -> Result<()> {
+
+    let amount = ctx.accounts.token_escrow.flash_loan_amount;
+    require!(amount > 0, LendanaError::NoActiveFlashLoan);
+
+    let fee = flash_loan_fee(amount)?;
+
+    ctx.accounts.repay_flash_loan_to_vault(amount, ctx.remaining_accounts)?;
+    ctx.accounts.pay_fee_to_treasury(fee, ctx.remaining_accounts)?;
+
+    let token_escrow = &mut ctx.accounts.token_escrow;
+    token_escrow.flash_loan_amount = 0;
+    record_treasury_fees(token_escrow, fee)?;
+
+    emit_cpi!(FlashRepaid {
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        amount,
+        fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
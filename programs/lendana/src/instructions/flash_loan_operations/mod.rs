@@ -0,0 +1,6 @@
+pub mod flash_borrow_from_vault;
+pub mod flash_repay_to_vault;
+
+
+pub use flash_borrow_from_vault::*;
+pub use flash_repay_to_vault::*;
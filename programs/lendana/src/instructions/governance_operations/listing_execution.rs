@@ -0,0 +1,182 @@
+use anchor_lang::{prelude::*, solana_program::{instruction::{AccountMeta, Instruction}, program::invoke_signed}, InstructionData};
+
+use crate::{states::{accounts::*, contexts::*, errors::*, events::*}, utils::proposal_lapsed};
+
+
+/* QUEUE A VOTED LISTING
+1. Anyone can settle a listing once voting has ended
+2. It passes with at least the quorum in favour and more weight for than against; otherwise it is defeated
+3. A passed listing is queued on the timelock by the governance signer as three proposals: whitelisting,
+risk parameters and price feed, so it waits out the same delay as any other listing
+4. A queued listing whose proposals were cancelled or expired unexecuted is queued again as three new proposals */
+pub fn settle_listing_vote(ctx: Context<QueueListing>) -> Result<()> {
+
+    let now = Clock::get()?.unix_timestamp;
+    let listing_proposal = &ctx.accounts.listing_proposal;
+    let passed = match listing_proposal.status {
+        ListingStatus::Voting => {
+            require!(now >= listing_proposal.voting_ends_at, LendanaError::ListingVotingOpen);
+            listing_proposal.votes_for >= ctx.accounts.listing_governance.quorum &&
+                listing_proposal.votes_for > listing_proposal.votes_against
+        }
+        // The Vote Already Passed; Only A Listing That Can No Longer Execute Is Queued Again
+        ListingStatus::Queued => {
+            let stale_proposal = ctx.accounts.stale_proposal.as_ref().ok_or(LendanaError::ListingProposalsLive)?;
+            require!(proposal_lapsed(stale_proposal, now), LendanaError::ListingProposalsLive);
+            true
+        }
+        _ => return err!(LendanaError::InvalidListingStatus),
+    };
+
+    let status = if passed {
+        let market = listing_proposal.market;
+        let token_mint = listing_proposal.token_mint;
+        let actions = [
+            (ctx.accounts.whitelist_proposal.key(), GovernanceAction::WhitelistToken {
+                market,
+                token_mint,
+                isolation_debt_ceiling: listing_proposal.isolation_debt_ceiling,
+            }),
+            (ctx.accounts.risk_proposal.key(), GovernanceAction::SetRiskConfig {
+                market,
+                token_mint,
+                risk_parameters: listing_proposal.risk_parameters,
+            }),
+            (ctx.accounts.price_feed_proposal.key(), GovernanceAction::AddPriceFeed {
+                market,
+                token_mint,
+                price_feed_id: listing_proposal.price_feed_id,
+            }),
+        ];
+
+        let account_infos = ctx.accounts.to_account_infos();
+        for (timelock_proposal, action) in actions.iter() {
+            // queue_proposal Creates Each Proposal At The Timelock's Next Id, So They Are Passed In That Order
+            let accounts = crate::accounts::QueueTimelockProposal {
+                proposer: ctx.accounts.governance_signer.key(),
+                admin_account: ctx.accounts.admin_account.key(),
+                trusted_roles: ctx.accounts.trusted_roles.key(),
+                market: None,
+                timelock: ctx.accounts.timelock.key(),
+                timelock_proposal: *timelock_proposal,
+                system_program: ctx.accounts.system_program.key(),
+                event_authority: ctx.accounts.event_authority.key(),
+                program: crate::ID,
+            };
+            let data = crate::instruction::QueueProposal { action: action.clone() }.data();
+            invoke_as_governance(accounts.to_account_metas(None), data, &account_infos, ctx.accounts.listing_governance.signer_bump)?;
+        }
+
+        ctx.accounts.listing_proposal.timelock_proposals = actions.map(|(timelock_proposal, _)| timelock_proposal);
+        ListingStatus::Queued
+    } else {
+        ListingStatus::Defeated
+    };
+
+    let listing_proposal = &mut ctx.accounts.listing_proposal;
+    listing_proposal.status = status;
+
+    emit_cpi!(ListingDecided {
+        listing_id: listing_proposal.listing_id,
+        status,
+        votes_for: listing_proposal.votes_for,
+        votes_against: listing_proposal.votes_against,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/* EXECUTE A QUEUED LISTING
+1. Anyone can execute it once its timelock proposals have matured
+2. The governance signer whitelists the token, then sets its risk parameters and maps its price feed,
+each through its usual instruction, which consumes the matching proposal */
+pub fn execute_token_listing(ctx: Context<ExecuteListing>) -> Result<()> {
+
+    let listing_proposal = &ctx.accounts.listing_proposal;
+    require!(listing_proposal.status == ListingStatus::Queued, LendanaError::InvalidListingStatus);
+
+    let token_mint = listing_proposal.token_mint;
+    let governance_signer = ctx.accounts.governance_signer.key();
+    let signer_bump = ctx.accounts.listing_governance.signer_bump;
+    let account_infos = ctx.accounts.to_account_infos();
+
+    let whitelist_accounts = crate::accounts::WhitelistToken {
+        whitelister_role: governance_signer,
+        market: ctx.accounts.market.key(),
+        trusted_roles: ctx.accounts.trusted_roles.key(),
+        mint_token: token_mint,
+        token_config: ctx.accounts.token_config.key(),
+        token_escrow: ctx.accounts.token_escrow.key(),
+        token_vault: ctx.accounts.token_vault.key(),
+        treasury: ctx.accounts.treasury.key(),
+        treasury_vault: ctx.accounts.treasury_vault.key(),
+        timelock_proposal: ctx.accounts.whitelist_proposal.key(),
+        system_program: ctx.accounts.system_program.key(),
+        associated_token_program: ctx.accounts.associated_token_program.key(),
+        token_program: ctx.accounts.token_program.key(),
+        event_authority: ctx.accounts.event_authority.key(),
+        program: crate::ID,
+    };
+    let whitelist_data = crate::instruction::WhitelistToken {
+        token_mint,
+        isolation_debt_ceiling: listing_proposal.isolation_debt_ceiling,
+    }.data();
+    invoke_as_governance(whitelist_accounts.to_account_metas(None), whitelist_data, &account_infos, signer_bump)?;
+
+    let risk_accounts = crate::accounts::SetTokenRiskConfig {
+        risk_manager_role: governance_signer,
+        market: ctx.accounts.market.key(),
+        trusted_roles: ctx.accounts.trusted_roles.key(),
+        mint_token: token_mint,
+        token_config: ctx.accounts.token_config.key(),
+        timelock_proposal: ctx.accounts.risk_proposal.key(),
+        system_program: ctx.accounts.system_program.key(),
+        event_authority: ctx.accounts.event_authority.key(),
+        program: crate::ID,
+    };
+    let risk_data = crate::instruction::SetRiskConfig {
+        token_mint,
+        risk_parameters: listing_proposal.risk_parameters,
+    }.data();
+    invoke_as_governance(risk_accounts.to_account_metas(None), risk_data, &account_infos, signer_bump)?;
+
+    let price_feed_accounts = crate::accounts::AddTokenPriceMapping {
+        oracle_manager: governance_signer,
+        market: ctx.accounts.market.key(),
+        trusted_roles: ctx.accounts.trusted_roles.key(),
+        token_config: ctx.accounts.token_config.key(),
+        timelock_proposal: ctx.accounts.price_feed_proposal.key(),
+        event_authority: ctx.accounts.event_authority.key(),
+        program: crate::ID,
+    };
+    // add_price Takes The Feed Id As Hex, As Oracle Managers Pass It
+    let price_feed_hex = listing_proposal.price_feed_id.iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+    let price_feed_data = crate::instruction::AddPrice {
+        token_mint,
+        price_feed_id: price_feed_hex,
+    }.data();
+    invoke_as_governance(price_feed_accounts.to_account_metas(None), price_feed_data, &account_infos, signer_bump)?;
+
+    let listing_proposal = &mut ctx.accounts.listing_proposal;
+    listing_proposal.status = ListingStatus::Executed;
+
+    emit_cpi!(ListingExecuted {
+        listing_id: listing_proposal.listing_id,
+        token_mint,
+        executor: ctx.accounts.executor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* Calls Back Into Lendana With The Governance Signer Signing */
+fn invoke_as_governance(accounts: Vec<AccountMeta>, data: Vec<u8>,
+    account_infos: &[AccountInfo], signer_bump: u8) -> Result<()> {
+
+    let instruction = Instruction { program_id: crate::ID, accounts, data };
+    let signer_seeds: &[&[&[u8]]] = &[&[b"listing_governance_signer", &[signer_bump]]];
+    invoke_signed(&instruction, account_infos, signer_seeds)?;
+    Ok(())
+}
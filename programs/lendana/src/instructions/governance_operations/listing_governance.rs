@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, contexts::*, errors::*, events::*}, utils::consume_matured_proposal};
+
+
+/* CONFIGURE LISTING GOVERNANCE
+1. The admin sets the governance token, quorum and voting period through a matured timelock proposal
+2. Once set, the governance token cannot change, so locked votes can always be withdrawn
+3. Listings only execute once the governance signer is granted the Whitelister, OracleManager and RiskManager roles,
+and it pays for the accounts a listing creates, so it needs to be funded with SOL */
+pub fn set_listing_governance(ctx: Context<ConfigureListingGovernance>, quorum: u64, voting_period: i64) -> Result<()> {
+
+    let governance_mint = ctx.accounts.governance_mint.key();
+    consume_matured_proposal(
+        &mut ctx.accounts.timelock_proposal,
+        &GovernanceAction::ConfigureListingGovernance { governance_mint, quorum, voting_period },
+    )?;
+
+    let listing_governance = &mut ctx.accounts.listing_governance;
+    require!(
+        quorum > 0 && voting_period > 0 && voting_period <= MAX_LISTING_VOTING_PERIOD &&
+        (listing_governance.governance_mint == Pubkey::default() || listing_governance.governance_mint == governance_mint),
+        LendanaError::InvalidListingGovernance
+    );
+
+    // Listing Ids Carry Over When Retuned
+    listing_governance.governance_mint = governance_mint;
+    listing_governance.quorum = quorum;
+    listing_governance.voting_period = voting_period;
+    listing_governance.governance_bump = ctx.bumps.listing_governance;
+    listing_governance.signer_bump = ctx.bumps.governance_signer;
+    listing_governance.version = ACCOUNT_VERSION;
+
+    emit_cpi!(ListingGovernanceConfigured {
+        governance_mint,
+        quorum,
+        voting_period,
+        governance_signer: ctx.accounts.governance_signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
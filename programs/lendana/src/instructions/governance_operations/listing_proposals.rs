@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, contexts::*, errors::*, events::*}, utils::{parse_price_feed_id, validate_mint_extensions}, validate_risk_parameters};
+
+
+/* PROPOSE LISTING A TOKEN
+1. Anyone can propose a token with its Pyth price feed, risk parameters and optional isolation debt ceiling,
+for a market run by the protocol's roles; curated markets list through their curator
+2. The feed, risk parameters and mint are checked now, so a passed vote does not fail on them at execution
+3. Voting stays open for the governance's voting period */
+pub fn propose_token_listing(ctx: Context<ProposeListing>, price_feed_id: String, risk_parameters: RiskParameters,
+    isolation_debt_ceiling: Option<u64>) -> Result<()> {
+
+    let price_feed_id = parse_price_feed_id(&price_feed_id)?;
+    validate_risk_parameters::validate_risk_parameters(&risk_parameters)?;
+    validate_mint_extensions(&ctx.accounts.mint_token.to_account_info())?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let listing_governance = &mut ctx.accounts.listing_governance;
+    let listing_id = listing_governance.next_listing_id;
+    let voting_ends_at = now.checked_add(listing_governance.voting_period).ok_or(LendanaError::MathOverflow)?;
+    listing_governance.next_listing_id = listing_id.checked_add(1).ok_or(LendanaError::MathOverflow)?;
+
+    let proposer = ctx.accounts.proposer.key();
+    let token_mint = ctx.accounts.mint_token.key();
+    ctx.accounts.listing_proposal.set_inner(ListingProposal {
+        listing_id,
+        proposer,
+        market: ctx.accounts.market.key(),
+        token_mint,
+        price_feed_id,
+        risk_parameters,
+        isolation_debt_ceiling,
+        voting_ends_at,
+        votes_for: 0,
+        votes_against: 0,
+        status: ListingStatus::Voting,
+        timelock_proposals: [Pubkey::default(); 3],
+        listing_bump: ctx.bumps.listing_proposal,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    emit_cpi!(ListingProposed {
+        listing_id,
+        proposer,
+        token_mint,
+        price_feed_id,
+        risk_parameters,
+        isolation_debt_ceiling,
+        voting_ends_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/* VOTE ON A LISTING
+1. Governance tokens are locked in the governance vault as vote weight until voting ends
+2. Each holder votes once per listing, for or against */
+pub fn cast_listing_vote<'info>(ctx: Context<'_, '_, '_, 'info, VoteOnListing<'info>>, support: bool, amount: u64) -> Result<()> {
+
+    require!(amount > 0, LendanaError::InvalidVoteWeight);
+
+    let now = Clock::get()?.unix_timestamp;
+    let listing_proposal = &ctx.accounts.listing_proposal;
+    require!(listing_proposal.status == ListingStatus::Voting, LendanaError::InvalidListingStatus);
+    require!(now < listing_proposal.voting_ends_at, LendanaError::ListingVotingClosed);
+
+    let weight = ctx.accounts.lock_vote_weight(amount, ctx.remaining_accounts)?;
+    require!(weight > 0, LendanaError::InvalidVoteWeight);
+
+    let listing_proposal = &mut ctx.accounts.listing_proposal;
+    if support {
+        listing_proposal.votes_for = listing_proposal.votes_for.checked_add(weight).ok_or(LendanaError::MathOverflow)?;
+    } else {
+        listing_proposal.votes_against = listing_proposal.votes_against.checked_add(weight).ok_or(LendanaError::MathOverflow)?;
+    }
+
+    let listing_proposal_key = listing_proposal.key();
+    let listing_id = listing_proposal.listing_id;
+    let voter = ctx.accounts.voter.key();
+    ctx.accounts.listing_vote.set_inner(ListingVote {
+        listing_proposal: listing_proposal_key,
+        voter,
+        weight,
+        support,
+        vote_bump: ctx.bumps.listing_vote,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    emit_cpi!(ListingVoteCast {
+        listing_id,
+        voter,
+        weight,
+        support,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/* WITHDRAW A LISTING VOTE
+1. Once voting has ended, whatever the outcome, the voter takes back the locked tokens and the vote's rent */
+pub fn withdraw_vote_weight<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawListingVote<'info>>) -> Result<()> {
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= ctx.accounts.listing_proposal.voting_ends_at, LendanaError::ListingVotingOpen);
+
+    let weight = ctx.accounts.listing_vote.weight;
+    ctx.accounts.unlock_vote_weight(weight, ctx.remaining_accounts)?;
+
+    emit_cpi!(ListingVoteWithdrawn {
+        listing_id: ctx.accounts.listing_proposal.listing_id,
+        voter: ctx.accounts.voter.key(),
+        weight,
+        timestamp: now,
+    });
+
+    Ok(())
+}
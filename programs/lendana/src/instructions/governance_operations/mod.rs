@@ -0,0 +1,8 @@
+pub mod listing_governance;
+pub mod listing_proposals;
+pub mod listing_execution;
+
+
+pub use listing_governance::*;
+pub use listing_proposals::*;
+pub use listing_execution::*;
\ No newline at end of file
@@ -1,15 +1,15 @@
 use anchor_lang::prelude::*;
 
-use crate::states::{contexts::*, errors::*};
+use crate::states::{contexts::*, errors::*, events::*};
 
 
 // @dev Should Only Be Allowed If There Is No Matching Yet
 // @note Should Only Close The Lender Position PDA account, and refund the tokens to the lender
 
-pub fn cancel_lending_order(ctx: Context<CancelLendingOrder>) -> Result<()> {
+pub fn cancel_lending_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelLendingOrder<'info>>) -> Result<()> {
 
     //@dev Refund The Lender
-    ctx.accounts.refund_tokens_to_lender()?;
+    ctx.accounts.refund_tokens_to_lender(ctx.remaining_accounts)?;
 
     // Get Lender position for validation and checks
     let lender_position = &mut ctx.accounts.lender_position;
@@ -18,6 +18,14 @@ pub fn cancel_lending_order(ctx: Context<CancelLendingOrder>) -> Result<()> {
     // Update Token holdings of Lent Tokens
     token_escrow_data.total_lent_tokens = token_escrow_data.total_lent_tokens.checked_sub(lender_position.lending_amount).ok_or(LendanaError::InsufficientLentTokens)?;
 
+    emit_cpi!(LendOrderCancelled {
+        lender: ctx.accounts.lender.key(),
+        lender_position_id: lender_position.lender_position_id,
+        lending_token: lender_position.lending_token,
+        refunded_amount: lender_position.lending_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     let lender_closing = ctx.accounts.lender.to_account_info();
     
     //@dev Close The Lender Position
@@ -1,23 +1,34 @@
 use anchor_lang::prelude::*;
 
-use crate::{states::{contexts::*, errors::*, accounts::*}, validate_loan_terms, LoanTerms};
+use crate::{states::{contexts::*, errors::*, accounts::*, events::*}, validate_loan_terms, ensure_open_for_orders, ensure_within_cap, LoanTerms, ACCOUNT_VERSION};
 
 /*
 1. Deposit lending tokens from lender into the token Vault, 
 and update the data in the token Escrow
 2. Update Lender position */
 
-pub fn create_lending_order(ctx: Context<LenderPositionInfo>, amount_to_lend: u64,
+pub fn create_lending_order<'info>(ctx: Context<'_, '_, '_, 'info, LenderPositionInfo<'info>>, amount_to_lend: u64,
      loan_terms: LoanTerms) -> Result<()> {
 
     // Non Zero Amount To Lend
     require!(amount_to_lend > 0, LendanaError::ZeroAmount);
 
     // Validate Loan Terms
-    validate_loan_terms::validate_loan(loan_terms)?;
+    validate_loan_terms::validate_loan(loan_terms, &ctx.accounts.market)?;
 
-    // Make Lending Deposit Into Token Vault
-    ctx.accounts.deposit_to_token_vault(amount_to_lend)?;
+    // No New Lends While The Protocol Or The Token Is Paused Or Delisting
+    ensure_open_for_orders(&ctx.accounts.protocol_state, &ctx.accounts.token_escrow)?;
+
+    // Respect The Token Supply Cap
+    ensure_within_cap(
+        ctx.accounts.token_escrow.total_lent_tokens,
+        amount_to_lend,
+        ctx.accounts.token_config.risk_parameters.supply_cap,
+        LendanaError::SupplyCapExceeded,
+    )?;
+
+    // Make Lending Deposit Into Token Vault, Crediting Only What Reaches The Vault
+    let amount_to_lend = ctx.accounts.deposit_to_token_vault(amount_to_lend, ctx.remaining_accounts)?;
 
     // Update The Token Escrow Data
     let token_escrow_data = &mut ctx.accounts.token_escrow;
@@ -35,17 +46,29 @@ pub fn create_lending_order(ctx: Context<LenderPositionInfo>, amount_to_lend: u6
     lender_postion_counter.lenders_current_position_id += 1;
 
     // Set Lender Position
+    let timestamp = Clock::get()?.unix_timestamp;
     lender_position.set_inner(LenderPosition{
         lending_token: ctx.accounts.token_to_lend.key(),
         lender_pubkey: ctx.accounts.lender.key(),
         lending_amount: amount_to_lend,
         interest_accumulated: 0,
-        lender_position_id: lender_position_id,
+        lender_position_id,
         lending_terms: loan_terms,
         is_position_active: true,
         is_matched: false,
-        lending_start: Clock::get()?.unix_timestamp,
+        lending_start: timestamp,
         lender_position_bump: ctx.bumps.lender_position,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    emit_cpi!(LendOrderCreated {
+        lender: ctx.accounts.lender.key(),
+        lender_position_id,
+        lending_token: ctx.accounts.token_to_lend.key(),
+        lending_amount: amount_to_lend,
+        lending_terms: loan_terms,
+        timestamp,
     });
     
     Ok(())
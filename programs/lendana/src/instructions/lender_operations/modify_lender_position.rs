@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{states::{contexts::*, errors::*}, validate_loan_terms, LoanTerms};
+use crate::{states::{contexts::*, errors::*, events::*}, validate_loan_terms, ensure_open_for_orders, ensure_within_cap, LoanTerms};
 
 
 /*
@@ -10,38 +10,57 @@ use crate::{states::{contexts::*, errors::*}, validate_loan_terms, LoanTerms};
 @note If Changing both loan terms and topping up lending amount, set new values according to standard
 */
 
-pub fn modify_lender_position(ctx: Context<ModifyLenderPosition>, new_loan_terms: LoanTerms, add_lending_amount: u64) -> Result<()> {
+pub fn modify_lender_position<'info>(ctx: Context<'_, '_, '_, 'info, ModifyLenderPosition<'info>>, new_loan_terms: LoanTerms, add_lending_amount: u64) -> Result<()> {
 
     // Get Lender position for validation and checks
     let lender_position = &ctx.accounts.lender_position;
     
     // First Ensure Lending Order Is Not Matched
-    require!(lender_position.is_matched == false, LendanaError::OrderAlreadyMatched);
+    require!(!lender_position.is_matched, LendanaError::OrderAlreadyMatched);
 
     // If Modifying Loan Terms, Then We Validate The New Loan Terms. If Not, We Just Skip because of previous validation
     if lender_position.lending_terms != new_loan_terms {
-        validate_loan_terms::validate_loan(new_loan_terms)?;
+        validate_loan_terms::validate_loan(new_loan_terms, &ctx.accounts.market)?;
     }
 
-    // If Topping Up Lending Amount, Retrieve Tokens From Lender ATA
+    // If Topping Up Lending Amount, Retrieve Tokens From Lender ATA, Crediting Only What Reaches The Vault
+    let mut added_amount = 0;
     if add_lending_amount > 0 {
-        ctx.accounts.increase_lending_amount(add_lending_amount)?;
+        ensure_open_for_orders(&ctx.accounts.protocol_state, &ctx.accounts.token_escrow)?;
+
+        ensure_within_cap(
+            ctx.accounts.token_escrow.total_lent_tokens,
+            add_lending_amount,
+            ctx.accounts.token_config.risk_parameters.supply_cap,
+            LendanaError::SupplyCapExceeded,
+        )?;
+
+        added_amount = ctx.accounts.increase_lending_amount(add_lending_amount, ctx.remaining_accounts)?;
         
         // Update The Token Escrow Data
         let token_escrow_data = &mut ctx.accounts.token_escrow;
 
         token_escrow_data.total_lent_tokens = token_escrow_data.total_lent_tokens
-            .checked_add(add_lending_amount)
+            .checked_add(added_amount)
             .ok_or(LendanaError::TokenAdditionOverflow)?;
     }
 
     // Update Lender Position
     {
     let lender_position = &mut ctx.accounts.lender_position;
-    lender_position.lending_amount = lender_position.lending_amount.checked_add(add_lending_amount).ok_or(LendanaError::TokenAdditionOverflow)?;
+    lender_position.lending_amount = lender_position.lending_amount.checked_add(added_amount).ok_or(LendanaError::TokenAdditionOverflow)?;
     lender_position.lending_terms = new_loan_terms;
     }
 
+    emit_cpi!(LendOrderModified {
+        lender: ctx.accounts.lender.key(),
+        lender_position_id: ctx.accounts.lender_position.lender_position_id,
+        added_amount,
+        lending_amount: ctx.accounts.lender_position.lending_amount,
+        lending_terms: new_loan_terms,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, constants::*, events::*}, release_isolated_debt, get_mint_price, reserve_fee, record_treasury_fees};
+
+
+/* LIQUIDATE BORROW POSITION
+1. Position must be below the collateral token's liquidation threshold, or past its loan duration
+2. Liquidator repays the full debt, and seizes collateral worth the debt plus the liquidation bonus
+3. Remaining collateral and the position rent go back to the borrower */
+pub fn liquidate_borrow_position<'info>(ctx: Context<'_, '_, '_, 'info, LiquidateBorrowPosition<'info>>) -> Result<()> {
+
+    require!(ctx.accounts.borrower_position.is_position_active, LendanaError::PositionNotLiquidatable);
+
+    let (debt_amount, seized_collateral) = ctx.accounts.calculate_liquidation()?;
+
+    let borrowing_amount = ctx.accounts.borrower_position.borrowing_amount;
+    let collateral_amount = ctx.accounts.borrower_position.collateral_amount;
+
+    // Repay The Debt Less The Reserve Factor's Cut Of The Interest, Then Release The Collateral
+    let interest_paid = debt_amount.saturating_sub(borrowing_amount);
+    let reserve_fee = reserve_fee(interest_paid, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.repay_debt_to_borrow_vault(debt_amount - reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.pay_reserve_fee_to_treasury(reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.seize_borrower_collateral(seized_collateral, ctx.remaining_accounts)?;
+
+    // Update Borrowing Token Escrow
+    let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
+    borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
+        .checked_sub(borrowing_amount)
+        .ok_or(LendanaError::InsufficientBorrowedTokens)?;
+
+    record_treasury_fees(borrowing_token_escrow, reserve_fee)?;
+
+    // Free The Position's Share Of The Collateral's Isolation Debt Ceiling
+    let isolated_debt = ctx.accounts.borrower_position.isolated_debt;
+    release_isolated_debt(&mut ctx.accounts.collateral_token_escrow, isolated_debt);
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if ctx.accounts.token_collateral.key() != NATIVE_SOL_MINT_ADDRESS {
+        let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_sub(collateral_amount)
+            .ok_or(LendanaError::InsufficientLentTokens)?;
+    }
+
+    // Prices Used To Value The Debt And Collateral
+    let collateral_price = get_mint_price(&ctx.accounts.collateral_token_config, &ctx.accounts.collateral_price_update, &ctx.accounts.token_collateral.to_account_info())?;
+    let borrowing_price = get_mint_price(&ctx.accounts.borrowing_token_config, &ctx.accounts.borrowing_price_update, &ctx.accounts.token_to_borrow.to_account_info())?;
+
+    emit_cpi!(PositionLiquidated {
+        liquidator: ctx.accounts.liquidator.key(),
+        borrower: ctx.accounts.borrower.key(),
+        borrower_position_id: ctx.accounts.borrower_position.borrower_position_id,
+        collateral_token: ctx.accounts.token_collateral.key(),
+        borrowing_token: ctx.accounts.token_to_borrow.key(),
+        debt_repaid: debt_amount,
+        collateral_seized: seized_collateral,
+        collateral_price: collateral_price.into(),
+        borrowing_price: borrowing_price.into(),
+        reserve_fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // Position Account Is Closed To The Borrower By The Context
+    let borrower_position = &mut ctx.accounts.borrower_position;
+    borrower_position.is_position_active = false;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, constants::*, events::*}, utils::*};
+
+
+/* LIQUIDATE MARGIN ACCOUNT
+1. Account must have a borrow past its loan duration, or its combined debt value above the basket's liquidation limit
+2. Liquidator repays part or all of one borrow, and seizes one collateral token worth the repaid value plus that token's liquidation bonus
+3. remaining_accounts carry a [token config, price update, mint] triple per deposit, then a [token config, price update, mint] triple per borrow */
+pub fn liquidate_margin_account<'info>(ctx: Context<'_, '_, '_, 'info, LiquidateMarginAccount<'info>>, repay_amount: u64) -> Result<()> {
+
+    require!(repay_amount > 0, LendanaError::ZeroAmount);
+
+    let borrowing_token = ctx.accounts.token_to_borrow.key();
+    let collateral_token = ctx.accounts.token_collateral.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let margin_account = &mut ctx.accounts.margin_account;
+
+    // Health Is Measured Over The Whole Account
+    let valuation = value_margin_account(&ctx.accounts.market.key(), margin_account, ctx.remaining_accounts, now)?;
+    require!(
+        has_expired_margin_debt(margin_account, now) || valuation.debt_value > valuation.collateral.liquidation_limit,
+        LendanaError::PositionNotLiquidatable
+    );
+
+    let borrow_index = margin_account.borrows.iter()
+        .position(|borrow| borrow.token_mint == borrowing_token)
+        .ok_or(LendanaError::MarginDebtNotFound)?;
+    let deposit_index = margin_account.collateral_deposits.iter()
+        .position(|deposit| deposit.token_mint == collateral_token)
+        .ok_or(LendanaError::CollateralNotDeposited)?;
+    let deposit = margin_account.collateral_deposits[deposit_index];
+
+    let borrow = &mut margin_account.borrows[borrow_index];
+    settle_margin_debt_interest(borrow, now)?;
+    let outstanding_debt = borrow.borrowing_amount
+        .checked_add(borrow.interest_owed)
+        .ok_or(LendanaError::MathOverflow)?;
+
+    // Seize The Repaid Value Plus The Collateral's Liquidation Bonus, Limited To The Deposit
+    let (repay_amount, seized_collateral) = liquidation_seizure(
+        repay_amount.min(outstanding_debt),
+        borrow.decimals,
+        &valuation.borrow_prices[borrow_index],
+        &deposit,
+        &valuation.collateral.deposits[deposit_index],
+    )?;
+
+    let (interest_paid, principal_paid) = apply_margin_repayment(borrow, repay_amount);
+    if borrow.borrowing_amount == 0 && borrow.interest_owed == 0 {
+        margin_account.borrows.remove(borrow_index);
+    }
+
+    // Reduce The Seized Deposit, Dropping It From The Basket Once Empty
+    let seized_deposit = &mut margin_account.collateral_deposits[deposit_index];
+    seized_deposit.amount -= seized_collateral;
+    if seized_deposit.amount == 0 {
+        margin_account.collateral_deposits.remove(deposit_index);
+    }
+
+    // Repay The Debt Less The Reserve Factor's Cut Of The Interest, Then Release The Collateral
+    let reserve_fee = reserve_fee(interest_paid, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.repay_debt_to_borrow_vault(repay_amount - reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.pay_reserve_fee_to_treasury(reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.transfer_seized_collateral(seized_collateral, ctx.remaining_accounts)?;
+
+    // Update Borrowing Token Escrow
+    let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
+    borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
+        .checked_sub(principal_paid)
+        .ok_or(LendanaError::InsufficientBorrowedTokens)?;
+
+    record_treasury_fees(borrowing_token_escrow, reserve_fee)?;
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if collateral_token != NATIVE_SOL_MINT_ADDRESS {
+        let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_sub(seized_collateral)
+            .ok_or(LendanaError::InsufficientLentTokens)?;
+    }
+
+    emit_cpi!(MarginAccountLiquidated {
+        liquidator: ctx.accounts.liquidator.key(),
+        borrower: ctx.accounts.borrower.key(),
+        collateral_token,
+        borrowing_token,
+        debt_repaid: repay_amount,
+        collateral_seized: seized_collateral,
+        debt_value: valuation.debt_value,
+        liquidation_limit: valuation.collateral.liquidation_limit,
+        collateral_price: valuation.collateral.deposits[deposit_index].price.into(),
+        borrowing_price: valuation.borrow_prices[borrow_index].into(),
+        reserve_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
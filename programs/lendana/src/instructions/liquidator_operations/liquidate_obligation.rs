@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, constants::*, events::*}, utils::*};
+
+
+/* LIQUIDATE OBLIGATION
+1. Obligation must be past its loan duration, or its debt value above the basket's liquidation limit
+2. Liquidator repays up to the whole debt, and seizes the chosen collateral worth the repaid value plus that token's liquidation bonus
+3. remaining_accounts carry a [token config, price update, mint] triple per deposit */
+pub fn liquidate_obligation<'info>(ctx: Context<'_, '_, '_, 'info, LiquidateObligation<'info>>, repay_amount: u64) -> Result<()> {
+
+    require!(repay_amount > 0, LendanaError::ZeroAmount);
+
+    let collateral_token = ctx.accounts.token_collateral.key();
+    let now = Clock::get()?.unix_timestamp;
+    let borrowing_token_config = &ctx.accounts.borrowing_token_config;
+
+    let obligation = &mut ctx.accounts.obligation;
+
+    settle_obligation_interest(obligation, now)?;
+    let outstanding_debt = obligation.borrowing_amount
+        .checked_add(obligation.interest_owed)
+        .ok_or(LendanaError::MathOverflow)?;
+    require!(outstanding_debt > 0, LendanaError::NoOutstandingDebt);
+
+    // Health Is Measured Over The Whole Basket
+    let valuation = value_collateral_basket(&ctx.accounts.market.key(), &obligation.collateral_deposits, ctx.remaining_accounts)?;
+    let debt_value = obligation_debt_value(obligation, Some(borrowing_token_config), Some(&ctx.accounts.borrowing_price_update), Some(&ctx.accounts.token_to_borrow.to_account_info()), now)?;
+    let loan_end = obligation.borrowing_start
+        .saturating_add(obligation.borrowing_terms.lending_duration as i64);
+    require!(now >= loan_end || debt_value > valuation.liquidation_limit, LendanaError::PositionNotLiquidatable);
+
+    let index = obligation.collateral_deposits.iter()
+        .position(|deposit| deposit.token_mint == collateral_token)
+        .ok_or(LendanaError::CollateralNotDeposited)?;
+    let deposit = obligation.collateral_deposits[index];
+
+    // Seize The Repaid Value Plus The Collateral's Liquidation Bonus, Limited To The Deposit
+    let borrowing_price = get_mint_price(borrowing_token_config, &ctx.accounts.borrowing_price_update, &ctx.accounts.token_to_borrow.to_account_info())?;
+    let (repay_amount, seized_collateral) = liquidation_seizure(
+        repay_amount.min(outstanding_debt),
+        obligation.borrowing_token_decimals,
+        &borrowing_price,
+        &deposit,
+        &valuation.deposits[index],
+    )?;
+
+    let (interest_paid, principal_paid, released_isolated_debt) = apply_obligation_repayment(obligation, repay_amount)?;
+
+    // Reduce The Seized Deposit, Dropping It From The Basket Once Empty
+    let seized_deposit = &mut obligation.collateral_deposits[index];
+    seized_deposit.amount -= seized_collateral;
+    if seized_deposit.amount == 0 {
+        obligation.collateral_deposits.remove(index);
+    }
+
+    // Repay The Debt Less The Reserve Factor's Cut Of The Interest, Then Release The Collateral
+    let reserve_fee = reserve_fee(interest_paid, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.repay_debt_to_borrow_vault(repay_amount - reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.pay_reserve_fee_to_treasury(reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.transfer_seized_collateral(seized_collateral, ctx.remaining_accounts)?;
+
+    // Update Borrowing Token Escrow
+    let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
+    borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
+        .checked_sub(principal_paid)
+        .ok_or(LendanaError::InsufficientBorrowedTokens)?;
+
+    record_treasury_fees(borrowing_token_escrow, reserve_fee)?;
+
+    // Isolated Collateral Is Always The Sole Deposit, So It Is The One Being Seized
+    release_isolated_debt(&mut ctx.accounts.collateral_token_escrow, released_isolated_debt);
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if collateral_token != NATIVE_SOL_MINT_ADDRESS {
+        let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_sub(seized_collateral)
+            .ok_or(LendanaError::InsufficientLentTokens)?;
+    }
+
+    emit_cpi!(ObligationLiquidated {
+        liquidator: ctx.accounts.liquidator.key(),
+        borrower: ctx.accounts.borrower.key(),
+        collateral_token,
+        borrowing_token: ctx.accounts.token_to_borrow.key(),
+        debt_repaid: repay_amount,
+        collateral_seized: seized_collateral,
+        debt_value,
+        liquidation_limit: valuation.liquidation_limit,
+        collateral_price: valuation.deposits[index].price.into(),
+        borrowing_price: borrowing_price.into(),
+        reserve_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
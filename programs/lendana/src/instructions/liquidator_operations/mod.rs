@@ -0,0 +1,8 @@
+pub mod liquidate_borrow_position;
+pub mod liquidate_margin_account;
+pub mod liquidate_obligation;
+
+
+pub use liquidate_borrow_position::*;
+pub use liquidate_margin_account::*;
+pub use liquidate_obligation::*;
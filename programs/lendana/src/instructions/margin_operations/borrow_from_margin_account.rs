@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, contexts::*, errors::*, constants::*, events::*}, utils::*, LoanTerms};
+
+
+/* BORROW FROM MARGIN ACCOUNT
+1. A margin account can borrow up to MAX_MARGIN_BORROWS different tokens; borrowing more of a token must use that borrow's terms and keeps its start
+2. The combined value of all borrows must stay within the basket's borrow limit
+3. remaining_accounts carry a [token config, price update, mint] triple per deposit, then a [token config, price update, mint] triple per borrow including this one */
+pub fn borrow_from_margin_account<'info>(ctx: Context<'_, '_, '_, 'info, BorrowFromMarginAccount<'info>>, borrowing_amount: u64, loan_terms: LoanTerms)
+-> Result<()> {
+
+    // Non-Zero Amount To Borrow
+    require!(borrowing_amount > 0, LendanaError::ZeroAmount);
+
+    // Validate Loan Terms
+    validate_loan_terms::validate_loan(loan_terms, &ctx.accounts.market)?;
+
+    // No New Borrows While The Protocol Or The Borrowed Token Is Paused Or Delisting
+    ensure_open_for_orders(&ctx.accounts.protocol_state, &ctx.accounts.borrowing_token_escrow)?;
+
+    // Respect The Borrowing Token Borrow Cap
+    ensure_within_cap(
+        ctx.accounts.borrowing_token_escrow.total_borrowed_tokens,
+        borrowing_amount,
+        ctx.accounts.borrowing_token_config.risk_parameters.borrow_cap,
+        LendanaError::BorrowCapExceeded,
+    )?;
+
+    let borrowing_token = ctx.accounts.token_to_borrow.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let margin_account = &mut ctx.accounts.margin_account;
+    require!(
+        !margin_account.collateral_deposits.iter().any(|deposit| deposit.token_mint == borrowing_token),
+        LendanaError::MarginTokenConflict
+    );
+
+    // Add To An Existing Borrow Under Its Own Terms And Start Once Its Interest Is Settled, Or Open A New One
+    let total_borrowed = match margin_account.borrows.iter_mut().find(|borrow| borrow.token_mint == borrowing_token) {
+        Some(borrow) => {
+            require!(borrow.borrowing_terms == loan_terms, LendanaError::LoanTermsMismatch);
+            settle_margin_debt_interest(borrow, now)?;
+            borrow.borrowing_amount = borrow.borrowing_amount
+                .checked_add(borrowing_amount)
+                .ok_or(LendanaError::TokenAdditionOverflow)?;
+            borrow.borrowing_amount
+        },
+        None => {
+            require!(margin_account.borrows.len() < MAX_MARGIN_BORROWS, LendanaError::TooManyMarginBorrows);
+            margin_account.borrows.push(MarginDebt {
+                token_mint: borrowing_token,
+                decimals: ctx.accounts.token_to_borrow.decimals,
+                borrowing_amount,
+                interest_owed: 0,
+                borrowing_terms: loan_terms,
+                borrowing_start: now,
+                interest_accrued_at: now,
+            });
+            borrowing_amount
+        }
+    };
+
+    // All Borrows Together Must Fit Within The Basket's Borrow Limit
+    let valuation = value_margin_account(&ctx.accounts.market.key(), margin_account, ctx.remaining_accounts, now)?;
+    require!(valuation.debt_value <= valuation.collateral.borrow_limit, LendanaError::MarginBorrowLimitExceeded);
+
+    // Transfer Borrow Tokens To Borrower, Less The Origination Fee Which Goes To The Treasury
+    let origination_fee = origination_fee(borrowing_amount, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.transfer_tokens_to_borrower(borrowing_amount - origination_fee, ctx.remaining_accounts)?;
+    let treasury_credit = ctx.accounts.transfer_origination_fee_to_treasury(origination_fee, ctx.remaining_accounts)?;
+
+    // Update Borrowing Token Escrow
+    let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
+    borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
+        .checked_add(borrowing_amount)
+        .ok_or(LendanaError::TokenAdditionOverflow)?;
+
+    record_treasury_fees(borrowing_token_escrow, treasury_credit)?;
+
+    emit_cpi!(MarginBorrowed {
+        borrower: ctx.accounts.borrower.key(),
+        borrowing_token,
+        amount: borrowing_amount,
+        borrowing_amount: total_borrowed,
+        borrowing_terms: loan_terms,
+        debt_value: valuation.debt_value,
+        borrow_limit: valuation.collateral.borrow_limit,
+        collateral_prices: valuation.collateral.deposits.iter().map(|deposit| deposit.price.into()).collect(),
+        borrow_prices: valuation.borrow_prices.into_iter().map(Into::into).collect(),
+        origination_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, contexts::*, errors::*, constants::*, events::*}, utils::{ensure_within_cap, required_sol_escrow, bind_sol_escrow}};
+
+
+/* DEPOSIT MARGIN COLLATERAL
+1. A margin account holds up to MAX_MARGIN_COLLATERALS collateral tokens, backing all of its borrows together
+2. Collateral in isolation mode cannot be cross-margined, and a token cannot be both collateral and borrowed */
+pub fn deposit_margin_collateral<'info>(ctx: Context<'_, '_, '_, 'info, DepositMarginCollateral<'info>>, amount: u64) -> Result<()> {
+
+    require!(amount > 0, LendanaError::ZeroAmount);
+    require!(!ctx.accounts.collateral_token_escrow.isolation_mode, LendanaError::IsolatedCollateralNotMarginable);
+
+    let collateral_token = ctx.accounts.token_collateral.key();
+
+    // Respect The Collateral Token Supply Cap
+    if collateral_token != NATIVE_SOL_MINT_ADDRESS {
+        ensure_within_cap(
+            ctx.accounts.collateral_token_escrow.total_lent_tokens,
+            amount,
+            ctx.accounts.collateral_token_config.risk_parameters.supply_cap,
+            LendanaError::SupplyCapExceeded,
+        )?;
+    }
+
+    // Lock The Deposited Collateral, Crediting Only What Reaches The Vault
+    let amount = ctx.accounts.lock_margin_collateral(amount, ctx.remaining_accounts)?;
+
+    let margin_account = &mut ctx.accounts.margin_account;
+
+    // The First Deposit Initializes The Margin Account
+    if margin_account.borrower_pubkey == Pubkey::default() {
+        margin_account.borrower_pubkey = ctx.accounts.borrower.key();
+        margin_account.margin_account_bump = ctx.bumps.margin_account;
+        margin_account.version = ACCOUNT_VERSION;
+    }
+
+    require!(
+        !margin_account.borrows.iter().any(|borrow| borrow.token_mint == collateral_token),
+        LendanaError::MarginTokenConflict
+    );
+
+    // Top Up An Existing Deposit, Or Add The Token To The Basket
+    let deposited_amount = match margin_account.collateral_deposits.iter_mut().find(|deposit| deposit.token_mint == collateral_token) {
+        Some(deposit) => {
+            deposit.amount = deposit.amount.checked_add(amount).ok_or(LendanaError::TokenAdditionOverflow)?;
+            deposit.amount
+        },
+        None => {
+            require!(margin_account.collateral_deposits.len() < MAX_MARGIN_COLLATERALS, LendanaError::TooManyCollateralDeposits);
+            margin_account.collateral_deposits.push(CollateralDeposit {
+                token_mint: collateral_token,
+                decimals: ctx.accounts.token_collateral.decimals,
+                amount,
+                is_isolated: false,
+            });
+            amount
+        }
+    };
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if collateral_token == NATIVE_SOL_MINT_ADDRESS {
+        let position_key = ctx.accounts.margin_account.key();
+        let sol_collateral_escrow = required_sol_escrow(&mut ctx.accounts.sol_collateral_escrow)?;
+        bind_sol_escrow(sol_collateral_escrow, position_key, ctx.accounts.borrower.key(), ctx.bumps.sol_collateral_escrow.unwrap_or_default());
+    } else {
+        let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_add(amount)
+            .ok_or(LendanaError::TokenAdditionOverflow)?;
+    }
+
+    emit_cpi!(MarginCollateralDeposited {
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: collateral_token,
+        amount,
+        deposited_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
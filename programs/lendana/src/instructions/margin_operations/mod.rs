@@ -0,0 +1,10 @@
+pub mod borrow_from_margin_account;
+pub mod deposit_margin_collateral;
+pub mod repay_margin_debt;
+pub mod withdraw_margin_collateral;
+
+
+pub use borrow_from_margin_account::*;
+pub use deposit_margin_collateral::*;
+pub use repay_margin_debt::*;
+pub use withdraw_margin_collateral::*;
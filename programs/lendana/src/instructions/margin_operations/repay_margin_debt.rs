@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, events::*}, utils::{apply_margin_repayment, settle_margin_debt_interest, reserve_fee, record_treasury_fees}};
+
+
+/* REPAY MARGIN DEBT
+1. Repayments are capped at the borrow's outstanding debt, and pay settled interest before principal
+2. A fully repaid borrow is removed from the margin account */
+pub fn repay_margin_debt<'info>(ctx: Context<'_, '_, '_, 'info, RepayMarginDebt<'info>>, repay_amount: u64) -> Result<()> {
+
+    require!(repay_amount > 0, LendanaError::ZeroAmount);
+
+    let borrowing_token = ctx.accounts.token_to_borrow.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let margin_account = &mut ctx.accounts.margin_account;
+    let index = margin_account.borrows.iter()
+        .position(|borrow| borrow.token_mint == borrowing_token)
+        .ok_or(LendanaError::MarginDebtNotFound)?;
+
+    let borrow = &mut margin_account.borrows[index];
+    settle_margin_debt_interest(borrow, now)?;
+    let outstanding_debt = borrow.borrowing_amount
+        .checked_add(borrow.interest_owed)
+        .ok_or(LendanaError::MathOverflow)?;
+
+    let repay_amount = repay_amount.min(outstanding_debt);
+    let (interest_paid, principal_paid) = apply_margin_repayment(borrow, repay_amount);
+    let remaining_principal = borrow.borrowing_amount;
+    if borrow.borrowing_amount == 0 && borrow.interest_owed == 0 {
+        margin_account.borrows.remove(index);
+    }
+
+    // Return The Tokens To The Borrowing Vault, Less The Reserve Factor's Cut Of The Interest
+    let reserve_fee = reserve_fee(interest_paid, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.wrap_repayment(repay_amount)?;
+    ctx.accounts.repay_tokens_to_borrow_vault(repay_amount - reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.pay_reserve_fee_to_treasury(reserve_fee, ctx.remaining_accounts)?;
+
+    // Update Borrowing Token Escrow: interest stays in the vault on top of the lent tokens
+    let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
+    borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
+        .checked_sub(principal_paid)
+        .ok_or(LendanaError::InsufficientBorrowedTokens)?;
+
+    record_treasury_fees(borrowing_token_escrow, reserve_fee)?;
+
+    emit_cpi!(MarginDebtRepaid {
+        borrower: ctx.accounts.borrower.key(),
+        borrowing_token,
+        interest_paid,
+        principal_paid,
+        borrowing_amount: remaining_principal,
+        reserve_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
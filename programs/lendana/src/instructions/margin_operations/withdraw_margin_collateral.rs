@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, constants::*, events::*}, utils::value_margin_account};
+
+
+/* WITHDRAW MARGIN COLLATERAL
+1. While the account has borrows, their combined value must stay within the reduced basket's borrow limit
+2. remaining_accounts carry a [token config, price update, mint] triple per remaining deposit, then a [token config, price update, mint] triple per borrow */
+pub fn withdraw_margin_collateral<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawMarginCollateral<'info>>, amount: u64) -> Result<()> {
+
+    require!(amount > 0, LendanaError::ZeroAmount);
+
+    let collateral_token = ctx.accounts.token_collateral.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let margin_account = &mut ctx.accounts.margin_account;
+    let index = margin_account.collateral_deposits.iter()
+        .position(|deposit| deposit.token_mint == collateral_token)
+        .ok_or(LendanaError::CollateralNotDeposited)?;
+
+    // Reduce The Deposit, Dropping It From The Basket Once Empty
+    let deposit = &mut margin_account.collateral_deposits[index];
+    deposit.amount = deposit.amount.checked_sub(amount).ok_or(LendanaError::InsufficientCollateral)?;
+    let remaining_amount = deposit.amount;
+    if remaining_amount == 0 {
+        margin_account.collateral_deposits.remove(index);
+    }
+
+    // All Borrows Must Stay Within The Borrow Limit Of The Reduced Basket
+    let (mut collateral_prices, mut borrow_prices) = (Vec::new(), Vec::new());
+    if !margin_account.borrows.is_empty() {
+        let valuation = value_margin_account(&ctx.accounts.market.key(), margin_account, ctx.remaining_accounts, now)?;
+        require!(valuation.debt_value <= valuation.collateral.borrow_limit, LendanaError::MarginBorrowLimitExceeded);
+        collateral_prices = valuation.collateral.deposits.iter().map(|deposit| deposit.price.into()).collect();
+        borrow_prices = valuation.borrow_prices.into_iter().map(Into::into).collect();
+    }
+
+    // Release The Collateral
+    ctx.accounts.unlock_margin_collateral(amount, ctx.remaining_accounts)?;
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if collateral_token != NATIVE_SOL_MINT_ADDRESS {
+        let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_sub(amount)
+            .ok_or(LendanaError::InsufficientLentTokens)?;
+    }
+
+    emit_cpi!(MarginCollateralWithdrawn {
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: collateral_token,
+        amount,
+        remaining_amount,
+        collateral_prices,
+        borrow_prices,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+
+use crate::states::{accounts::*, constants::*, contexts::*, errors::*, events::*};
+
+
+/* OPEN A CURATED MARKET
+1. Anyone can open one, under any unused market id, posting the creation deposit into the market account
+2. The creator names its curator, who lists its tokens, price feeds and risk parameters through the timelock,
+in place of the protocol's roles and within the same program-wide bounds
+3. The creator picks which of the supported loan durations the market offers */
+pub fn open_curated_market(ctx: Context<CreateCuratedMarket>, market_id: u64, name: String, curator: Pubkey,
+    lending_durations: Vec<u64>) -> Result<()> {
+
+    require!(name.len() <= MAX_MARKET_NAME_LEN, LendanaError::InvalidMarketName);
+    require!(!lending_durations.is_empty(), LendanaError::InvalidLendingDurations);
+    for (index, duration) in lending_durations.iter().enumerate() {
+        require!(
+            SUPPORTED_LENDING_DURATIONS.contains(duration) && !lending_durations[..index].contains(duration),
+            LendanaError::InvalidLendingDurations
+        );
+    }
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.creator.to_account_info(),
+        to: ctx.accounts.market.to_account_info(),
+    };
+    transfer(CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts), MARKET_CREATION_DEPOSIT)?;
+
+    let creator = ctx.accounts.creator.key();
+    ctx.accounts.market.set_inner(Market {
+        market_id,
+        name: name.clone(),
+        creator,
+        curator: Some(curator),
+        lending_durations: lending_durations.clone(),
+        creation_deposit: MARKET_CREATION_DEPOSIT,
+        listed_tokens: 0,
+        is_closed: false,
+        market_bump: ctx.bumps.market,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    // Initializing The Market's Lender Position ID Counter
+    let lender_position_counter = &mut ctx.accounts.lenders_position_id_counter;
+    lender_position_counter.lenders_current_position_id = 0;
+    lender_position_counter.lender_position_id_bump = ctx.bumps.lenders_position_id_counter;
+    lender_position_counter.version = ACCOUNT_VERSION;
+
+    // Initializing The Market's Borrower Position ID Counter
+    let borrower_position_counter = &mut ctx.accounts.borrowers_position_id_counter;
+    borrower_position_counter.borrowers_current_position_id = 0;
+    borrower_position_counter.borrower_position_id_bump = ctx.bumps.borrowers_position_id_counter;
+    borrower_position_counter.version = ACCOUNT_VERSION;
+
+    emit_cpi!(CuratedMarketCreated {
+        market: ctx.accounts.market.key(),
+        market_id,
+        name,
+        creator,
+        curator,
+        lending_durations,
+        creation_deposit: MARKET_CREATION_DEPOSIT,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* HAND OVER A CURATED MARKET
+1. Only the current curator can, and it takes effect at once; changes already queued keep their proposer */
+pub fn change_market_curator(ctx: Context<SetMarketCurator>, new_curator: Pubkey) -> Result<()> {
+
+    let previous_curator = ctx.accounts.curator.key();
+    ctx.accounts.market.curator = Some(new_curator);
+
+    emit_cpi!(MarketCuratorChanged {
+        market: ctx.accounts.market.key(),
+        previous_curator,
+        new_curator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* CLOSE A CURATED MARKET
+1. Only its creator can, once every token it listed has been fully delisted, or before it lists any
+2. The creation deposit goes back to the creator; the market account and its position id counters keep their rent
+and stay behind, the market marked closed, so its id is never opened again over the accounts it still seeds */
+pub fn close_market(ctx: Context<CloseCuratedMarket>) -> Result<()> {
+
+    let creation_deposit = ctx.accounts.market.creation_deposit;
+    ctx.accounts.market.sub_lamports(creation_deposit)?;
+    ctx.accounts.creator.add_lamports(creation_deposit)?;
+
+    let market = &mut ctx.accounts.market;
+    market.creation_deposit = 0;
+    market.is_closed = true;
+
+    emit_cpi!(CuratedMarketClosed {
+        market: ctx.accounts.market.key(),
+        market_id: ctx.accounts.market.market_id,
+        creator: ctx.accounts.creator.key(),
+        creation_deposit,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,4 @@
+pub mod curated_markets;
+
+
+pub use curated_markets::*;
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, contexts::*, events::*}, utils::{migrate_account, VersionedAccount}};
+
+
+/* Dispatches On The Named Account Type, So One Instruction Migrates Every Versioned Account */
+pub fn migrate_account_layout<'info>(ctx: Context<'_, '_, '_, 'info, MigrateAccount<'info>>, kind: MigratableAccount) -> Result<()> {
+    match kind {
+        MigratableAccount::Administrator => migrate_versioned_account::<Administrator>(ctx),
+        MigratableAccount::TrustedEntities => migrate_versioned_account::<TrustedEntities>(ctx),
+        MigratableAccount::ProtocolState => migrate_versioned_account::<ProtocolState>(ctx),
+        MigratableAccount::Market => migrate_versioned_account::<Market>(ctx),
+        MigratableAccount::Timelock => migrate_versioned_account::<Timelock>(ctx),
+        MigratableAccount::TimelockProposal => migrate_versioned_account::<TimelockProposal>(ctx),
+        MigratableAccount::AdminMultisig => migrate_versioned_account::<AdminMultisig>(ctx),
+        MigratableAccount::MultisigTransaction => migrate_versioned_account::<MultisigTransaction>(ctx),
+        MigratableAccount::ListingGovernance => migrate_versioned_account::<ListingGovernance>(ctx),
+        MigratableAccount::ListingProposal => migrate_versioned_account::<ListingProposal>(ctx),
+        MigratableAccount::ListingVote => migrate_versioned_account::<ListingVote>(ctx),
+        MigratableAccount::SolCollateralEscrow => migrate_versioned_account::<SolCollateralEscrow>(ctx),
+        MigratableAccount::LenderPositionIDCounter => migrate_versioned_account::<LenderPositionIDCounter>(ctx),
+        MigratableAccount::BorrowerPositionIDCounter => migrate_versioned_account::<BorrowerPositionIDCounter>(ctx),
+        MigratableAccount::TokenEscrow => migrate_versioned_account::<LentBorrowedTokenEscrow>(ctx),
+        MigratableAccount::TokenConfig => migrate_versioned_account::<TokenConfig>(ctx),
+        MigratableAccount::EModeCategory => migrate_versioned_account::<EModeCategory>(ctx),
+        MigratableAccount::LenderPosition => migrate_versioned_account::<LenderPosition>(ctx),
+        MigratableAccount::BorrowerPosition => migrate_versioned_account::<BorrowerPosition>(ctx),
+        MigratableAccount::BorrowerObligation => migrate_versioned_account::<BorrowerObligation>(ctx),
+        MigratableAccount::MarginAccount => migrate_versioned_account::<MarginAccount>(ctx),
+        MigratableAccount::TokenTreasury => migrate_versioned_account::<TokenTreasury>(ctx),
+    }
+}
+
+/* MIGRATE AN ACCOUNT TO THE CURRENT LAYOUT
+1. The caller names the account's type, which picks the layout to decode it with; the owner and discriminator
+must still match it
+2. Older, shorter accounts are reallocated to the current size; the payer covers the extra rent
+3. Every existing field, and any funds the account holds, are kept; new fields start zeroed
+4. It is permissionless, since it changes nothing but the layout, and fails once the account is current */
+pub fn migrate_versioned_account<'info, T>(ctx: Context<'_, '_, '_, 'info, MigrateAccount<'info>>) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Space + VersionedAccount,
+{
+    let previous_version = migrate_account::<T>(
+        &ctx.accounts.migrated_account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit_cpi!(AccountMigrated {
+        account: ctx.accounts.migrated_account.key(),
+        previous_version,
+        version: ACCOUNT_VERSION,
+        payer: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
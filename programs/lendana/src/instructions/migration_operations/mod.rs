@@ -0,0 +1,4 @@
+pub mod migrate_accounts;
+
+
+pub use migrate_accounts::*;
\ No newline at end of file
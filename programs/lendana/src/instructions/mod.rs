@@ -1,6 +1,14 @@
 pub mod admin_operations;
 pub mod borrower_operations;
 pub mod lender_operations;
+pub mod liquidator_operations;
+pub mod margin_operations;
+pub mod flash_loan_operations;
+pub mod obligation_operations;
+pub mod multisig_operations;
+pub mod governance_operations;
+pub mod migration_operations;
+pub mod market_operations;
 pub mod vault_token_operations;
 pub mod utils;
 
@@ -8,5 +16,13 @@ pub mod utils;
 pub use admin_operations::*;
 pub use borrower_operations::*;
 pub use lender_operations::*;
+pub use liquidator_operations::*;
+pub use margin_operations::*;
+pub use flash_loan_operations::*;
+pub use obligation_operations::*;
+pub use multisig_operations::*;
+pub use governance_operations::*;
+pub use migration_operations::*;
+pub use market_operations::*;
 //pub use vault_token_operations::*;
 pub use utils::*;
\ No newline at end of file
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{constants::*, contexts::*, events::*}, utils::validate_multisig_owners};
+
+
+/* CREATE THE ADMIN MULTISIG
+1. Only the current admin can create it, and only once
+2. It holds no power yet: the admin hands over by proposing the multisig signer as the next admin through the timelock,
+and the multisig then executes `accept_admin`
+3. The multisig signer pays for what it creates, e.g. timelock proposals, so it needs to be funded with SOL */
+pub fn initialize_admin_multisig(ctx: Context<CreateAdminMultisig>, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+
+    validate_multisig_owners(&owners, threshold)?;
+
+    let admin_multisig = &mut ctx.accounts.admin_multisig;
+    admin_multisig.owners = owners.clone();
+    admin_multisig.threshold = threshold;
+    admin_multisig.owner_set_seqno = 0;
+    admin_multisig.next_transaction_id = 0;
+    admin_multisig.multisig_bump = ctx.bumps.admin_multisig;
+    admin_multisig.signer_bump = ctx.bumps.multisig_signer;
+    admin_multisig.version = ACCOUNT_VERSION;
+
+    emit_cpi!(AdminMultisigCreated {
+        owners,
+        threshold,
+        multisig_signer: ctx.accounts.multisig_signer.key(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* CHANGE THE MULTISIG OWNERS OR THRESHOLD
+1. Signed by the multisig signer, so it takes a transaction approved by the current owners
+2. Transactions proposed before the change can no longer be approved or executed */
+pub fn update_admin_multisig(ctx: Context<UpdateAdminMultisig>, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+
+    validate_multisig_owners(&owners, threshold)?;
+
+    let admin_multisig = &mut ctx.accounts.admin_multisig;
+    admin_multisig.owners = owners.clone();
+    admin_multisig.threshold = threshold;
+    admin_multisig.owner_set_seqno = admin_multisig.owner_set_seqno.wrapping_add(1);
+
+    emit_cpi!(AdminMultisigUpdated {
+        owners,
+        threshold,
+        owner_set_seqno: admin_multisig.owner_set_seqno,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod admin_multisig;
+pub mod multisig_transactions;
+
+
+pub use admin_multisig::*;
+pub use multisig_transactions::*;
\ No newline at end of file
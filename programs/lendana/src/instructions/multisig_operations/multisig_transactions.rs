@@ -0,0 +1,111 @@
+use anchor_lang::{prelude::*, solana_program::{instruction::{AccountMeta, Instruction}, program::invoke_signed}};
+
+use crate::states::{accounts::*, constants::*, contexts::*, errors::*, events::*};
+
+
+/* PROPOSE A MULTISIG TRANSACTION
+1. Any owner proposes a Lendana instruction, given as its accounts and serialized data, and approves it by doing so
+2. The multisig signer is marked as a signer when executed, so it can stand in wherever the admin signs */
+pub fn create_multisig_transaction(ctx: Context<ProposeMultisigTransaction>, accounts: Vec<TransactionAccount>, data: Vec<u8>) -> Result<()> {
+
+    require!(
+        accounts.len() <= MAX_MULTISIG_TRANSACTION_ACCOUNTS && data.len() <= MAX_MULTISIG_TRANSACTION_DATA,
+        LendanaError::MultisigTransactionTooLarge
+    );
+
+    let owner = ctx.accounts.owner.key();
+    let admin_multisig = &mut ctx.accounts.admin_multisig;
+    let transaction_id = admin_multisig.next_transaction_id;
+    admin_multisig.next_transaction_id = transaction_id.checked_add(1).ok_or(LendanaError::MathOverflow)?;
+
+    ctx.accounts.multisig_transaction.set_inner(MultisigTransaction {
+        transaction_id,
+        proposer: owner,
+        accounts,
+        data,
+        approvals: vec![owner],
+        owner_set_seqno: admin_multisig.owner_set_seqno,
+        executed: false,
+        transaction_bump: ctx.bumps.multisig_transaction,
+        version: ACCOUNT_VERSION,
+        reserved: [0; 64],
+    });
+
+    emit_cpi!(MultisigTransactionProposed {
+        transaction_id,
+        proposer: owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* APPROVE A MULTISIG TRANSACTION */
+pub fn sign_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+
+    let owner = ctx.accounts.owner.key();
+    let multisig_transaction = &mut ctx.accounts.multisig_transaction;
+
+    require!(!multisig_transaction.executed, LendanaError::MultisigTransactionExecuted);
+    require!(
+        multisig_transaction.owner_set_seqno == ctx.accounts.admin_multisig.owner_set_seqno,
+        LendanaError::StaleMultisigTransaction
+    );
+    require!(!multisig_transaction.approvals.contains(&owner), LendanaError::AlreadyApproved);
+
+    multisig_transaction.approvals.push(owner);
+
+    emit_cpi!(MultisigTransactionApproved {
+        transaction_id: multisig_transaction.transaction_id,
+        owner,
+        approvals: multisig_transaction.approvals.len() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/* EXECUTE A MULTISIG TRANSACTION
+1. Once approvals reach the threshold, anyone can execute it, passing its accounts as remaining accounts
+2. The instruction is invoked on Lendana with the multisig signer PDA signing; it runs once */
+pub fn run_multisig_transaction<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteMultisigTransaction<'info>>) -> Result<()> {
+
+    let admin_multisig = &ctx.accounts.admin_multisig;
+    let multisig_transaction = &mut ctx.accounts.multisig_transaction;
+
+    require!(!multisig_transaction.executed, LendanaError::MultisigTransactionExecuted);
+    require!(multisig_transaction.owner_set_seqno == admin_multisig.owner_set_seqno, LendanaError::StaleMultisigTransaction);
+    require!(
+        multisig_transaction.approvals.len() >= admin_multisig.threshold as usize,
+        LendanaError::MultisigThresholdNotMet
+    );
+    multisig_transaction.executed = true;
+
+    let multisig_signer = ctx.accounts.multisig_signer.key();
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: multisig_transaction.accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.pubkey,
+                is_signer: account.is_signer || account.pubkey == multisig_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: multisig_transaction.data.clone(),
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.lendana_program.to_account_info());
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"admin_multisig_signer", &[admin_multisig.signer_bump]]];
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+
+    emit_cpi!(MultisigTransactionExecuted {
+        transaction_id: multisig_transaction.transaction_id,
+        executor: ctx.accounts.executor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
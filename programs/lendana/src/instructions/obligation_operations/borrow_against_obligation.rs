@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, events::*}, utils::*, LoanTerms};
+
+
+/* BORROW AGAINST OBLIGATION
+1. The obligation carries one debt token under one set of loan terms; borrows added to existing debt must use those terms
+and keep its start, so topping up never pushes the maturity out
+2. The debt must stay within the basket's borrow limit, the sum of each deposit's value at its max LTV
+3. remaining_accounts carry a [token config, price update, mint] triple per deposit */
+pub fn borrow_against_obligation<'info>(ctx: Context<'_, '_, '_, 'info, BorrowAgainstObligation<'info>>, borrowing_amount: u64, loan_terms: LoanTerms)
+-> Result<()> {
+
+    // Non-Zero Amount To Borrow
+    require!(borrowing_amount > 0, LendanaError::ZeroAmount);
+
+    // Validate Loan Terms
+    validate_loan_terms::validate_loan(loan_terms, &ctx.accounts.market)?;
+
+    // No New Borrows While The Protocol Or The Borrowed Token Is Paused Or Delisting
+    ensure_open_for_orders(&ctx.accounts.protocol_state, &ctx.accounts.borrowing_token_escrow)?;
+
+    // Respect The Borrowing Token Borrow Cap
+    ensure_within_cap(
+        ctx.accounts.borrowing_token_escrow.total_borrowed_tokens,
+        borrowing_amount,
+        ctx.accounts.borrowing_token_config.risk_parameters.borrow_cap,
+        LendanaError::BorrowCapExceeded,
+    )?;
+
+    let borrowing_token = ctx.accounts.token_to_borrow.key();
+    let now = Clock::get()?.unix_timestamp;
+    let borrowing_token_config = &ctx.accounts.borrowing_token_config;
+
+    let obligation = &mut ctx.accounts.obligation;
+
+    // Existing Debt Must Be In The Same Token And Terms, And Its Interest Is Settled Before The Principal Grows
+    if obligation.borrowing_amount > 0 || obligation.interest_owed > 0 {
+        require_keys_eq!(obligation.borrowing_token, borrowing_token, LendanaError::ObligationDebtTokenMismatch);
+        require!(obligation.borrowing_terms == loan_terms, LendanaError::LoanTermsMismatch);
+        settle_obligation_interest(obligation, now)?;
+    } else {
+        obligation.borrowing_token = borrowing_token;
+        obligation.borrowing_token_decimals = ctx.accounts.token_to_borrow.decimals;
+        obligation.borrowing_terms = loan_terms;
+        obligation.borrowing_start = now;
+    }
+    obligation.borrowing_amount = obligation.borrowing_amount
+        .checked_add(borrowing_amount)
+        .ok_or(LendanaError::TokenAdditionOverflow)?;
+    obligation.interest_accrued_at = now;
+
+    // The Whole Debt Must Fit Within The Basket's Borrow Limit
+    let valuation = value_collateral_basket(&ctx.accounts.market.key(), &obligation.collateral_deposits, ctx.remaining_accounts)?;
+    let debt_value = obligation_debt_value(obligation, Some(borrowing_token_config), Some(&ctx.accounts.borrowing_price_update), Some(&ctx.accounts.token_to_borrow.to_account_info()), now)?;
+    require!(debt_value <= valuation.borrow_limit, LendanaError::ObligationBorrowLimitExceeded);
+    let borrowing_price = get_mint_price(borrowing_token_config, &ctx.accounts.borrowing_price_update, &ctx.accounts.token_to_borrow.to_account_info())?;
+
+    // Count The Borrow Against The Isolated Collateral's Debt Ceiling, If The Basket Holds One
+    let isolated_collateral = obligation.collateral_deposits.iter()
+        .find(|deposit| deposit.is_isolated)
+        .map(|deposit| deposit.token_mint);
+    if let Some(isolated_collateral) = isolated_collateral {
+        // Borrowing The Isolated Token Itself Shares The Borrowing Escrow
+        let collateral_escrow = if isolated_collateral == borrowing_token {
+            &mut ctx.accounts.borrowing_token_escrow
+        } else {
+            ctx.accounts.isolated_collateral_escrow.as_mut()
+                .filter(|escrow| escrow.lending_borrowing_token == isolated_collateral)
+                .ok_or(LendanaError::MissingIsolatedCollateralEscrow)?
+        };
+
+        let borrow_value = token_usd_value(borrowing_amount, obligation.borrowing_token_decimals, &borrowing_price)?;
+        let added_debt = add_isolated_debt(collateral_escrow, &ctx.accounts.borrowing_token_config.risk_parameters, borrow_value)?;
+
+        obligation.isolated_debt = obligation.isolated_debt.checked_add(added_debt).ok_or(LendanaError::MathOverflow)?;
+    }
+
+    // Transfer Borrow Tokens To Borrower, Less The Origination Fee Which Goes To The Treasury
+    let origination_fee = origination_fee(borrowing_amount, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.transfer_tokens_to_borrower(borrowing_amount - origination_fee, ctx.remaining_accounts)?;
+    let treasury_credit = ctx.accounts.transfer_origination_fee_to_treasury(origination_fee, ctx.remaining_accounts)?;
+
+    // Update Borrowing Token Escrow
+    let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
+    borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
+        .checked_add(borrowing_amount)
+        .ok_or(LendanaError::TokenAdditionOverflow)?;
+
+    record_treasury_fees(borrowing_token_escrow, treasury_credit)?;
+
+    emit_cpi!(ObligationBorrowed {
+        borrower: ctx.accounts.borrower.key(),
+        borrowing_token,
+        amount: borrowing_amount,
+        borrowing_amount: ctx.accounts.obligation.borrowing_amount,
+        borrowing_terms: loan_terms,
+        debt_value,
+        borrow_limit: valuation.borrow_limit,
+        collateral_prices: valuation.deposits.iter().map(|deposit| deposit.price.into()).collect(),
+        borrowing_price: borrowing_price.into(),
+        origination_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
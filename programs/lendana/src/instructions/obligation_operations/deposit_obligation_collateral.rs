@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, contexts::*, errors::*, constants::*, events::*}, utils::{ensure_within_cap, required_sol_escrow, bind_sol_escrow}};
+
+
+/* DEPOSIT OBLIGATION COLLATERAL
+1. An obligation holds up to MAX_OBLIGATION_COLLATERALS different collateral tokens
+2. Collateral in isolation mode can only be held on its own */
+pub fn deposit_obligation_collateral<'info>(ctx: Context<'_, '_, '_, 'info, DepositObligationCollateral<'info>>, amount: u64) -> Result<()> {
+
+    require!(amount > 0, LendanaError::ZeroAmount);
+
+    let collateral_token = ctx.accounts.token_collateral.key();
+    let is_isolated = ctx.accounts.collateral_token_escrow.isolation_mode;
+
+    // Respect The Collateral Token Supply Cap
+    if collateral_token != NATIVE_SOL_MINT_ADDRESS {
+        ensure_within_cap(
+            ctx.accounts.collateral_token_escrow.total_lent_tokens,
+            amount,
+            ctx.accounts.collateral_token_config.risk_parameters.supply_cap,
+            LendanaError::SupplyCapExceeded,
+        )?;
+    }
+
+    // Lock The Deposited Collateral, Crediting Only What Reaches The Vault
+    let amount = ctx.accounts.lock_obligation_collateral(amount, ctx.remaining_accounts)?;
+
+    let obligation = &mut ctx.accounts.obligation;
+
+    // The First Deposit Initializes The Obligation
+    if obligation.borrower_pubkey == Pubkey::default() {
+        obligation.borrower_pubkey = ctx.accounts.borrower.key();
+        obligation.obligation_bump = ctx.bumps.obligation;
+        obligation.version = ACCOUNT_VERSION;
+    }
+
+    // Isolated Collateral Cannot Share The Basket With Other Tokens
+    let holds_other_tokens = obligation.collateral_deposits.iter().any(|deposit| deposit.token_mint != collateral_token);
+    let holds_isolated = obligation.collateral_deposits.iter().any(|deposit| deposit.is_isolated);
+    require!(!(holds_other_tokens && (is_isolated || holds_isolated)), LendanaError::IsolatedCollateralNotSole);
+
+    // Top Up An Existing Deposit, Or Add The Token To The Basket
+    let deposited_amount = match obligation.collateral_deposits.iter_mut().find(|deposit| deposit.token_mint == collateral_token) {
+        Some(deposit) => {
+            deposit.amount = deposit.amount.checked_add(amount).ok_or(LendanaError::TokenAdditionOverflow)?;
+            deposit.is_isolated = is_isolated;
+            deposit.amount
+        },
+        None => {
+            require!(obligation.collateral_deposits.len() < MAX_OBLIGATION_COLLATERALS, LendanaError::TooManyCollateralDeposits);
+            obligation.collateral_deposits.push(CollateralDeposit {
+                token_mint: collateral_token,
+                decimals: ctx.accounts.token_collateral.decimals,
+                amount,
+                is_isolated,
+            });
+            amount
+        }
+    };
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if collateral_token == NATIVE_SOL_MINT_ADDRESS {
+        let position_key = ctx.accounts.obligation.key();
+        let sol_collateral_escrow = required_sol_escrow(&mut ctx.accounts.sol_collateral_escrow)?;
+        bind_sol_escrow(sol_collateral_escrow, position_key, ctx.accounts.borrower.key(), ctx.bumps.sol_collateral_escrow.unwrap_or_default());
+    } else {
+        let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_add(amount)
+            .ok_or(LendanaError::TokenAdditionOverflow)?;
+    }
+
+    emit_cpi!(ObligationCollateralDeposited {
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: collateral_token,
+        amount,
+        deposited_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+pub mod borrow_against_obligation;
+pub mod deposit_obligation_collateral;
+pub mod repay_obligation_debt;
+pub mod withdraw_obligation_collateral;
+
+
+pub use borrow_against_obligation::*;
+pub use deposit_obligation_collateral::*;
+pub use repay_obligation_debt::*;
+pub use withdraw_obligation_collateral::*;
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, events::*}, utils::{apply_obligation_repayment, release_isolated_debt, settle_obligation_interest, reserve_fee, record_treasury_fees}};
+
+
+/* REPAY OBLIGATION DEBT
+1. Repayments are capped at the outstanding debt, and pay settled interest before principal
+2. Repaid principal frees its share of any isolated collateral's debt ceiling */
+pub fn repay_obligation_debt<'info>(ctx: Context<'_, '_, '_, 'info, RepayObligationDebt<'info>>, repay_amount: u64) -> Result<()> {
+
+    require!(repay_amount > 0, LendanaError::ZeroAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let obligation = &mut ctx.accounts.obligation;
+
+    settle_obligation_interest(obligation, now)?;
+    let outstanding_debt = obligation.borrowing_amount
+        .checked_add(obligation.interest_owed)
+        .ok_or(LendanaError::MathOverflow)?;
+    require!(outstanding_debt > 0, LendanaError::NoOutstandingDebt);
+
+    let repay_amount = repay_amount.min(outstanding_debt);
+    let isolated_collateral = obligation.collateral_deposits.iter()
+        .find(|deposit| deposit.is_isolated)
+        .map(|deposit| deposit.token_mint);
+    let (interest_paid, principal_paid, released_isolated_debt) = apply_obligation_repayment(obligation, repay_amount)?;
+
+    // Free The Repaid Share Of The Isolated Collateral's Debt Ceiling
+    if let (Some(isolated_collateral), true) = (isolated_collateral, released_isolated_debt > 0) {
+        // Debt In The Isolated Token Itself Shares The Borrowing Escrow
+        let collateral_escrow = if isolated_collateral == ctx.accounts.token_to_borrow.key() {
+            &mut ctx.accounts.borrowing_token_escrow
+        } else {
+            ctx.accounts.isolated_collateral_escrow.as_mut()
+                .filter(|escrow| escrow.lending_borrowing_token == isolated_collateral)
+                .ok_or(LendanaError::MissingIsolatedCollateralEscrow)?
+        };
+        release_isolated_debt(collateral_escrow, released_isolated_debt);
+    }
+
+    // Return The Tokens To The Borrowing Vault, Less The Reserve Factor's Cut Of The Interest
+    let reserve_fee = reserve_fee(interest_paid, &ctx.accounts.borrowing_token_config.risk_parameters)?;
+    ctx.accounts.wrap_repayment(repay_amount)?;
+    ctx.accounts.repay_tokens_to_borrow_vault(repay_amount - reserve_fee, ctx.remaining_accounts)?;
+    ctx.accounts.pay_reserve_fee_to_treasury(reserve_fee, ctx.remaining_accounts)?;
+
+    // Update Borrowing Token Escrow: interest stays in the vault on top of the lent tokens
+    let borrowing_token_escrow = &mut ctx.accounts.borrowing_token_escrow;
+    borrowing_token_escrow.total_borrowed_tokens = borrowing_token_escrow.total_borrowed_tokens
+        .checked_sub(principal_paid)
+        .ok_or(LendanaError::InsufficientBorrowedTokens)?;
+
+    record_treasury_fees(borrowing_token_escrow, reserve_fee)?;
+
+    emit_cpi!(ObligationRepaid {
+        borrower: ctx.accounts.borrower.key(),
+        borrowing_token: ctx.accounts.token_to_borrow.key(),
+        interest_paid,
+        principal_paid,
+        borrowing_amount: ctx.accounts.obligation.borrowing_amount,
+        reserve_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{contexts::*, errors::*, constants::*, events::*}, utils::{obligation_debt_value, value_collateral_basket}};
+
+
+/* WITHDRAW OBLIGATION COLLATERAL
+1. While the obligation has debt, the remaining basket must still cover it at each token's max LTV
+2. remaining_accounts carry a [token config, price update, mint] triple per remaining deposit */
+pub fn withdraw_obligation_collateral<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawObligationCollateral<'info>>, amount: u64) -> Result<()> {
+
+    require!(amount > 0, LendanaError::ZeroAmount);
+
+    let collateral_token = ctx.accounts.token_collateral.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let obligation = &mut ctx.accounts.obligation;
+    let index = obligation.collateral_deposits.iter()
+        .position(|deposit| deposit.token_mint == collateral_token)
+        .ok_or(LendanaError::CollateralNotDeposited)?;
+
+    // Reduce The Deposit, Dropping It From The Basket Once Empty
+    let deposit = &mut obligation.collateral_deposits[index];
+    deposit.amount = deposit.amount.checked_sub(amount).ok_or(LendanaError::InsufficientCollateral)?;
+    let remaining_amount = deposit.amount;
+    if remaining_amount == 0 {
+        obligation.collateral_deposits.remove(index);
+    }
+
+    // Any Outstanding Debt Must Stay Within The Borrow Limit Of The Reduced Basket
+    let debt_value = obligation_debt_value(
+        obligation,
+        ctx.accounts.borrowing_token_config.as_deref().map(|config| &**config),
+        ctx.accounts.borrowing_price_update.as_deref(),
+        ctx.accounts.token_to_borrow.as_ref().map(|mint| mint.to_account_info()).as_ref(),
+        now,
+    )?;
+    let mut collateral_prices = Vec::new();
+    if debt_value > 0 {
+        let valuation = value_collateral_basket(&ctx.accounts.market.key(), &obligation.collateral_deposits, ctx.remaining_accounts)?;
+        require!(debt_value <= valuation.borrow_limit, LendanaError::ObligationBorrowLimitExceeded);
+        collateral_prices = valuation.deposits.iter().map(|deposit| deposit.price.into()).collect();
+    }
+
+    // Release The Collateral
+    ctx.accounts.unlock_obligation_collateral(amount, ctx.remaining_accounts)?;
+
+    // Update Collateral Token Escrow: Native SOL Collateral Is Recorded On The Position's Own Escrow Instead
+    if collateral_token != NATIVE_SOL_MINT_ADDRESS {
+        let collateral_token_escrow = &mut ctx.accounts.collateral_token_escrow;
+        collateral_token_escrow.total_lent_tokens = collateral_token_escrow.total_lent_tokens
+            .checked_sub(amount)
+            .ok_or(LendanaError::InsufficientLentTokens)?;
+    }
+
+    emit_cpi!(ObligationCollateralWithdrawn {
+        borrower: ctx.accounts.borrower.key(),
+        token_mint: collateral_token,
+        amount,
+        remaining_amount,
+        collateral_prices,
+        timestamp: now,
+    });
+
+    Ok(())
+}
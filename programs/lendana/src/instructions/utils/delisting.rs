@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{accounts::*, errors::*};
+
+
+/* Max LTV Along The Wind-Down: Lowered Linearly From The Start Value To The Target, Then Held At The Target */
+pub fn wound_down_max_ltv(ltv_wind_down: &LtvWindDown, now: i64) -> Result<u64> {
+    if now >= ltv_wind_down.end_time {
+        return Ok(ltv_wind_down.target_max_ltv_bps);
+    }
+
+    let elapsed = now.saturating_sub(ltv_wind_down.start_time).max(0) as u128;
+    let duration = ltv_wind_down.end_time.saturating_sub(ltv_wind_down.start_time) as u128;
+    let total_drop = ltv_wind_down.start_max_ltv_bps.saturating_sub(ltv_wind_down.target_max_ltv_bps) as u128;
+
+    let drop_so_far = total_drop
+        .checked_mul(elapsed)
+        .ok_or(LendanaError::MathOverflow)?
+        / duration;
+
+    Ok(ltv_wind_down.start_max_ltv_bps - drop_so_far as u64)
+}
+
+/* The Token's Risk Parameters With Its Max LTV Lowered By Any Delisting Wind-Down In Progress */
+pub fn effective_risk_parameters(token_config: &TokenConfig, now: i64) -> Result<RiskParameters> {
+    let mut risk_parameters = token_config.risk_parameters;
+
+    if let Some(ltv_wind_down) = &token_config.ltv_wind_down {
+        risk_parameters.max_ltv_bps = risk_parameters.max_ltv_bps.min(wound_down_max_ltv(ltv_wind_down, now)?);
+    }
+
+    Ok(risk_parameters)
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{accounts::*, errors::*};
+
+
+fn in_category(emode_category: &EModeCategory, collateral_mint: &Pubkey, borrowing_mint: &Pubkey) -> bool {
+    emode_category.token_mints.contains(collateral_mint) && emode_category.token_mints.contains(borrowing_mint)
+}
+
+/* A New Borrow Opting Into E-Mode Must Have Both Its Collateral And Borrowing Tokens In The Category */
+pub fn require_emode_membership(emode_category: Option<&EModeCategory>, collateral_mint: &Pubkey, borrowing_mint: &Pubkey) -> Result<u8> {
+    match emode_category {
+        Some(category) => {
+            require!(in_category(category, collateral_mint, borrowing_mint), LendanaError::NotInEModeCategory);
+            Ok(category.category_id)
+        },
+        None => Ok(0),
+    }
+}
+
+/* Existing Positions Must Be Handled With The Category They Were Opened In, Or Without One If None */
+pub fn require_position_emode(position_category: u8, emode_category: Option<&EModeCategory>) -> Result<()> {
+    let passed_category = emode_category.map(|c| c.category_id).unwrap_or(0);
+    require!(passed_category == position_category, LendanaError::EModeCategoryMismatch);
+    Ok(())
+}
+
+/* LTV, Threshold And Bonus For A Collateral/Borrow Pair: The E-Mode Category's While Both Tokens Belong To It,
+Otherwise The Collateral Token's Own Risk Parameters */
+pub fn pair_liquidation_parameters(collateral_risk_parameters: &RiskParameters, emode_category: Option<&EModeCategory>,
+    collateral_mint: &Pubkey, borrowing_mint: &Pubkey) -> EModeParameters {
+
+    match emode_category {
+        Some(category) if in_category(category, collateral_mint, borrowing_mint) => category.emode_parameters,
+        _ => EModeParameters {
+            max_ltv_bps: collateral_risk_parameters.max_ltv_bps,
+            liquidation_threshold_bps: collateral_risk_parameters.liquidation_threshold_bps,
+            liquidation_bonus_bps: collateral_risk_parameters.liquidation_bonus_bps,
+        },
+    }
+}
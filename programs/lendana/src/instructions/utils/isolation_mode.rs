@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{accounts::*, errors::*};
+
+/* Counts A New Borrow Against An Isolated Collateral's Debt Ceiling, Returning The USD Debt Added */
+pub fn add_isolated_debt(collateral_escrow: &mut LentBorrowedTokenEscrow, borrowing_risk_parameters: &RiskParameters, borrow_usd_value: u128) -> Result<u64> {
+    if !collateral_escrow.isolation_mode {
+        return Ok(0);
+    }
+
+    // Isolated Collateral Only Backs Tokens Allowed In Isolation, Up To The Ceiling
+    require!(borrowing_risk_parameters.borrowable_in_isolation, LendanaError::NotBorrowableInIsolation);
+
+    let added_debt = u64::try_from(borrow_usd_value).map_err(|_| LendanaError::MathOverflow)?;
+    let new_isolation_debt = collateral_escrow.isolation_debt
+        .checked_add(added_debt)
+        .ok_or(LendanaError::MathOverflow)?;
+    require!(new_isolation_debt <= collateral_escrow.isolation_debt_ceiling, LendanaError::IsolationDebtCeilingExceeded);
+
+    collateral_escrow.isolation_debt = new_isolation_debt;
+    Ok(added_debt)
+}
+
+/* Releases A Closed Position's Debt From The Isolated Collateral's Ceiling */
+pub fn release_isolated_debt(collateral_escrow: &mut LentBorrowedTokenEscrow, isolated_debt: u64) {
+    collateral_escrow.isolation_debt = collateral_escrow.isolation_debt.saturating_sub(isolated_debt);
+}
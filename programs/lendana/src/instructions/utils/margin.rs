@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+
+use crate::{states::{accounts::*, constants::*, errors::*}, utils::*};
+
+
+/* Collateral Basket And Combined Debt Value Of A Margin Account */
+pub struct MarginValuation {
+    pub collateral: BasketValuation,
+
+    pub borrow_prices: Vec<Price>,
+
+    pub debt_value: u128,
+}
+
+/* Values A Margin Account's Collateral Basket And Every Borrow
+@note remaining_accounts Must Start With A [token config, price update, mint] Triple Per Deposit, Followed By A
+[token config, price update, mint] Triple Per Borrow, In Order; any transfer hook extra accounts follow them */
+pub fn value_margin_account(market: &Pubkey, margin_account: &MarginAccount, health_accounts: &[AccountInfo], now: i64) -> Result<MarginValuation> {
+
+    let collateral_account_count = margin_account.collateral_deposits.len() * HEALTH_ACCOUNTS_PER_DEPOSIT;
+    let borrow_account_count = margin_account.borrows.len() * HEALTH_ACCOUNTS_PER_BORROW;
+    let health_accounts = health_accounts.get(..collateral_account_count + borrow_account_count)
+        .ok_or(LendanaError::InvalidHealthAccounts)?;
+    let (collateral_accounts, borrow_accounts) = health_accounts.split_at(collateral_account_count);
+
+    let mut valuation = MarginValuation {
+        collateral: value_collateral_basket(market, &margin_account.collateral_deposits, collateral_accounts)?,
+        borrow_prices: Vec::with_capacity(margin_account.borrows.len()),
+        debt_value: 0,
+    };
+
+    for (borrow, accounts) in margin_account.borrows.iter().zip(borrow_accounts.chunks_exact(HEALTH_ACCOUNTS_PER_BORROW)) {
+        let token_config = load_market_token_config(&accounts[0], market, &borrow.token_mint)?;
+        require_keys_eq!(accounts[2].key(), borrow.token_mint, LendanaError::InvalidHealthAccounts);
+        let price_update: PriceUpdateV2 = load_remaining_account(&accounts[1])?;
+        let price = get_mint_price(&token_config, &price_update, &accounts[2])?;
+        let borrow_value = token_usd_value(margin_debt(borrow, now)?, borrow.decimals, &price)?;
+
+        valuation.debt_value = valuation.debt_value.checked_add(borrow_value).ok_or(LendanaError::MathOverflow)?;
+        valuation.borrow_prices.push(price);
+    }
+
+    Ok(valuation)
+}
+
+/* Outstanding Debt Of One Borrow: Principal, Settled Interest, And Interest Accrued Since The Last Settlement */
+pub fn margin_debt(borrow: &MarginDebt, now: i64) -> Result<u64> {
+    let interest = unsettled_interest(borrow.borrowing_amount, &borrow.borrowing_terms, borrow.borrowing_start, borrow.interest_accrued_at, now)?;
+    let debt = borrow.borrowing_amount
+        .checked_add(borrow.interest_owed)
+        .and_then(|d| d.checked_add(interest))
+        .ok_or(LendanaError::MathOverflow)?;
+    Ok(debt)
+}
+
+/* Moves Interest Accrued On The Borrow's Principal Into interest_owed, Restarting Accrual From `now` */
+pub fn settle_margin_debt_interest(borrow: &mut MarginDebt, now: i64) -> Result<()> {
+    let interest = unsettled_interest(borrow.borrowing_amount, &borrow.borrowing_terms, borrow.borrowing_start, borrow.interest_accrued_at, now)?;
+    borrow.interest_owed = borrow.interest_owed.checked_add(interest).ok_or(LendanaError::MathOverflow)?;
+    borrow.interest_accrued_at = now;
+    Ok(())
+}
+
+/* Applies A Repayment To The Borrow's Settled Interest First, Then Principal, Returning (interest paid, principal paid) */
+pub fn apply_margin_repayment(borrow: &mut MarginDebt, repay_amount: u64) -> (u64, u64) {
+    let interest_paid = repay_amount.min(borrow.interest_owed);
+    let principal_paid = (repay_amount - interest_paid).min(borrow.borrowing_amount);
+
+    borrow.interest_owed -= interest_paid;
+    borrow.borrowing_amount -= principal_paid;
+
+    (interest_paid, principal_paid)
+}
+
+/* True Once Any Of The Account's Borrows Is Past Its Loan Duration */
+pub fn has_expired_margin_debt(margin_account: &MarginAccount, now: i64) -> bool {
+    margin_account.borrows.iter().any(|borrow| {
+        now >= borrow.borrowing_start.saturating_add(borrow.borrowing_terms.lending_duration as i64)
+    })
+}
@@ -0,0 +1,82 @@
+use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+
+use crate::states::{accounts::*, constants::*, errors::*};
+
+
+/* Accounts Carrying A Layout Version And Reserved Bytes At Their End */
+pub trait VersionedAccount {
+    fn version(&self) -> u8;
+
+    fn upgrade_layout(&mut self);
+}
+
+macro_rules! versioned_accounts {
+    ($($account:ty),* $(,)?) => {
+        $(
+            impl VersionedAccount for $account {
+                fn version(&self) -> u8 {
+                    self.version
+                }
+
+                fn upgrade_layout(&mut self) {
+                    self.version = ACCOUNT_VERSION;
+                    self.reserved = [0; 64];
+                }
+            }
+        )*
+    };
+}
+
+versioned_accounts!(
+    Administrator, TrustedEntities, ProtocolState, Market, Timelock, TimelockProposal, AdminMultisig, MultisigTransaction,
+    ListingGovernance, ListingProposal, ListingVote, SolCollateralEscrow, LenderPositionIDCounter,
+    BorrowerPositionIDCounter, LentBorrowedTokenEscrow, TokenConfig, EModeCategory, LenderPosition,
+    BorrowerPosition, BorrowerObligation, MarginAccount, TokenTreasury,
+);
+
+/* Brings An Account Of Type `T` Up To The Current Layout In Place, Returning The Version It Had
+Accounts Created Before A Layout Grew Are Shorter Than It: They Are Reallocated, With The Payer Covering The Extra Rent,
+And Every New Field Starts Zeroed. Existing Fields Are Decoded And Written Back Unchanged */
+pub fn migrate_account<'info, T>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Space + VersionedAccount,
+{
+    require_keys_eq!(*account.owner, T::owner(), LendanaError::InvalidMigrationAccount);
+
+    let current_len = account.data_len();
+    let target_len = 8 + T::INIT_SPACE;
+    require!(current_len <= target_len, LendanaError::InvalidMigrationAccount);
+
+    // Decoding Pads The Old Data With Zeroes, So Fields Past Its End Read As New; Checks The Discriminator Too
+    let mut padded_data = account.try_borrow_data()?.to_vec();
+    padded_data.resize(target_len, 0);
+    let mut migrated = T::try_deserialize(&mut padded_data.as_slice())?;
+
+    // Accounts Too Short For The Version Byte Predate Versioning
+    let previous_version = if current_len < target_len { 0 } else { migrated.version() };
+    require!(previous_version < ACCOUNT_VERSION, LendanaError::AccountAlreadyMigrated);
+
+    if current_len < target_len {
+        // Only The Rent For The Added Bytes, So Lamports Held On Top Of Rent Stay As They Are
+        let rent = Rent::get()?;
+        let extra_rent = rent.minimum_balance(target_len).saturating_sub(rent.minimum_balance(current_len));
+        if extra_rent > 0 {
+            let cpi_accounts = Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            };
+            transfer(CpiContext::new(system_program.clone(), cpi_accounts), extra_rent)?;
+        }
+        account.resize(target_len)?;
+    }
+
+    migrated.upgrade_layout();
+    let mut account_data = account.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut account_data[..])?;
+
+    Ok(previous_version)
+}
@@ -1,4 +1,38 @@
 pub mod validate_loan_terms;
+pub mod validate_risk_parameters;
+pub mod price_oracle;
+pub mod risk_calculations;
+pub mod isolation_mode;
+pub mod emode;
+pub mod obligations;
+pub mod margin;
+pub mod treasury;
+pub mod token_extensions;
+pub mod sol_collateral;
+pub mod native_sol;
+pub mod roles;
+pub mod pause;
+pub mod delisting;
+pub mod timelock;
+pub mod multisig;
+pub mod migration;
 
 
-pub use validate_loan_terms::*;
\ No newline at end of file
+pub use validate_loan_terms::*;
+pub use validate_risk_parameters::*;
+pub use price_oracle::*;
+pub use risk_calculations::*;
+pub use isolation_mode::*;
+pub use emode::*;
+pub use obligations::*;
+pub use margin::*;
+pub use treasury::*;
+pub use token_extensions::*;
+pub use sol_collateral::*;
+pub use native_sol::*;
+pub use roles::*;
+pub use pause::*;
+pub use delisting::*;
+pub use timelock::*;
+pub use multisig::*;
+pub use migration::*;
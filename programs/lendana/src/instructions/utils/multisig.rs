@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{constants::*, errors::*};
+
+
+/* Owners Must Be Unique And Fit The Account; The Threshold Must Be Reachable And Above Zero */
+pub fn validate_multisig_owners(owners: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(!owners.is_empty() && owners.len() <= MAX_MULTISIG_OWNERS, LendanaError::InvalidMultisigOwners);
+
+    let has_duplicate = owners.iter().enumerate().any(|(index, owner)| owners[..index].contains(owner));
+    require!(!has_duplicate, LendanaError::InvalidMultisigOwners);
+
+    require!(threshold > 0 && threshold as usize <= owners.len(), LendanaError::InvalidMultisigThreshold);
+    Ok(())
+}
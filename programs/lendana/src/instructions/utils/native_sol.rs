@@ -0,0 +1,50 @@
+use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+
+use anchor_spl::token_interface::{CloseAccount, SyncNative, TokenAccount, close_account, sync_native};
+
+use crate::states::constants::NATIVE_SOL_MINT_ADDRESS;
+
+
+/* Wraps Enough Of The Owner's Lamports Into Their wSOL Account To Cover `amount`, Using Any wSOL Already There First; Other Mints Are Left Alone */
+pub fn wrap_native_sol<'info>(
+    owner_token_account: &InterfaceAccount<'info, TokenAccount>,
+    owner: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if owner_token_account.mint != NATIVE_SOL_MINT_ADDRESS {
+        return Ok(());
+    }
+
+    let shortfall = amount.saturating_sub(owner_token_account.amount);
+    if shortfall == 0 {
+        return Ok(());
+    }
+
+    let cpi_accounts = Transfer {
+        from: owner,
+        to: owner_token_account.to_account_info(),
+    };
+    transfer(CpiContext::new(system_program, cpi_accounts), shortfall)?;
+
+    sync_native(CpiContext::new(token_program, SyncNative { account: owner_token_account.to_account_info() }))
+}
+
+/* Closes The Owner's wSOL Account So Everything In It Comes Back As Lamports; Other Mints Are Left Alone */
+pub fn unwrap_native_sol<'info>(
+    owner_token_account: &InterfaceAccount<'info, TokenAccount>,
+    owner: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+) -> Result<()> {
+    if owner_token_account.mint != NATIVE_SOL_MINT_ADDRESS {
+        return Ok(());
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: owner_token_account.to_account_info(),
+        destination: owner.clone(),
+        authority: owner,
+    };
+    close_account(CpiContext::new(token_program, cpi_accounts))
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+
+use crate::{states::{accounts::*, constants::*, errors::*}, utils::*};
+
+
+/* Price And Risk Parameters Of One Collateral Deposit, In Deposit Order */
+pub struct DepositValuation {
+    pub risk_parameters: RiskParameters,
+
+    pub price: Price,
+
+    pub usd_value: u128,
+}
+
+/* Collateral Basket Values Weighted By Max LTV And By Liquidation Threshold */
+pub struct BasketValuation {
+    pub deposits: Vec<DepositValuation>,
+
+    pub borrow_limit: u128,
+
+    pub liquidation_limit: u128,
+}
+
+// Deserializes An Account Passed Through remaining_accounts, Checking Its Owner And Discriminator
+pub(crate) fn load_remaining_account<T: AccountDeserialize + Owner>(account_info: &AccountInfo) -> Result<T> {
+    require_keys_eq!(*account_info.owner, T::owner(), ErrorCode::AccountOwnedByWrongProgram);
+    let data = account_info.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+/* Loads A Token Config Passed Through remaining_accounts, Which Must Be The Mint's Config In The Position's Market */
+pub(crate) fn load_market_token_config(account_info: &AccountInfo, market: &Pubkey, token_mint: &Pubkey) -> Result<TokenConfig> {
+    let token_config: TokenConfig = load_remaining_account(account_info)?;
+    require_keys_eq!(token_config.market, *market, LendanaError::MismatchedMarket);
+    require_keys_eq!(token_config.token_mint, *token_mint, LendanaError::MismatchedRiskConfig);
+    Ok(token_config)
+}
+
+/* Values Every Deposit In A Collateral Basket
+@note remaining_accounts Must Start With, For Each Deposit In Order, Its Token Config, Its Pyth Price Update And Its Mint;
+any transfer hook extra accounts follow them */
+pub fn value_collateral_basket(market: &Pubkey, deposits: &[CollateralDeposit], health_accounts: &[AccountInfo]) -> Result<BasketValuation> {
+
+    let health_accounts = health_accounts.get(..deposits.len() * HEALTH_ACCOUNTS_PER_DEPOSIT)
+        .ok_or(LendanaError::InvalidHealthAccounts)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut valuation = BasketValuation {
+        deposits: Vec::with_capacity(deposits.len()),
+        borrow_limit: 0,
+        liquidation_limit: 0,
+    };
+
+    for (deposit, accounts) in deposits.iter().zip(health_accounts.chunks_exact(HEALTH_ACCOUNTS_PER_DEPOSIT)) {
+        let token_config = load_market_token_config(&accounts[0], market, &deposit.token_mint)?;
+
+        require_keys_eq!(accounts[2].key(), deposit.token_mint, LendanaError::InvalidHealthAccounts);
+        let price_update: PriceUpdateV2 = load_remaining_account(&accounts[1])?;
+        let price = get_mint_price(&token_config, &price_update, &accounts[2])?;
+        let usd_value = token_usd_value(deposit.amount, deposit.decimals, &price)?;
+
+        let risk_parameters = effective_risk_parameters(&token_config, now)?;
+        valuation.borrow_limit = valuation.borrow_limit
+            .checked_add(apply_bps(usd_value, risk_parameters.max_ltv_bps)?)
+            .ok_or(LendanaError::MathOverflow)?;
+        valuation.liquidation_limit = valuation.liquidation_limit
+            .checked_add(apply_bps(usd_value, risk_parameters.liquidation_threshold_bps)?)
+            .ok_or(LendanaError::MathOverflow)?;
+
+        valuation.deposits.push(DepositValuation { risk_parameters, price, usd_value });
+    }
+
+    Ok(valuation)
+}
+
+/* Interest Accrued On A Principal Since Its Last Settlement, Up To The End Of The Loan Term */
+pub fn unsettled_interest(principal: u64, loan_terms: &LoanTerms, loan_start: i64, interest_accrued_at: i64, now: i64) -> Result<u64> {
+    let loan_end = loan_start.saturating_add(loan_terms.lending_duration as i64);
+    accrued_interest(principal, loan_terms, interest_accrued_at, now.min(loan_end))
+}
+
+/* Collateral Seized For Repaying `repay_amount` Of Debt, Worth Its Value Plus The Deposit's Liquidation Bonus
+Returns (repay amount, seized collateral); when the deposit cannot cover it, only the repayment it covers is kept */
+pub fn liquidation_seizure(repay_amount: u64, debt_decimals: u8, debt_price: &Price,
+    deposit: &CollateralDeposit, deposit_valuation: &DepositValuation) -> Result<(u64, u64)> {
+
+    let bonus_bps = BPS_DENOMINATOR + deposit_valuation.risk_parameters.liquidation_bonus_bps;
+    let repay_value = token_usd_value(repay_amount, debt_decimals, debt_price)?;
+    let seized_collateral = usd_value_to_token_amount(apply_bps(repay_value, bonus_bps)?, deposit.decimals, &deposit_valuation.price)?;
+
+    if seized_collateral <= deposit.amount {
+        return Ok((repay_amount, seized_collateral));
+    }
+
+    let covered_value = deposit_valuation.usd_value
+        .checked_mul(BPS_DENOMINATOR as u128)
+        .ok_or(LendanaError::MathOverflow)?
+        / bonus_bps as u128;
+    let covered_repay_amount = usd_value_to_token_amount(covered_value, debt_decimals, debt_price)?.min(repay_amount);
+
+    Ok((covered_repay_amount, deposit.amount))
+}
+
+/* Interest Accrued On The Obligation's Principal Since The Last Settlement */
+fn obligation_unsettled_interest(obligation: &BorrowerObligation, now: i64) -> Result<u64> {
+    unsettled_interest(obligation.borrowing_amount, &obligation.borrowing_terms, obligation.borrowing_start, obligation.interest_accrued_at, now)
+}
+
+/* Outstanding Debt Of The Obligation: Principal, Settled Interest, And Interest Accrued Since The Last Settlement */
+pub fn obligation_debt(obligation: &BorrowerObligation, now: i64) -> Result<u64> {
+    let interest = obligation_unsettled_interest(obligation, now)?;
+    let debt = obligation.borrowing_amount
+        .checked_add(obligation.interest_owed)
+        .and_then(|d| d.checked_add(interest))
+        .ok_or(LendanaError::MathOverflow)?;
+    Ok(debt)
+}
+
+/* USD Value Of The Obligation's Outstanding Debt, Zero When It Has None */
+pub fn obligation_debt_value(obligation: &BorrowerObligation, borrowing_token_config: Option<&TokenConfig>,
+    borrowing_price_update: Option<&PriceUpdateV2>, borrowing_mint: Option<&AccountInfo>, now: i64) -> Result<u128> {
+
+    if obligation.borrowing_amount == 0 && obligation.interest_owed == 0 {
+        return Ok(0);
+    }
+    let price_update = borrowing_price_update.ok_or(LendanaError::MissingBorrowingPriceUpdate)?;
+    let borrowing_mint = borrowing_mint.ok_or(LendanaError::MissingBorrowingPriceUpdate)?;
+    let borrowing_token_config = borrowing_token_config.ok_or(LendanaError::MissingBorrowingPriceUpdate)?;
+    require_keys_eq!(borrowing_mint.key(), obligation.borrowing_token, LendanaError::MismatchBorrowToken);
+    let price = get_mint_price(borrowing_token_config, price_update, borrowing_mint)?;
+
+    token_usd_value(obligation_debt(obligation, now)?, obligation.borrowing_token_decimals, &price)
+}
+
+/* Moves Interest Accrued On The Principal Into interest_owed, Restarting Accrual From `now` */
+pub fn settle_obligation_interest(obligation: &mut BorrowerObligation, now: i64) -> Result<()> {
+    let interest = obligation_unsettled_interest(obligation, now)?;
+    obligation.interest_owed = obligation.interest_owed.checked_add(interest).ok_or(LendanaError::MathOverflow)?;
+    obligation.interest_accrued_at = now;
+    Ok(())
+}
+
+/* Applies A Repayment To Settled Interest First, Then Principal
+Returns (interest paid, principal paid, isolated USD debt released); clears the debt token once fully repaid */
+pub fn apply_obligation_repayment(obligation: &mut BorrowerObligation, repay_amount: u64) -> Result<(u64, u64, u64)> {
+    let interest_paid = repay_amount.min(obligation.interest_owed);
+    let principal_paid = (repay_amount - interest_paid).min(obligation.borrowing_amount);
+
+    // Isolated Debt Is Released In Proportion To The Principal Repaid
+    let released_isolated_debt = if principal_paid == obligation.borrowing_amount {
+        obligation.isolated_debt
+    } else {
+        u64::try_from(
+            (obligation.isolated_debt as u128) * (principal_paid as u128) / (obligation.borrowing_amount as u128)
+        ).map_err(|_| LendanaError::MathOverflow)?
+    };
+
+    obligation.interest_owed -= interest_paid;
+    obligation.borrowing_amount -= principal_paid;
+    obligation.isolated_debt -= released_isolated_debt;
+
+    if obligation.borrowing_amount == 0 && obligation.interest_owed == 0 {
+        obligation.borrowing_token = Pubkey::default();
+    }
+
+    Ok((interest_paid, principal_paid, released_isolated_debt))
+}
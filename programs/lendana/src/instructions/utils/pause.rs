@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{accounts::*, errors::*};
+
+
+/* New Lends And Borrows Need The Protocol And The Token To Be Unpaused, And The Token Not To Be Delisting */
+pub fn ensure_open_for_orders(protocol_state: &ProtocolState, token_escrow: &LentBorrowedTokenEscrow) -> Result<()> {
+    require!(!protocol_state.paused, LendanaError::ProtocolPaused);
+    require!(token_escrow.is_active, LendanaError::TokenPaused);
+    require!(!token_escrow.is_delisted, LendanaError::TokenDelisted);
+    Ok(())
+}
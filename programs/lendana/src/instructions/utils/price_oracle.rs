@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2, get_feed_id_from_hex};
+
+use crate::states::{accounts::*, errors::*, constants::*};
+
+/* Parses A Hex Pyth Feed ID (With Or Without 0x) Into The 32 Bytes Stored On The Token Config */
+pub fn parse_price_feed_id(price_feed_id_hex: &str) -> Result<[u8; 32]> {
+    get_feed_id_from_hex(price_feed_id_hex).map_err(|_| LendanaError::InvalidPriceFeedId.into())
+}
+
+/* Reads A Fresh, Positive Price For The Token's Pyth Feed From The Update Account */
+pub fn get_token_price(token_config: &TokenConfig, price_update: &PriceUpdateV2) -> Result<Price> {
+
+    let feed_id = token_config.price_feed_id.ok_or(LendanaError::PriceFeedIDNotFound)?;
+
+    let price = price_update.get_price_no_older_than(&Clock::get()?, MAX_PRICE_FEED_AGE, &feed_id)?;
+    require!(price.price > 0, LendanaError::InvalidOraclePrice);
+
+    Ok(price)
+}
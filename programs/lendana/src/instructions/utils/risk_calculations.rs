@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use pyth_solana_receiver_sdk::price_update::Price;
+
+use crate::states::{accounts::*, errors::*, constants::*};
+
+
+// Scales `value` by 10^exponent, rounding down when the exponent is negative
+fn apply_exponent(value: u128, exponent: i32) -> Result<u128> {
+    let factor = 10u128.checked_pow(exponent.unsigned_abs()).ok_or(LendanaError::MathOverflow)?;
+    let scaled = if exponent >= 0 {
+        value.checked_mul(factor)
+    } else {
+        value.checked_div(factor)
+    };
+    Ok(scaled.ok_or(LendanaError::MathOverflow)?)
+}
+
+/* USD Value Of `amount` Base Units Of A Token, With USD_VALUE_DECIMALS Decimals */
+pub fn token_usd_value(amount: u64, decimals: u8, price: &Price) -> Result<u128> {
+    let raw_value = (amount as u128)
+        .checked_mul(price.price as u128)
+        .ok_or(LendanaError::MathOverflow)?;
+
+    apply_exponent(raw_value, price.exponent + USD_VALUE_DECIMALS as i32 - decimals as i32)
+}
+
+/* Token Base Units Worth `usd_value`, Rounded Up So Collateral Requirements Are Never Understated */
+pub fn usd_value_to_token_amount(usd_value: u128, decimals: u8, price: &Price) -> Result<u64> {
+    let exponent = decimals as i32 - USD_VALUE_DECIMALS as i32 - price.exponent;
+    let factor = 10u128.checked_pow(exponent.unsigned_abs()).ok_or(LendanaError::MathOverflow)?;
+
+    let (numerator, denominator) = if exponent >= 0 {
+        (usd_value.checked_mul(factor), Some(price.price as u128))
+    } else {
+        (Some(usd_value), (price.price as u128).checked_mul(factor))
+    };
+
+    let amount = numerator
+        .zip(denominator)
+        .map(|(n, d)| n.div_ceil(d))
+        .ok_or(LendanaError::MathOverflow)?;
+
+    Ok(u64::try_from(amount).map_err(|_| LendanaError::MathOverflow)?)
+}
+
+/* Share Of A USD Value In BPS */
+pub fn apply_bps(usd_value: u128, bps: u64) -> Result<u128> {
+    let scaled = usd_value
+        .checked_mul(bps as u128)
+        .ok_or(LendanaError::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    Ok(scaled)
+}
+
+/* Collateral Needed So The Borrow Stays Within The Collateral Token's Max LTV */
+pub fn required_collateral_value(borrow_usd_value: u128, max_ltv_bps: u64) -> Result<u128> {
+    require!(max_ltv_bps > 0, LendanaError::InvalidRiskParameters);
+
+    let required = borrow_usd_value
+        .checked_mul(BPS_DENOMINATOR as u128)
+        .ok_or(LendanaError::MathOverflow)?
+        .div_ceil(max_ltv_bps as u128);
+    Ok(required)
+}
+
+/* A Position Is Liquidatable Once Its Debt Exceeds The Collateral Value Weighted By The Liquidation Threshold */
+pub fn is_liquidatable(collateral_usd_value: u128, debt_usd_value: u128, liquidation_threshold_bps: u64) -> Result<bool> {
+    Ok(apply_bps(collateral_usd_value, liquidation_threshold_bps)? < debt_usd_value)
+}
+
+/* Interest Accrued Pro Rata Over The Elapsed Share Of The Loan Duration, Capped At The Full Term */
+pub fn accrued_interest(principal: u64, loan_terms: &LoanTerms, loan_start: i64, now: i64) -> Result<u64> {
+    if loan_terms.lending_duration == 0 {
+        return Ok(0);
+    }
+    let elapsed = (now.saturating_sub(loan_start).max(0) as u64).min(loan_terms.lending_duration);
+
+    let interest = (principal as u128)
+        .checked_mul(loan_terms.interest_rate as u128)
+        .and_then(|v| v.checked_mul(elapsed as u128))
+        .ok_or(LendanaError::MathOverflow)?
+        / (BPS_DENOMINATOR as u128 * loan_terms.lending_duration as u128);
+
+    Ok(u64::try_from(interest).map_err(|_| LendanaError::MathOverflow)?)
+}
+
+/* Ensures Adding `additional` To `current` Stays Within `cap`; A Cap Of 0 Is Uncapped */
+pub fn ensure_within_cap(current: u64, additional: u64, cap: u64, error: LendanaError) -> Result<()> {
+    if cap == 0 {
+        return Ok(());
+    }
+    let new_total = current.checked_add(additional).ok_or(LendanaError::TokenAdditionOverflow)?;
+    if new_total > cap {
+        return Err(error.into());
+    }
+    Ok(())
+}
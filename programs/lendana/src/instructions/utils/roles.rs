@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::states::accounts::*;
+
+
+/* Whether The Address Has Been Granted The Role In The Trusted Roles Registry */
+pub fn has_role(trusted_roles: &TrustedEntities, role: Role, member: &Pubkey) -> bool {
+    trusted_roles.trusted_roles.iter().any(|assignment| assignment.role == role && assignment.member == *member)
+}
+
+/* Curated Markets Are Run By Their Curator Alone; Admin Markets By Whoever Holds The Role */
+pub fn is_market_authority(trusted_roles: &TrustedEntities, market: &Market, role: Role, member: &Pubkey) -> bool {
+    match market.curator {
+        Some(curator) => curator == *member,
+        None => has_role(trusted_roles, role, member),
+    }
+}
+
+/* Whoever Runs The Market Receives What Is Left In A Delisted Token's Vaults: Its Curator, Or The Admin For Admin Markets */
+pub fn market_leftovers_recipient(market: &Market, admin_account: &Administrator) -> Pubkey {
+    market.curator.unwrap_or(admin_account.admin_address)
+}
+
+/* Liquidations Stay Permissionless Until The Admin Grants The First Liquidator Role, Then Only The Allowlist May Liquidate */
+pub fn is_allowed_liquidator(trusted_roles: &TrustedEntities, liquidator: &Pubkey) -> bool {
+    !trusted_roles.trusted_roles.iter().any(|assignment| assignment.role == Role::Liquidator)
+        || has_role(trusted_roles, Role::Liquidator, liquidator)
+}
+
+/* Every Address Holding The Role, In The Order They Were Granted */
+pub fn role_members(trusted_roles: &TrustedEntities, role: Role) -> Vec<Pubkey> {
+    trusted_roles.trusted_roles
+        .iter()
+        .filter(|assignment| assignment.role == role)
+        .map(|assignment| assignment.member)
+        .collect()
+}
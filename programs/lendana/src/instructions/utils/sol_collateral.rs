@@ -0,0 +1,69 @@
+use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+
+use crate::states::{accounts::*, constants::*, errors::*};
+
+
+/* The Position's SOL Escrow, Which Native SOL Collateral Cannot Move Without */
+pub fn required_sol_escrow<'a, 'info>(
+    sol_collateral_escrow: &'a mut Option<Box<Account<'info, SolCollateralEscrow>>>,
+) -> Result<&'a mut Account<'info, SolCollateralEscrow>> {
+    Ok(sol_collateral_escrow.as_deref_mut().ok_or(LendanaError::MissingSolCollateralEscrow)?)
+}
+
+/* Ties A Freshly Created Escrow To Its Position; Already Bound Escrows Are Left As They Are */
+pub fn bind_sol_escrow(sol_collateral_escrow: &mut SolCollateralEscrow, position: Pubkey, owner: Pubkey, escrow_bump: u8) {
+    if sol_collateral_escrow.position == Pubkey::default() {
+        sol_collateral_escrow.position = position;
+        sol_collateral_escrow.owner = owner;
+        sol_collateral_escrow.escrow_bump = escrow_bump;
+        sol_collateral_escrow.version = ACCOUNT_VERSION;
+    }
+}
+
+/* Moves SOL From The Depositor Into The Position's Escrow And Records It As Collateral */
+pub fn deposit_sol_collateral<'info>(
+    sol_collateral_escrow: &mut Account<'info, SolCollateralEscrow>,
+    depositor: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_accounts = Transfer {
+        from: depositor,
+        to: sol_collateral_escrow.to_account_info(),
+    };
+    transfer(CpiContext::new(system_program, cpi_accounts), amount)?;
+
+    sol_collateral_escrow.collateral_lamports = sol_collateral_escrow.collateral_lamports
+        .checked_add(amount)
+        .ok_or(LendanaError::TokenAdditionOverflow)?;
+    Ok(())
+}
+
+/* Pays Recorded Collateral Out Of The Escrow; The Escrow Is Program Owned, So Lamports Are Moved Directly */
+pub fn release_sol_collateral<'info>(
+    sol_collateral_escrow: &mut Account<'info, SolCollateralEscrow>,
+    recipient: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    reconcile_sol_escrow(sol_collateral_escrow)?;
+    require!(amount <= sol_collateral_escrow.collateral_lamports, LendanaError::InsufficientCollateral);
+
+    sol_collateral_escrow.collateral_lamports -= amount;
+    sol_collateral_escrow.sub_lamports(amount)?;
+    recipient.add_lamports(amount)?;
+    Ok(())
+}
+
+/* The Escrow Must Hold At Least Its Recorded Collateral Above Its Rent-Exempt Minimum */
+pub fn reconcile_sol_escrow(sol_collateral_escrow: &Account<SolCollateralEscrow>) -> Result<()> {
+    let escrow_info = sol_collateral_escrow.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let free_lamports = escrow_info.lamports().saturating_sub(rent_exempt_minimum);
+
+    require!(free_lamports >= sol_collateral_escrow.collateral_lamports, LendanaError::SolCollateralOutOfSync);
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::{prelude::*, solana_program::hash::hash};
+
+use crate::{states::{accounts::*, constants::*, errors::*}, utils::{has_role, is_market_authority}};
+
+
+/* Admin Actions Are Queued By The Admin, Everything Else By The Role That Would Otherwise Make The Change Directly.
+A Curated Market's Changes Are Queued By Its Curator, Who Passes The Market Along */
+pub fn can_propose(
+    action: &GovernanceAction,
+    trusted_roles: &TrustedEntities,
+    admin_account: &Administrator,
+    market: Option<&Account<Market>>,
+    proposer: &Pubkey,
+) -> bool {
+    let (role, action_market) = match action {
+        GovernanceAction::SetTimelockDelay { .. }
+        | GovernanceAction::ProposeAdmin { .. }
+        | GovernanceAction::GrantRole { .. }
+        | GovernanceAction::RevokeRole { .. }
+        | GovernanceAction::RotateRole { .. }
+        | GovernanceAction::CreateMarket { .. }
+        | GovernanceAction::ConfigureListingGovernance { .. } => return admin_account.admin_address == *proposer,
+        GovernanceAction::WhitelistToken { market, .. } | GovernanceAction::DelistToken { market, .. } => (Role::Whitelister, market),
+        GovernanceAction::AddPriceFeed { market, .. }
+        | GovernanceAction::UpdatePriceFeed { market, .. }
+        | GovernanceAction::RemovePriceFeed { market, .. } => (Role::OracleManager, market),
+        GovernanceAction::SetRiskConfig { market, .. }
+        | GovernanceAction::SetIsolationMode { market, .. }
+        | GovernanceAction::SetEModeCategory { market, .. } => (Role::RiskManager, market),
+    };
+    match market {
+        Some(market) => market.key() == *action_market && is_market_authority(trusted_roles, market, role, proposer),
+        // Without The Market, Only Role Holders Can Propose; Execution Still Turns Them Away From Curated Markets
+        None => has_role(trusted_roles, role, proposer),
+    }
+}
+
+/* Proposals Commit To The Hash Of Their Borsh-Serialized Action */
+pub fn governance_action_hash(action: &GovernanceAction) -> Result<[u8; 32]> {
+    Ok(hash(&action.try_to_vec()?).to_bytes())
+}
+
+/* Marks A Queued, Matured And Unexpired Proposal For Exactly This Action As Executed */
+pub fn consume_matured_proposal(timelock_proposal: &mut TimelockProposal, action: &GovernanceAction) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(timelock_proposal.status == ProposalStatus::Queued, LendanaError::ProposalNotQueued);
+    require!(now >= timelock_proposal.eta, LendanaError::ProposalNotMatured);
+    require!(now <= timelock_proposal.eta.saturating_add(TIMELOCK_GRACE_PERIOD), LendanaError::ProposalExpired);
+    require!(timelock_proposal.action_hash == governance_action_hash(action)?, LendanaError::ProposalActionMismatch);
+
+    timelock_proposal.status = ProposalStatus::Executed;
+    Ok(())
+}
+
+/* A Proposal That Was Cancelled Or Outlived Its Grace Period Can Never Be Executed */
+pub fn proposal_lapsed(timelock_proposal: &TimelockProposal, now: i64) -> bool {
+    match timelock_proposal.status {
+        ProposalStatus::Cancelled => true,
+        ProposalStatus::Queued => now > timelock_proposal.eta.saturating_add(TIMELOCK_GRACE_PERIOD),
+        ProposalStatus::Executed => false,
+    }
+}
+
+/* Proposals Queued By The Listing Governance Signer Are Executed Only Through It, So Execute Listing Always Finds Them */
+pub fn require_proposal_executor(timelock_proposal: &TimelockProposal, executor: &Pubkey) -> Result<()> {
+    let (governance_signer, _) = Pubkey::find_program_address(&[b"listing_governance_signer"], &crate::ID);
+    require!(
+        timelock_proposal.proposer != governance_signer || *executor == governance_signer,
+        LendanaError::GovernanceQueuedProposal
+    );
+    Ok(())
+}
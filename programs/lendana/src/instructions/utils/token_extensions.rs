@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{token_2022::spl_token_2022::{self, onchain::invoke_transfer_checked, state::Mint,
+extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+interest_bearing_mint::InterestBearingConfig, transfer_fee::TransferFeeConfig}}, token_interface::TransferChecked};
+
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+
+use crate::{states::{accounts::*, constants::*, errors::*}, utils::get_token_price};
+
+
+// Extensions That Let A Third Party Move Or Freeze Vault Balances, Or Hide Them From The Program
+const UNSUPPORTED_MINT_EXTENSIONS: [ExtensionType; 3] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::NonTransferable,
+    ExtensionType::ConfidentialTransferMint,
+];
+
+// Fixed-Point Precision Of An Interest-Bearing Mint's Scale
+const INTEREST_SCALE: u128 = 1_000_000_000_000;
+
+// Year Length Token-2022 Compounds Interest-Bearing Mints Over: 365.24 Days
+const INTEREST_SECONDS_PER_YEAR: u128 = 31_556_736;
+
+// Taylor Terms Summed For exp Once Its Argument Is Reduced Below One
+const EXP_TAYLOR_TERMS: u128 = 24;
+
+/* Rejects Token-2022 Mints Carrying Extensions The Protocol Cannot Hold Safely */
+pub fn validate_mint_extensions(mint_info: &AccountInfo) -> Result<()> {
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let has_unsupported_extension = mint.get_extension_types()?.iter()
+        .any(|extension| UNSUPPORTED_MINT_EXTENSIONS.contains(extension));
+    require!(!has_unsupported_extension, LendanaError::UnsupportedMintExtension);
+
+    Ok(())
+}
+
+/* Transfer Fee Withheld When Sending `amount` Of The Mint This Epoch, Zero Without The Transfer Fee Extension */
+pub fn transfer_fee(mint_info: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let Ok(fee_config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    let fee = fee_config.calculate_epoch_fee(Clock::get()?.epoch, amount).ok_or(LendanaError::MathOverflow)?;
+    Ok(fee)
+}
+
+/* Tokens The Destination Actually Receives When `amount` Is Sent */
+pub fn amount_received(mint_info: &AccountInfo, amount: u64) -> Result<u64> {
+    Ok(amount - transfer_fee(mint_info, amount)?)
+}
+
+/* Tokens To Send So The Destination Receives Exactly `amount` After The Transfer Fee */
+pub fn amount_to_send(mint_info: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(amount);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let Ok(fee_config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(amount);
+    };
+
+    let fee = fee_config.calculate_inverse_epoch_fee(Clock::get()?.epoch, amount).ok_or(LendanaError::MathOverflow)?;
+    Ok(amount.checked_add(fee).ok_or(LendanaError::MathOverflow)?)
+}
+
+/* e^(numerator / denominator) With INTEREST_SCALE Precision: The Argument Is Halved Below One, Summed As A Taylor Series,
+Then Squared Back; None When The Result Does Not Fit */
+fn fixed_point_exp(numerator: i128, denominator: u128) -> Option<u128> {
+    let magnitude = numerator.unsigned_abs();
+
+    let mut halvings = 0u32;
+    while magnitude > denominator.checked_shl(halvings)? {
+        halvings += 1;
+    }
+    let reduced = magnitude.checked_mul(INTEREST_SCALE)? / (denominator << halvings);
+
+    let mut term = INTEREST_SCALE;
+    let mut exp = INTEREST_SCALE;
+    for index in 1..=EXP_TAYLOR_TERMS {
+        term = term * reduced / (INTEREST_SCALE * index);
+        if term == 0 {
+            break;
+        }
+        exp += term;
+    }
+    for _ in 0..halvings {
+        exp = exp.checked_mul(exp)? / INTEREST_SCALE;
+    }
+
+    if numerator < 0 {
+        (INTEREST_SCALE * INTEREST_SCALE).checked_div(exp)
+    } else {
+        Some(exp)
+    }
+}
+
+/* Pyth Prices The UI Token; An Interest-Bearing Mint's Base Units Grow In UI Terms, So The Price Per Base Unit Is Scaled Up
+The Scale Is Token-2022's e^(rate x time), Computed In Fixed Point From The Mint's Rates And Timestamps */
+pub fn interest_adjusted_price(price: Price, mint_info: &AccountInfo) -> Result<Price> {
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(price);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let Ok(interest_config) = mint.get_extension::<InterestBearingConfig>() else {
+        return Ok(price);
+    };
+
+    // Basis Points x Seconds Accrued Before The Last Rate Update, Then At The Current Rate Since
+    let initialized_at = i64::from(interest_config.initialization_timestamp);
+    let rate_updated_at = i64::from(interest_config.last_update_timestamp);
+    let pre_update_rate = i16::from(interest_config.pre_update_average_rate) as i128;
+    let current_rate = i16::from(interest_config.current_rate) as i128;
+    let pre_update_span = rate_updated_at.checked_sub(initialized_at).ok_or(LendanaError::MathOverflow)? as i128;
+    let post_update_span = Clock::get()?.unix_timestamp.checked_sub(rate_updated_at).ok_or(LendanaError::MathOverflow)? as i128;
+    let exponent_numerator = pre_update_rate * pre_update_span + current_rate * post_update_span;
+
+    let scale = fixed_point_exp(exponent_numerator, BPS_DENOMINATOR as u128 * INTEREST_SECONDS_PER_YEAR)
+        .ok_or(LendanaError::MathOverflow)?;
+    let adjusted_price = (price.price as i128)
+        .checked_mul(i128::try_from(scale).map_err(|_| LendanaError::MathOverflow)?)
+        .ok_or(LendanaError::MathOverflow)?
+        / INTEREST_SCALE as i128;
+
+    Ok(Price { price: i64::try_from(adjusted_price).map_err(|_| LendanaError::MathOverflow)?, ..price })
+}
+
+/* Oracle Price Of A Token Per Base Unit Of Its Mint, Adjusted For Interest-Bearing Mints */
+pub fn get_mint_price(token_config: &TokenConfig, price_update: &PriceUpdateV2, mint_info: &AccountInfo) -> Result<Price> {
+    require_keys_eq!(token_config.token_mint, mint_info.key(), LendanaError::MismatchedRiskConfig);
+    let price = get_token_price(token_config, price_update)?;
+    interest_adjusted_price(price, mint_info)
+}
+
+/* transfer_checked That Also Resolves A Transfer Hook's Extra Accounts From The Context's Remaining Accounts */
+pub fn transfer_tokens<'info>(cpi_ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>, amount: u64, decimals: u8) -> Result<()> {
+    invoke_transfer_checked(
+        cpi_ctx.program.key,
+        cpi_ctx.accounts.from,
+        cpi_ctx.accounts.mint,
+        cpi_ctx.accounts.to,
+        cpi_ctx.accounts.authority,
+        &cpi_ctx.remaining_accounts,
+        amount,
+        decimals,
+        cpi_ctx.signer_seeds,
+    )?;
+    Ok(())
+}
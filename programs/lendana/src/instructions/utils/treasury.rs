@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{states::{accounts::*, constants::*, errors::*}, utils::apply_bps};
+
+
+/* Reserve Factor Share Of An Interest Payment, Owed To The Token Treasury */
+pub fn reserve_fee(interest_paid: u64, borrowing_risk_parameters: &RiskParameters) -> Result<u64> {
+    token_bps_share(interest_paid, borrowing_risk_parameters.reserve_factor_bps)
+}
+
+/* Origination Fee On A Borrow, Owed To The Token Treasury And Deducted From The Tokens Sent To The Borrower */
+pub fn origination_fee(borrowing_amount: u64, borrowing_risk_parameters: &RiskParameters) -> Result<u64> {
+    token_bps_share(borrowing_amount, borrowing_risk_parameters.origination_fee_bps)
+}
+
+/* Fee Owed On Top Of A Flash Loan */
+pub fn flash_loan_fee(amount: u64) -> Result<u64> {
+    token_bps_share(amount, FLASH_LOAN_FEE_BPS)
+}
+
+/* Adds Fees Paid Into The Treasury To The Escrow's Treasury Total */
+pub fn record_treasury_fees(token_escrow: &mut LentBorrowedTokenEscrow, fees: u64) -> Result<()> {
+    token_escrow.treasury_fees = token_escrow.treasury_fees
+        .checked_add(fees)
+        .ok_or(LendanaError::TokenAdditionOverflow)?;
+    Ok(())
+}
+
+fn token_bps_share(amount: u64, bps: u64) -> Result<u64> {
+    let share = apply_bps(amount as u128, bps)?;
+    Ok(u64::try_from(share).map_err(|_| LendanaError::MathOverflow)?)
+}
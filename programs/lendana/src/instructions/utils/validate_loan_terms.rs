@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 
 use crate::states::{ accounts::*, errors::*, constants::*};
 
-pub fn validate_loan(loan_terms: LoanTerms) -> Result<()> {
+pub fn validate_loan(loan_terms: LoanTerms, market: &Market) -> Result<()> {
+    // Each Market Offers Its Own Subset Of The Supported Durations
+    require!(market.lending_durations.contains(&loan_terms.lending_duration), LendanaError::UnsupportedLendingDuration);
+
     /*  Ensure Lending Duration Meets 3 of the Available durations, 1, 3 or 6 Months
     require!(loan_terms.lending_duration == SIX_MONTH_LENDING_DURATION ||
             loan_terms.lending_duration == THREE_MONTH_LENDING_DURATION ||
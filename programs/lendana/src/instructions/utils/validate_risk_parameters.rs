@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{accounts::*, errors::*, constants::*};
+
+pub fn validate_risk_parameters(risk_parameters: &RiskParameters) -> Result<()> {
+
+    // Fees Are Shares Of The Interest Or Borrowed Amount, The Origination Fee Bounded
+    require!(risk_parameters.reserve_factor_bps <= BPS_DENOMINATOR, LendanaError::InvalidRiskParameters);
+    require!(risk_parameters.origination_fee_bps <= MAX_ORIGINATION_FEE_BPS, LendanaError::InvalidRiskParameters);
+
+    validate_liquidation_parameters(
+        risk_parameters.max_ltv_bps,
+        risk_parameters.liquidation_threshold_bps,
+        risk_parameters.liquidation_bonus_bps,
+    )
+}
+
+pub fn validate_emode_parameters(emode_parameters: &EModeParameters) -> Result<()> {
+
+    validate_liquidation_parameters(
+        emode_parameters.max_ltv_bps,
+        emode_parameters.liquidation_threshold_bps,
+        emode_parameters.liquidation_bonus_bps,
+    )
+}
+
+fn validate_liquidation_parameters(max_ltv_bps: u64, liquidation_threshold_bps: u64, liquidation_bonus_bps: u64) -> Result<()> {
+    /* + Max LTV Must Be Non-Zero And Strictly Below The Liquidation Threshold, So New Borrows Start Healthy
+       + Liquidation Threshold Must Be Below 100%
+       + Liquidation Bonus Is Bounded, And Threshold Plus Bonus Must Not Exceed 100% So Seized Collateral Covers The Debt */
+    require!(max_ltv_bps > 0, LendanaError::InvalidRiskParameters);
+    require!(max_ltv_bps < liquidation_threshold_bps, LendanaError::InvalidRiskParameters);
+    require!(liquidation_threshold_bps < BPS_DENOMINATOR, LendanaError::InvalidRiskParameters);
+    require!(liquidation_bonus_bps <= MAX_LIQUIDATION_BONUS_BPS, LendanaError::InvalidRiskParameters);
+
+    let threshold_with_bonus = liquidation_threshold_bps
+        .checked_mul(BPS_DENOMINATOR + liquidation_bonus_bps)
+        .ok_or(LendanaError::MathOverflow)?
+        / BPS_DENOMINATOR;
+    require!(threshold_with_bonus <= BPS_DENOMINATOR, LendanaError::InvalidRiskParameters);
+
+    Ok(())
+}
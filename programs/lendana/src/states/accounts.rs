@@ -1,70 +1,345 @@
 use anchor_lang::prelude::*;
 
+/* Every Account Ends With Its Layout Version And Reserved Bytes: New Fields Take Reserved Bytes,
+Or Are Appended After Them And Brought Into Existing Accounts By migrate_account */
 
 /**
  * We Need An Administrator Role
  */
 
  #[account]
+ #[derive(InitSpace)]
  pub struct Administrator {
     pub admin_address: Pubkey,
-    pub admin_bump: u8
+    // Proposed Next Admin, Who Must Accept Before Taking Over
+    pub pending_admin: Option<Pubkey>,
+    pub admin_bump: u8,
+
+    pub version: u8,
+    pub reserved: [u8; 64],
  }
 
  /*
- A Global Registry Of Trusted Roles */
+ A Global Registry Of Trusted Roles, Each Entry Granting One Role To One Address */
  #[account]
+ #[derive(InitSpace)]
  pub struct TrustedEntities {
-    pub trusted_roles: Vec<Pubkey>,
+    #[max_len(20)]
+    pub trusted_roles: Vec<RoleAssignment>,
     pub trusted_entities_bump: u8,
+
+    pub version: u8,
+    pub reserved: [u8; 64],
  }
-/* 
-The platform will support only whitelisted tokens, so we need to first give the whitelister role */
 
+/* The Roles The Admin Can Grant, Each Gating Its Own Set Of Instructions */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum Role {
+    Whitelister,
+    OracleManager,
+    RiskManager,
+    Pauser,
+    Liquidator,
+    Treasurer,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq)]
+pub struct RoleAssignment {
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+/* Protocol-Wide Switches, Created Once At Bootstrap */
 #[account]
-pub struct WhitelisterInfo {
-    pub address: Pubkey,
-    pub whitelister_bump: u8
+#[derive(InitSpace)]
+pub struct ProtocolState {
+   // Blocks New Lends And Borrows Across Every Token; Repayments, Top-Ups, Cancels And Liquidations Still Go Through
+   pub paused: bool,
+
+   pub protocol_state_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
 }
 
-/** A global Mapping Of Whitelisted Tokens */
+/* An Isolated Market With Its Own Listed Tokens, Vaults, Treasuries, E-Mode Categories And Positions,
+All Seeded Under Its Key, So Separate Markets Run From One Program Without Sharing Liquidity Or Risk.
+Markets Opened By The Admin Are Run By The Protocol's Roles; Anyone Else's Are Run By Their Curator */
 #[account]
-pub struct AllWhitelistedTokens {
-   pub tokens_whitelisted: Vec<Pubkey>,
-   pub tokens_whitelisted_bump: u8,
+#[derive(InitSpace)]
+pub struct Market {
+   pub market_id: u64,
+
+   #[max_len(32)]
+   pub name: String,
+
+   pub creator: Pubkey,
+
+   // Lists Tokens, Price Feeds And Risk Parameters In Place Of The Protocol's Roles; None For Admin Markets
+   pub curator: Option<Pubkey>,
+
+   // Loan Durations Offered, A Subset Of The Program's Supported Durations
+   #[max_len(3)]
+   pub lending_durations: Vec<u64>,
+
+   // Lamports Posted By The Creator, Held In This Account On Top Of Its Rent
+   pub creation_deposit: u64,
+
+   // Tokens Whitelisted And Not Yet Fully Delisted; A Curated Market Can Only Be Closed Without Any
+   pub listed_tokens: u16,
+
+   // Set Once A Curated Market Is Closed; The Account Is Kept So Its Id Can Never Be Opened Again
+   pub is_closed: bool,
+
+   pub market_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
 }
 
-/* SOL COLLATERAL PDA VAULT */
+/* Timelock Settings, Created Once At Bootstrap */
 #[account]
 #[derive(InitSpace)]
-pub struct SolCollateralVault {
-   pub vault_bump: u8,
-   pub vault_authority: Pubkey,
-   pub vault_balance: u64,
-   pub is_active: bool,
+pub struct Timelock {
+   // Seconds A Queued Proposal Waits Before It Can Be Executed
+   pub delay: i64,
+   // Id Given To The Next Queued Proposal, Which Also Seeds Its Account
+   pub next_proposal_id: u64,
+
+   pub timelock_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+/* A Queued Parameter Change; Only The Hash Of Its Action Is Stored, The Full Action Is In The Queue Event */
+#[account]
+#[derive(InitSpace)]
+pub struct TimelockProposal {
+   pub proposal_id: u64,
+   pub proposer: Pubkey,
+   pub action_hash: [u8; 32],
+   pub queued_at: i64,
+   // Earliest Execution Time; The Proposal Expires A Grace Period After It
+   pub eta: i64,
+   pub status: ProposalStatus,
+
+   pub proposal_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Queued,
+    Executed,
+    Cancelled,
+}
+
+/* Every Change That Goes Through The Timelock, Carrying The Exact Arguments Of The Instruction That Executes It */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum GovernanceAction {
+    SetTimelockDelay { delay: i64 },
+    ProposeAdmin { new_admin: Pubkey },
+    GrantRole { role: Role, member: Pubkey },
+    RevokeRole { role: Role, member: Pubkey },
+    RotateRole { role: Role, previous_member: Pubkey, new_member: Pubkey },
+    CreateMarket { market_id: u64, name: String },
+    WhitelistToken { market: Pubkey, token_mint: Pubkey, isolation_debt_ceiling: Option<u64> },
+    DelistToken { market: Pubkey, token_mint: Pubkey, target_max_ltv_bps: Option<u64>, wind_down_duration: u64 },
+    AddPriceFeed { market: Pubkey, token_mint: Pubkey, price_feed_id: [u8; 32] },
+    UpdatePriceFeed { market: Pubkey, token_mint: Pubkey, price_feed_id: [u8; 32] },
+    RemovePriceFeed { market: Pubkey, token_mint: Pubkey },
+    SetRiskConfig { market: Pubkey, token_mint: Pubkey, risk_parameters: RiskParameters },
+    SetIsolationMode { market: Pubkey, token_mint: Pubkey, isolation_debt_ceiling: Option<u64> },
+    SetEModeCategory { market: Pubkey, category_id: u8, name: String, emode_parameters: EModeParameters, token_mints: Vec<Pubkey> },
+    ConfigureListingGovernance { governance_mint: Pubkey, quorum: u64, voting_period: i64 },
+}
+
+/* M-of-N Owners Who Act As The Admin Through The Multisig Signer PDA Once It Holds The Admin Role */
+#[account]
+#[derive(InitSpace)]
+pub struct AdminMultisig {
+   #[max_len(10)]
+   pub owners: Vec<Pubkey>,
+   pub threshold: u8,
+   // Bumped Whenever The Owners Or Threshold Change, Voiding Transactions Proposed Before
+   pub owner_set_seqno: u32,
+   // Id Given To The Next Proposed Transaction, Which Also Seeds Its Account
+   pub next_transaction_id: u64,
+
+   pub multisig_bump: u8,
+   pub signer_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+/* A Lendana Instruction The Multisig Signer Signs Once Enough Owners Approve */
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigTransaction {
+   pub transaction_id: u64,
+   pub proposer: Pubkey,
+   #[max_len(24)]
+   pub accounts: Vec<TransactionAccount>,
+   #[max_len(1024)]
+   pub data: Vec<u8>,
+   #[max_len(10)]
+   pub approvals: Vec<Pubkey>,
+   pub owner_set_seqno: u32,
+   pub executed: bool,
+
+   pub transaction_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub struct TransactionAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/* Token-Holder Governance Over Listing New Tokens, Voted With Locked Governance Tokens */
+#[account]
+#[derive(InitSpace)]
+pub struct ListingGovernance {
+   pub governance_mint: Pubkey,
+   // Minimum Weight Voting For A Listing For It To Pass
+   pub quorum: u64,
+   // Seconds A Listing Stays Open For Votes
+   pub voting_period: i64,
+   // Id Given To The Next Listing Proposal, Which Also Seeds Its Account
+   pub next_listing_id: u64,
+
+   pub governance_bump: u8,
+   pub signer_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+/* A Vote On Listing One Token With Its Price Feed And Risk Parameters */
+#[account]
+#[derive(InitSpace)]
+pub struct ListingProposal {
+   pub listing_id: u64,
+   pub proposer: Pubkey,
+   pub market: Pubkey,
+   pub token_mint: Pubkey,
+   pub price_feed_id: [u8; 32],
+   pub risk_parameters: RiskParameters,
+   pub isolation_debt_ceiling: Option<u64>,
+   pub voting_ends_at: i64,
+   pub votes_for: u64,
+   pub votes_against: u64,
+   pub status: ListingStatus,
+   // Timelock Proposals For The Whitelisting, Risk Parameters And Price Feed, Queued Once The Vote Passes
+   pub timelock_proposals: [Pubkey; 3],
+
+   pub listing_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum ListingStatus {
+    Voting,
+    Defeated,
+    Queued,
+    Executed,
+}
+
+/* Every Account Type migrate_account Can Bring Up To The Current Layout */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MigratableAccount {
+    Administrator,
+    TrustedEntities,
+    ProtocolState,
+    Market,
+    Timelock,
+    TimelockProposal,
+    AdminMultisig,
+    MultisigTransaction,
+    ListingGovernance,
+    ListingProposal,
+    ListingVote,
+    SolCollateralEscrow,
+    LenderPositionIDCounter,
+    BorrowerPositionIDCounter,
+    TokenEscrow,
+    TokenConfig,
+    EModeCategory,
+    LenderPosition,
+    BorrowerPosition,
+    BorrowerObligation,
+    MarginAccount,
+    TokenTreasury,
+}
+
+/* One Voter's Locked Weight On A Listing, Returned Once Voting Ends */
+#[account]
+#[derive(InitSpace)]
+pub struct ListingVote {
+   pub listing_proposal: Pubkey,
+   pub voter: Pubkey,
+   pub weight: u64,
+   pub support: bool,
+
+   pub vote_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+/* Per-Position Native SOL Collateral Escrow, Holding Its Collateral On Top Of Its Own Rent-Exempt Minimum */
+#[account]
+#[derive(InitSpace)]
+pub struct SolCollateralEscrow {
+   pub position: Pubkey,
+   pub owner: Pubkey,
+   pub collateral_lamports: u64,
+   pub escrow_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
 }
 
 
 /* Lender Position ID Counter */
 #[account]
+#[derive(InitSpace)]
 pub struct LenderPositionIDCounter {
    pub lenders_current_position_id: u64,
 
-   pub lender_position_id_bump: u8
+   pub lender_position_id_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
 }
 
 
 /* Borrower Position ID Counter */
 #[account]
+#[derive(InitSpace)]
 pub struct BorrowerPositionIDCounter {
    pub borrowers_current_position_id: u64,
 
    pub borrower_position_id_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
 }
 
 
 /** An Associated Token Escrow To Track All Lent and Borrowed Tokens */
 #[account]
+#[derive(InitSpace)]
 pub struct LentBorrowedTokenEscrow {
 
    pub lending_borrowing_token: Pubkey,
@@ -75,34 +350,139 @@ pub struct LentBorrowedTokenEscrow {
 
    pub token_vault_bump: u8,
 
+   // Cleared By The Pauser To Block New Lends And Borrows Of This Token
    pub is_active: bool,
+
+   // Set Once The Whitelister Starts Delisting: No New Lends Or Borrows, Existing Loans Wind Down
+   pub is_delisted: bool,
+
+   // Isolated Tokens Can Only Be Sole Collateral, For Borrowing Tokens Allowed In Isolation
+   pub isolation_mode: bool,
+
+   // Max USD Debt Backed By This Token As Collateral While Isolated, With USD_VALUE_DECIMALS
+   pub isolation_debt_ceiling: u64,
+
+   // Current USD Debt Backed By This Token As Collateral While Isolated
+   pub isolation_debt: u64,
+
+   // Protocol Fees Collected In The Token Treasury And Not Yet Withdrawn
+   pub treasury_fees: u64,
+
+   // Tokens Flash Borrowed From The Vault And Owed Back Within The Same Transaction, 0 When None
+   pub flash_loan_amount: u64,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
 }
 
 
-/* A Registry Mapping of Tokens And Their Price Feed Ids In Hex From Pyth Oracle */
+/* Per-Token Configuration: One PDA Per Mint, So Instructions Load Only The Tokens They Touch
+Whitelisting Fills The Listing Fields, The Oracle Manager The Price Feed, And The Risk Manager The Risk Parameters */
 #[account]
 #[derive(InitSpace)]
-pub struct TokenPriceFeedRegistry {
-   pub authority: Pubkey,
+pub struct TokenConfig {
+   // The Market The Token Is Listed In, Checked Wherever A Config Is Passed Without Its Seeds
+   pub market: Pubkey,
+
+   pub token_mint: Pubkey,
 
-   #[max_len(50)]
-   pub token_price_mapping: Vec<TokenPriceMapping>,// at least 50 tokens
+   // Whether New Lends, Borrows And Collateral Deposits Of The Token Are Accepted
+   pub is_whitelisted: bool,
 
-   pub registry_bump: u8,
+   // Pyth Feed ID, Decoded From Hex When It Is Set
+   pub price_feed_id: Option<[u8; 32]>,
+
+   pub decimals: u8,
+
+   pub risk_parameters: RiskParameters,
+
+   // Set While The Token Is Being Delisted, Lowering Its Max LTV Over Time
+   pub ltv_wind_down: Option<LtvWindDown>,
+
+   // The Token's Escrow And Treasury PDAs, Recorded At Whitelisting
+   pub token_escrow: Pubkey,
+
+   pub treasury: Pubkey,
+
+   pub token_config_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq)]
-pub struct TokenPriceMapping {
-   pub token_mint: Pubkey,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub struct LtvWindDown {
+   pub start_max_ltv_bps: u64,
+
+   pub target_max_ltv_bps: u64,
 
-   #[max_len(70)]
-   pub price_feed_id: String,
+   pub start_time: i64,
+
+   pub end_time: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub struct RiskParameters {
+   // Max Borrow Value As A Share Of Collateral Value, In BPS
+   pub max_ltv_bps: u64,
+
+   // Debt To Collateral Value At Which A Position Becomes Liquidatable, In BPS
+   pub liquidation_threshold_bps: u64,
+
+   // Extra Collateral Paid To Liquidators On Top Of The Repaid Debt, In BPS
+   pub liquidation_bonus_bps: u64,
+
+   // Max Total Lent Tokens In The Escrow, 0 Means No Cap
+   pub supply_cap: u64,
+
+   // Max Total Borrowed Tokens From The Escrow, 0 Means No Cap
+   pub borrow_cap: u64,
+
+   // Whether The Token Can Be Borrowed Against Isolated Collateral, e.g Stablecoins
+   pub borrowable_in_isolation: bool,
+
+   // Share Of Every Interest Payment Skimmed Into The Token Treasury, In BPS
+   pub reserve_factor_bps: u64,
+
+   // Fee Charged On Every Borrow Of The Token, Paid Into The Token Treasury, In BPS
+   pub origination_fee_bps: u64,
 }
 
 
 
 
 
+/* Efficiency Mode Category: Correlated Tokens Borrowed Against Each Other With Higher LTV */
+#[account]
+#[derive(InitSpace)]
+pub struct EModeCategory {
+   pub category_id: u8,
+
+   #[max_len(32)]
+   pub name: String,
+
+   pub emode_parameters: EModeParameters,
+
+   #[max_len(10)]
+   pub token_mints: Vec<Pubkey>,
+
+   pub emode_bump: u8,
+
+   pub version: u8,
+   pub reserved: [u8; 64],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub struct EModeParameters {
+   pub max_ltv_bps: u64,
+
+   pub liquidation_threshold_bps: u64,
+
+   pub liquidation_bonus_bps: u64,
+}
+
+
+
 /* -------------------------------------               LENDER ACCOUNTS              ------------------------------------ */
 
 
@@ -132,6 +512,10 @@ pub struct LenderPosition {
 
    pub lender_position_bump: u8,// 1 byte
 
+   pub version: u8,// 1 byte: layout version, set by init and migrate_account
+
+   pub reserved: [u8; 64],// 64 bytes: room for new fields without a realloc
+
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
@@ -169,4 +553,110 @@ pub struct BorrowerPosition {
    pub borrowing_start: i64,// 8 bytes
 
    pub borrower_position_bump: u8,// 1 byte
-}
\ No newline at end of file
+
+   pub isolated_debt: u64,// 8 bytes: USD debt counted against an isolated collateral's ceiling
+
+   pub emode_category: u8,// 1 byte: 0 when the position is not in e-mode
+
+   pub version: u8,// 1 byte: layout version, set by init and migrate_account
+
+   pub reserved: [u8; 64],// 64 bytes: room for new fields without a realloc
+}
+
+
+
+/* A MULTI-COLLATERAL BORROWER OBLIGATION: A BASKET OF COLLATERAL DEPOSITS BACKING ONE DEBT */
+#[account]
+#[derive(InitSpace)]
+pub struct BorrowerObligation {
+   pub borrower_pubkey: Pubkey,// 32 bytes
+
+   #[max_len(5)]
+   pub collateral_deposits: Vec<CollateralDeposit>,
+
+   pub borrowing_token: Pubkey,// 32 bytes: default pubkey while there is no debt
+
+   pub borrowing_token_decimals: u8,// 1 byte
+
+   pub borrowing_amount: u64,// 8 bytes: outstanding principal
+
+   pub interest_owed: u64,// 8 bytes: interest settled into the obligation but not yet repaid
+
+   pub borrowing_terms: LoanTerms,// 16 bytes
+
+   pub borrowing_start: i64,// 8 bytes: the loan term runs from here
+
+   pub interest_accrued_at: i64,// 8 bytes: interest on the principal is settled up to here
+
+   pub isolated_debt: u64,// 8 bytes: USD debt counted against an isolated collateral's ceiling
+
+   pub obligation_bump: u8,// 1 byte
+
+   pub version: u8,// 1 byte: layout version, set by init and migrate_account
+
+   pub reserved: [u8; 64],// 64 bytes: room for new fields without a realloc
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub struct CollateralDeposit {
+   pub token_mint: Pubkey,
+
+   pub decimals: u8,
+
+   pub amount: u64,
+
+   pub is_isolated: bool,
+}
+
+
+
+/* A CROSS-MARGINED USER ACCOUNT: ONE COLLATERAL BASKET BACKING BORROWS IN SEVERAL TOKENS */
+#[account]
+#[derive(InitSpace)]
+pub struct MarginAccount {
+   pub borrower_pubkey: Pubkey,// 32 bytes
+
+   #[max_len(5)]
+   pub collateral_deposits: Vec<CollateralDeposit>,
+
+   #[max_len(5)]
+   pub borrows: Vec<MarginDebt>,
+
+   pub margin_account_bump: u8,// 1 byte
+
+   pub version: u8,// 1 byte: layout version, set by init and migrate_account
+
+   pub reserved: [u8; 64],// 64 bytes: room for new fields without a realloc
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub struct MarginDebt {
+   pub token_mint: Pubkey,
+
+   pub decimals: u8,
+
+   pub borrowing_amount: u64,// outstanding principal
+
+   pub interest_owed: u64,// interest settled into the debt but not yet repaid
+
+   pub borrowing_terms: LoanTerms,
+
+   pub borrowing_start: i64,// the loan term runs from here
+
+   pub interest_accrued_at: i64,// interest on the principal is settled up to here
+}
+
+
+
+/* PER-TOKEN PROTOCOL TREASURY: OWNS THE VAULT THAT COLLECTS RESERVE AND ORIGINATION FEES */
+#[account]
+#[derive(InitSpace)]
+pub struct TokenTreasury {
+   pub token_mint: Pubkey,// 32 bytes
+
+   pub treasury_bump: u8,// 1 byte
+
+   pub version: u8,// 1 byte: layout version, set by init and migrate_account
+
+   pub reserved: [u8; 64],// 64 bytes: room for new fields without a realloc
+}
@@ -12,7 +12,52 @@ pub const THREE_MONTH_LENDING_DURATION: u64 = 7_776_000;// 86400 * 90
 
 pub const ONE_MONTH_LENDING_DURATION: u64 = 2_592_000;// 86400 * 30
 
-pub const MIN_COLLATERAL_RATIO: u64 = 12000; // 150%
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Upper Bound On The Liquidation Bonus A Risk Manager Can Set
+pub const MAX_LIQUIDATION_BONUS_BPS: u64 = 2_000;// 20%
+
+// Upper Bound On The Origination Fee A Risk Manager Can Set
+pub const MAX_ORIGINATION_FEE_BPS: u64 = 1_000;// 10%
+
+// Fee On Every Flash Loan, Paid Into The Treasury
+pub const FLASH_LOAN_FEE_BPS: u64 = 9;// 0.09%
+
+// Max Tokens In A Single E-Mode Category, And Max Length Of Its Name
+pub const MAX_EMODE_CATEGORY_TOKENS: usize = 10;
+
+pub const MAX_EMODE_NAME_LEN: usize = 32;
+
+// Max Role Assignments Held In The Trusted Roles Registry
+pub const MAX_ROLE_ASSIGNMENTS: usize = 20;
+
+// Admin Multisig Limits, Matching The Account Sizes
+pub const MAX_MULTISIG_OWNERS: usize = 10;
+pub const MAX_MULTISIG_TRANSACTION_ACCOUNTS: usize = 24;
+pub const MAX_MULTISIG_TRANSACTION_DATA: usize = 1024;
+
+// Max Collateral Tokens In A Borrower Obligation
+pub const MAX_OBLIGATION_COLLATERALS: usize = 5;
+
+// Max Collateral Tokens And Borrowed Tokens In A Margin Account
+pub const MAX_MARGIN_COLLATERALS: usize = 5;
+pub const MAX_MARGIN_BORROWS: usize = 5;
+
+// Longest Delay The Timelock Can Be Set To
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// A Matured Proposal Must Be Executed Within This Long, Or Be Queued Again
+pub const TIMELOCK_GRACE_PERIOD: i64 = 14 * 24 * 60 * 60; // 14 days
+
+// Longest Voting Period Of A Token Listing Proposal
+pub const MAX_LISTING_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Health Accounts Passed Through remaining_accounts: [token config, price update, mint] Per Deposit And Per Borrow
+pub const HEALTH_ACCOUNTS_PER_DEPOSIT: usize = 3;
+pub const HEALTH_ACCOUNTS_PER_BORROW: usize = 3;
+
+// USD Values Are Expressed With This Many Decimals Across Risk Calculations
+pub const USD_VALUE_DECIMALS: u32 = 6;
 
 // Wrapped SOL Mint Address
 pub const NATIVE_SOL_MINT_ADDRESS: Pubkey = spl_token::native_mint::id();
@@ -35,4 +80,16 @@ pub const SOL_USD_PRICE_FEED_ID_HEX: &str = "0xef0d8b6fda2ceba41da15d4095d1da392
 pub const JITOSOL_USD_PRICE_FEED_ID_HEX: &str = "0x67be9f519b95cf24338801051f9a808eff0a578ccb388db73b7f6fe1de019ffb";
 
 // MAXIMUM AGE OF PRICE FEED
-pub const MAX_PRICE_FEED_AGE: u64 = 30; // 30 seconds
\ No newline at end of file
+pub const MAX_PRICE_FEED_AGE: u64 = 30; // 30 seconds
+
+// Layout Version Every Account Is Created With, Or Brought Up To By migrate_account
+pub const ACCOUNT_VERSION: u8 = 1;
+
+// Longest Market Name, In Bytes
+pub const MAX_MARKET_NAME_LEN: usize = 32;
+
+// Every Loan Duration A Market Can Offer
+pub const SUPPORTED_LENDING_DURATIONS: [u64; 3] = [ONE_MONTH_LENDING_DURATION, THREE_MONTH_LENDING_DURATION, SIX_MONTH_LENDING_DURATION];
+
+// Lamports Posted By Anyone Opening A Curated Market, Refunded When They Close It
+pub const MARKET_CREATION_DEPOSIT: u64 = 1_000_000_000; // 1 SOL
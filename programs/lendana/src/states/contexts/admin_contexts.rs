@@ -1,36 +1,74 @@
 use anchor_lang::prelude::*;
 
-use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked, close_account}};
 
-use crate::states::{accounts::*, errors::*};
+use crate::{program::Lendana, states::{accounts::*, constants::*, errors::*}, utils::{has_role, is_market_authority, market_leftovers_recipient, transfer_tokens}};
 
 /*
-The Admin Context Struct
- */
-
- #[derive(Accounts)]
- #[instruction(admin_address: Pubkey)]
- pub struct InitializeAdmin<'info> {
+One-Time Protocol Bootstrap: Creates The Admin, Trusted Roles, Protocol State And Timelock, And Only The Program's Upgrade Authority May Call It */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BootstrapProtocol<'info> {
     #[account(mut)]
-    pub deployer: Signer<'info>,
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(
+        constraint = lendana_program.programdata_address()? == Some(program_data.key()) @ LendanaError::InvalidProgramData,
+    )]
+    pub lendana_program: Program<'info, Lendana>,
+
+    // The Program's ProgramData Account Records Who May Upgrade, And So Who May Bootstrap
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ LendanaError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
 
+    // Every Account Below Is Created With `init`, So A Second Bootstrap Fails
     #[account(
         init,
-        payer = deployer,
-        seeds = [b"admin", admin_address.key().as_ref()],
+        payer = upgrade_authority,
+        seeds = [b"admin"],
         bump,
-        space = 8 + 32 + 1,
+        space = 8 + Administrator::INIT_SPACE,
     )]
-    pub admin_account: Account<'info, Administrator>,
+    pub admin_account: Box<Account<'info, Administrator>>,
 
-    pub system_program: Program<'info, System>,
- }
+    #[account(
+        init,
+        payer = upgrade_authority,
+        seeds = [b"trusted_entities"],
+        bump,
+        space = 8 + TrustedEntities::INIT_SPACE,
+    )]
+    pub trusted_roles: Box<Account<'info, TrustedEntities>>,
 
- /*
- The Trusted Entities Context Struct */
- #[derive(Accounts)]
- pub struct InitializeTrustedRoles<'info> {
+    #[account(
+        init,
+        payer = upgrade_authority,
+        seeds = [b"protocol_state"],
+        bump,
+        space = 8 + ProtocolState::INIT_SPACE,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
 
+    #[account(
+        init,
+        payer = upgrade_authority,
+        seeds = [b"timelock"],
+        bump,
+        space = 8 + Timelock::INIT_SPACE,
+    )]
+    pub timelock: Box<Account<'info, Timelock>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+The Admin Opens An Isolated Market, With Its Own Position Counters, Through A Matured Timelock Proposal */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct CreateMarket<'info> {
     #[account(
         mut,
         constraint = admin.key() == admin_account.admin_address.key() @ LendanaError::OnlyAdmin
@@ -38,7 +76,7 @@ The Admin Context Struct
     pub admin: Signer<'info>,
 
     #[account(
-        seeds = [b"admin", admin_account.admin_address.key().as_ref()],
+        seeds = [b"admin"],
         bump = admin_account.admin_bump
     )]
     pub admin_account: Account<'info, Administrator>,
@@ -46,29 +84,106 @@ The Admin Context Struct
     #[account(
         init,
         payer = admin,
-        seeds = [b"trusted_entities"],
+        space = 8 + Market::INIT_SPACE,
+        seeds = [b"market", market_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + LenderPositionIDCounter::INIT_SPACE,
+        seeds = [b"lenders_position_id_counter", market.key().as_ref()],
         bump,
-        space = 8 + 4 + (10 * 32) + 1// Maximum of 10 entities
     )]
-    pub trusted_roles: Account<'info, TrustedEntities>,
+    pub lenders_position_id_counter: Box<Account<'info, LenderPositionIDCounter>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + BorrowerPositionIDCounter::INIT_SPACE,
+        seeds = [b"borrowers_position_id_counter", market.key().as_ref()],
+        bump,
+    )]
+    pub borrowers_position_id_counter: Box<Account<'info, BorrowerPositionIDCounter>>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
 
     pub system_program: Program<'info, System>,
+}
+
+ /*
+ Propose Handing The Admin Role To A New Address, Through A Matured Timelock Proposal */
+ #[event_cpi]
+ #[derive(Accounts)]
+ pub struct ProposeAdmin<'info> {
+    #[account(
+        constraint = admin.key() == admin_account.admin_address.key() @ LendanaError::OnlyAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
  }
 
-/*
-The Whitelister context Struct */
-#[derive(Accounts)]
-#[instruction(whitelister_address: Pubkey)]
-pub struct InitializeWhiteLister<'info> {
+ /*
+ Cancel A Pending Admin Transfer, Which Takes Effect Immediately */
+ #[event_cpi]
+ #[derive(Accounts)]
+ pub struct TransferAdmin<'info> {
+    #[account(
+        constraint = admin.key() == admin_account.admin_address.key() @ LendanaError::OnlyAdmin
+    )]
+    pub admin: Signer<'info>,
 
     #[account(
         mut,
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+ }
+
+ /*
+ The Proposed Admin Accepts The Role */
+ #[event_cpi]
+ #[derive(Accounts)]
+ pub struct AcceptAdmin<'info> {
+    #[account(
+        constraint = admin_account.pending_admin == Some(pending_admin.key()) @ LendanaError::NotPendingAdmin
+    )]
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+ }
+
+/*
+The Admin Grants And Revokes Roles In The Trusted Roles Registry */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ManageRole<'info> {
+    #[account(
         constraint = admin.key() == admin_account.admin_address.key() @ LendanaError::OnlyAdmin
     )]
     pub admin: Signer<'info>,
 
     #[account(
-        seeds = [b"admin", admin_account.admin_address.key().as_ref()],
+        seeds = [b"admin"],
         bump = admin_account.admin_bump
     )]
     pub admin_account: Account<'info, Administrator>,
@@ -80,181 +195,719 @@ pub struct InitializeWhiteLister<'info> {
     )]
     pub trusted_roles: Account<'info, TrustedEntities>,
 
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+}
+
+/*
+Anyone Can Read Which Addresses Hold A Role */
+#[derive(Accounts)]
+pub struct ListRoleMembers<'info> {
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+}
+
+/*
+Queue A Parameter Change Behind The Timelock */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueTimelockProposal<'info> {
+    // Admin Or The Role Gating The Change, Checked Against The Action In The Handler
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    // The Market A Curator Proposes For; Role Holders And The Admin Leave It Out
+    pub market: Option<Box<Account<'info, Market>>>,
+
+    #[account(
+        mut,
+        seeds = [b"timelock"],
+        bump = timelock.timelock_bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
     #[account(
         init,
-        payer = admin,
-        seeds = [b"whitelister", whitelister_address.key().as_ref()],
-        bump,
-        space = 8 + 32 + 1 // 8 for discriminator, 32 for pubkey and 1 for bump
+        payer = proposer,
+        space = 8 + TimelockProposal::INIT_SPACE,
+        seeds = [b"timelock_proposal", timelock.next_proposal_id.to_le_bytes().as_ref()],
+        bump
     )]
-    pub whitelister: Account<'info, WhitelisterInfo>,
+    pub timelock_proposal: Account<'info, TimelockProposal>,
 
     pub system_program: Program<'info, System>,
+}
+
+/*
+The Proposer Or The Admin Cancels A Queued Proposal */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelTimelockProposal<'info> {
+    #[account(
+        constraint = (canceller.key() == timelock_proposal.proposer ||
+        canceller.key() == admin_account.admin_address) @ LendanaError::OnlyProposerOrAdmin,
+    )]
+    pub canceller: Signer<'info>,
 
+    #[account(
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
 }
 
-/* A Global Container Of Whitelisted Tokens */
+/*
+The Admin Changes The Timelock Delay, Itself Through A Matured Proposal */
+#[event_cpi]
 #[derive(Accounts)]
-pub struct GlobalWhitelistedTokensPositionCountersAndPriceRegistry<'info> {
-    // Signer ought to be whitelister
+pub struct UpdateTimelockDelay<'info> {
+    #[account(
+        constraint = admin.key() == admin_account.admin_address.key() @ LendanaError::OnlyAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
     #[account(
         mut,
-        constraint = whitelister_role.key() == whitelister.address.key() @ LendanaError::OnlyWhitelister,
+        seeds = [b"timelock"],
+        bump = timelock.timelock_bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+}
+
+/** TOKEN WHITELISTING OPERATION */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct WhitelistToken<'info> {
+    // Signer supposed to be whitelister
+    #[account(
+        mut,
+        constraint = is_market_authority(&trusted_roles, &market, Role::Whitelister, &whitelister_role.key()) @ LendanaError::OnlyWhitelister,
     )]
     pub whitelister_role: Signer<'info>,
 
+    // A Closed Market Lists Nothing Again
     #[account(
         mut,
-        seeds = [b"whitelister", whitelister_role.key().as_ref()],
-        bump = whitelister.whitelister_bump,
+        constraint = !market.is_closed @ LendanaError::MarketClosed,
     )]
-    pub whitelister: Account<'info, WhitelisterInfo>,
+    pub market: Box<Account<'info, Market>>,
 
-    // Let's Initialize The Global Container For Whitelisted tokens Here
     #[account(
-        init,
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    // Mint of Whitelisted Token
+    #[account(
+        constraint = token_mint.key() == mint_token.key() @LendanaError::MismatchedTokenMint,
+    )]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    // Token Config, Created On First Listing Or Kept From A Risk Manager Update Or An Earlier Listing
+    #[account(
+        init_if_needed,
         payer = whitelister_role,
-        seeds = [b"all_whitelisted_tokens"],
-        bump,
-        space = 8 + 4 + (7 * 32) + 1,// Support Up to 7 Tokens
+        space = 8 + TokenConfig::INIT_SPACE,
+        seeds = [b"token_config", market.key().as_ref(), mint_token.key().as_ref()],
+        bump
     )]
-    pub all_whitelisted_tokens: Account<'info, AllWhitelistedTokens>,
+    pub token_config: Box<Account<'info, TokenConfig>>,
 
-    // Let's Initialize The Lender Position ID Counter Here
+    // Token Escrow Account
     #[account(
         init,
         payer = whitelister_role,
-        space = 8 + 8 + 1,
-        seeds = [b"lenders_position_id_counter"],
-        bump,
+        space = 8 + LentBorrowedTokenEscrow::INIT_SPACE,
+        seeds = [b"token_escrow", market.key().as_ref(), mint_token.key().as_ref()],
+        bump
     )]
-    pub lenders_position_id_counter: Account<'info, LenderPositionIDCounter>,
+    pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
 
-    // Let's Initialize The Borrower Position ID Counter Here
+    // The Associated Token Esrow Vault
     #[account(
         init,
         payer = whitelister_role,
-        space = 8 + 8 + 1,
-        seeds = [b"borrowers_position_id_counter"],
-        bump,
+        associated_token::mint = mint_token,
+        associated_token::authority = token_escrow,
     )]
-    pub borrowers_position_id_counter: Account<'info, BorrowerPositionIDCounter>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
-    // Token Price Feeds Registry
+    // The Token Treasury, Collecting Protocol Fees In Its Own Vault
     #[account(
         init,
         payer = whitelister_role,
-        seeds = [b"price_feed_registry"],
-        bump,
-        space = 8 + TokenPriceFeedRegistry::INIT_SPACE,
+        space = 8 + TokenTreasury::INIT_SPACE,
+        seeds = [b"treasury", market.key().as_ref(), mint_token.key().as_ref()],
+        bump
     )]
-    pub tokens_price_feed_registry: Account<'info, TokenPriceFeedRegistry>,
+    pub treasury: Account<'info, TokenTreasury>,
 
-    // SOL COLLATERAL PDA VAULT
     #[account(
         init,
         payer = whitelister_role,
-        seeds = [b"sol_collateral_vault"],
-        bump,
-        space = 8 + SolCollateralVault::INIT_SPACE,
+        associated_token::mint = mint_token,
+        associated_token::authority = treasury,
     )]
-    pub sol_collateral_vault: Account<'info, SolCollateralVault>,
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Box<Account<'info, TimelockProposal>>,
 
+    // System and Associated Token programs, and Token program
     pub system_program: Program<'info, System>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
-/** TOKEN WHITELISTING OPERATION */
+
+
+/* START DELISTING A WHITELISTED TOKEN */
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(token_mint: Pubkey)]
-pub struct WhitelistToken<'info> {
+pub struct DelistToken<'info> {
     // Signer supposed to be whitelister
     #[account(
-        mut,
-        constraint = whitelister_role.key() == whitelister.address.key() @ LendanaError::OnlyWhitelister,
+        constraint = is_market_authority(&trusted_roles, &market, Role::Whitelister, &whitelister_role.key()) @ LendanaError::OnlyWhitelister,
     )]
     pub whitelister_role: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
     #[account(
         mut,
-        seeds = [b"whitelister", whitelister.address.key().as_ref()],
-        bump = whitelister.whitelister_bump,
+        seeds = [b"token_escrow", market.key().as_ref(), token_mint.as_ref()],
+        bump = token_escrow.token_vault_bump,
+        constraint = !token_escrow.is_delisted @ LendanaError::TokenDelisted,
     )]
-    pub whitelister: Account<'info, WhitelisterInfo>,
+    pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
 
-    // Global Registry Whitelisted
+    // Token Config, Holding The Whitelist Status And The Optional LTV Wind-Down
     #[account(
         mut,
-        seeds = [b"all_whitelisted_tokens"],
-        bump = all_whitelisted_tokens.tokens_whitelisted_bump,
+        seeds = [b"token_config", market.key().as_ref(), token_mint.as_ref()],
+        bump = token_config.token_config_bump,
     )]
-    pub all_whitelisted_tokens: Account<'info, AllWhitelistedTokens>,
+    pub token_config: Account<'info, TokenConfig>,
 
-    // Mint of Whitelisted Token
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+}
+
+
+/* FINISH DELISTING A TOKEN ONCE ITS POSITIONS HAVE WOUND DOWN, RECLAIMING ESCROW, TREASURY AND VAULT RENT */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeTokenDelisting<'info> {
+    // Signer supposed to be whitelister, and receives the reclaimed rent
     #[account(
-        constraint = token_mint.key() == mint_token.key() @LendanaError::MismatchedTokenMint,
+        mut,
+        constraint = is_market_authority(&trusted_roles, &market, Role::Whitelister, &whitelister_role.key()) @ LendanaError::OnlyWhitelister,
+    )]
+    pub whitelister_role: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
     )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
     pub mint_token: InterfaceAccount<'info, Mint>,
 
-    // Token Escrow Account
+    // Token Config, Kept So A Later Listing Reuses Its Risk Parameters And Price Feed
     #[account(
-        init,
-        payer = whitelister_role,
-        space = 8 + 32 + 8 + 8 + 1 + 1,
-        seeds = [b"token_escrow", mint_token.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"token_config", market.key().as_ref(), mint_token.key().as_ref()],
+        bump = token_config.token_config_bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(
+        mut,
+        close = whitelister_role,
+        seeds = [b"token_escrow", market.key().as_ref(), mint_token.key().as_ref()],
+        bump = token_escrow.token_vault_bump,
+        constraint = token_escrow.is_delisted @ LendanaError::TokenNotDelisted,
     )]
     pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
 
-    // The Associated Token Esrow Vault
     #[account(
-        init,
-        payer = whitelister_role,
+        mut,
         associated_token::mint = mint_token,
         associated_token::authority = token_escrow,
     )]
     pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
-    // System and Associated Token programs, and Token program
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        close = whitelister_role,
+        seeds = [b"treasury", market.key().as_ref(), mint_token.key().as_ref()],
+        bump = treasury.treasury_bump,
+    )]
+    pub treasury: Account<'info, TokenTreasury>,
 
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    // Receives Whatever Is Left In Either Vault Before They Are Closed: The Curator's For Curated Markets, Else The Admin's
+    #[account(
+        mut,
+        token::mint = mint_token,
+        token::authority = market_leftovers_recipient(&market, &admin_account),
+        token::token_program = token_program,
+    )]
+    pub leftovers_token_account: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+impl<'info> FinalizeTokenDelisting<'info> {
+
+    // Empties Both Vaults Into The Leftovers Token Account, So They Can Be Closed; Returns The Amount Swept
+    pub fn sweep_leftovers(&self, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        let token_mint = self.mint_token.key();
+        let market = self.market.key();
+        let escrow_seeds = &[
+            b"token_escrow",
+            market.as_ref(),
+            token_mint.as_ref(),
+            &[self.token_escrow.token_vault_bump],
+        ];
+        let treasury_seeds = &[
+            b"treasury",
+            market.as_ref(),
+            token_mint.as_ref(),
+            &[self.treasury.treasury_bump],
+        ];
+
+        let leftovers = [
+            (&self.token_vault, self.token_escrow.to_account_info(), &escrow_seeds[..]),
+            (&self.treasury_vault, self.treasury.to_account_info(), &treasury_seeds[..]),
+        ];
+        let mut swept_amount: u64 = 0;
+        for (vault, authority, seeds) in leftovers {
+            if vault.amount == 0 {
+                continue;
+            }
+
+            let cpi_accounts = TransferChecked {
+                from: vault.to_account_info(),
+                to: self.leftovers_token_account.to_account_info(),
+                mint: self.mint_token.to_account_info(),
+                authority,
+            };
+            let signer_seeds = &[seeds];
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+            transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), vault.amount, self.mint_token.decimals)?;
+
+            swept_amount = swept_amount.checked_add(vault.amount).ok_or(LendanaError::TokenAdditionOverflow)?;
+        }
+
+        Ok(swept_amount)
+    }
+
+    // Closes The Token Vault And The Treasury Vault, Both Signed For By Their PDA Owners
+    pub fn close_token_vaults(&self) -> Result<()> {
+
+        let token_mint = self.mint_token.key();
+        let market = self.market.key();
+        let escrow_seeds = &[
+            b"token_escrow",
+            market.as_ref(),
+            token_mint.as_ref(),
+            &[self.token_escrow.token_vault_bump],
+        ];
+        let treasury_seeds = &[
+            b"treasury",
+            market.as_ref(),
+            token_mint.as_ref(),
+            &[self.treasury.treasury_bump],
+        ];
+
+        let cpi_accounts = CloseAccount {
+            account: self.token_vault.to_account_info(),
+            destination: self.whitelister_role.to_account_info(),
+            authority: self.token_escrow.to_account_info(),
+        };
+        close_account(CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, &[&escrow_seeds[..]]))?;
+
+        let cpi_accounts = CloseAccount {
+            account: self.treasury_vault.to_account_info(),
+            destination: self.whitelister_role.to_account_info(),
+            authority: self.treasury.to_account_info(),
+        };
+        close_account(CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, &[&treasury_seeds[..]]))?;
+
+        Ok(())
+    }
+}
+
 
 /* ADD A WHITELISTED TOKEN PRICE FEED MAPPING */
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
 pub struct AddTokenPriceMapping<'info> {
 
+    // Signer ought to be oracle manager
+    #[account(
+        constraint = is_market_authority(&trusted_roles, &market, Role::OracleManager, &oracle_manager.key()) @ LendanaError::UnauthorizedPriceUpdater,
+    )]
+    pub oracle_manager: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    // Token Config Of The Whitelisted Token (Or Native SOL Collateral), Which Stores Its Price Feed ID
     #[account(
         mut,
-        constraint = whitelister.key() == tokens_price_feed_registry.authority @LendanaError::UnauthorizedPriceUpdater,
+        seeds = [b"token_config", market.key().as_ref(), token_mint.as_ref()],
+        bump = token_config.token_config_bump,
+        constraint = (token_config.is_whitelisted ||
+        token_mint == NATIVE_SOL_MINT_ADDRESS) @LendanaError::NotWhitelistedToken,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+}
+
+/* UPDATE OR REMOVE A TOKEN'S PRICE FEED MAPPING, e.g. WHEN A FEED IS MIGRATED */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct ManageTokenPriceMapping<'info> {
+
+    // Signer ought to be oracle manager
+    #[account(
+        constraint = is_market_authority(&trusted_roles, &market, Role::OracleManager, &oracle_manager.key()) @ LendanaError::UnauthorizedPriceUpdater,
+    )]
+    pub oracle_manager: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
     )]
-    pub whitelister: Signer<'info>,
+    pub trusted_roles: Account<'info, TrustedEntities>,
 
-    // Token Price Registry
+    // Token Config Holding The Mapped Feed; Delisted Tokens Can Still Be Updated Or Cleared
     #[account(
         mut,
-        seeds = [b"price_feed_registry"],
-        bump = tokens_price_feed_registry.registry_bump
+        seeds = [b"token_config", market.key().as_ref(), token_mint.as_ref()],
+        bump = token_config.token_config_bump,
     )]
-    pub tokens_price_feed_registry: Account<'info, TokenPriceFeedRegistry>,
+    pub token_config: Account<'info, TokenConfig>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
 }
 
-impl<'info> AddTokenPriceMapping<'info> {
-    // Method To Add Price Feed
-    pub fn add_token_price_to_registry(&mut self, token_mint: Pubkey, price_feed_id_hex: String) -> Result<()> {
+/* SET OR UPDATE THE RISK PARAMETERS OF A TOKEN */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct SetTokenRiskConfig<'info> {
+    // Signer ought to be risk manager
+    #[account(
+        mut,
+        constraint = is_market_authority(&trusted_roles, &market, Role::RiskManager, &risk_manager_role.key()) @ LendanaError::OnlyRiskManager,
+    )]
+    pub risk_manager_role: Signer<'info>,
 
-        let token_price_registry =&mut self.tokens_price_feed_registry;
+    pub market: Box<Account<'info, Market>>,
 
-        // Check if Price Mapping For Specified Token Does Not Exist
-        if token_price_registry.token_price_mapping.iter().any(|m| m.token_mint == token_mint) {
-            return err!(LendanaError::TokenPriceAlreadyExists);
-        }
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    // Mint of The Configured Token
+    #[account(
+        constraint = token_mint.key() == mint_token.key() @LendanaError::MismatchedTokenMint,
+    )]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    // Token Risk Config, Created On First Update
+    #[account(
+        init_if_needed,
+        payer = risk_manager_role,
+        space = 8 + TokenConfig::INIT_SPACE,
+        seeds = [b"token_config", market.key().as_ref(), mint_token.key().as_ref()],
+        bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+/* UPDATE THE ISOLATION MODE DEBT CEILING OF A WHITELISTED TOKEN */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct SetIsolationMode<'info> {
+    // Signer ought to be risk manager
+    #[account(
+        constraint = is_market_authority(&trusted_roles, &market, Role::RiskManager, &risk_manager_role.key()) @ LendanaError::OnlyRiskManager,
+    )]
+    pub risk_manager_role: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    // Token Escrow Tracking The Isolated Debt
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_mint.as_ref()],
+        bump = token_escrow.token_vault_bump,
+    )]
+    pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+}
+
+
+/* CREATE OR UPDATE AN E-MODE CATEGORY OF CORRELATED TOKENS */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(category_id: u8)]
+pub struct SetEModeCategory<'info> {
+    // Signer ought to be risk manager
+    #[account(
+        mut,
+        constraint = is_market_authority(&trusted_roles, &market, Role::RiskManager, &risk_manager_role.key()) @ LendanaError::OnlyRiskManager,
+    )]
+    pub risk_manager_role: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    // E-Mode Category, Created On First Update
+    #[account(
+        init_if_needed,
+        payer = risk_manager_role,
+        space = 8 + EModeCategory::INIT_SPACE,
+        seeds = [b"emode_category", market.key().as_ref(), category_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub emode_category: Account<'info, EModeCategory>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+/* PAUSE OR UNPAUSE NEW LENDS AND BORROWS ACROSS THE PROTOCOL */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProtocolPause<'info> {
+    // Signer ought to be pauser
+    #[account(
+        constraint = has_role(&trusted_roles, Role::Pauser, &pauser.key()) @ LendanaError::OnlyPauser,
+    )]
+    pub pauser: Signer<'info>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_state"],
+        bump = protocol_state.protocol_state_bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+
+/* PAUSE OR UNPAUSE NEW LENDS AND BORROWS OF A SINGLE TOKEN */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct SetTokenPause<'info> {
+    // Signer ought to be pauser
+    #[account(
+        constraint = has_role(&trusted_roles, Role::Pauser, &pauser.key()) @ LendanaError::OnlyPauser,
+    )]
+    pub pauser: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_mint.as_ref()],
+        bump = token_escrow.token_vault_bump,
+    )]
+    pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
+}
+
+
+/* WITHDRAW COLLECTED PROTOCOL FEES FROM A TOKEN TREASURY */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    // Signer ought to be treasurer
+    #[account(
+        constraint = has_role(&trusted_roles, Role::Treasurer, &treasurer.key()) @ LendanaError::OnlyTreasurer,
+    )]
+    pub treasurer: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    // Token Escrow Tracking The Collected Fees
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_mint.key().as_ref()],
+        bump = token_escrow.token_vault_bump,
+    )]
+    pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
+
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_mint.key().as_ref()],
+        bump = treasury.treasury_bump,
+    )]
+    pub treasury: Account<'info, TokenTreasury>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+
+    // Any Token Account Of The Mint Chosen By The Treasurer
+    #[account(
+        mut,
+        token::mint = token_mint,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+
+    pub fn transfer_from_treasury(&mut self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let token_mint = self.token_mint.key();
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.treasury_vault.to_account_info(),
+            to: self.recipient_token_account.to_account_info(),
+            mint: self.token_mint.to_account_info(),
+            authority: self.treasury.to_account_info(),
+        };
+
+        let market = self.market.key();
+        let seeds = &[
+            b"treasury",
+            market.as_ref(),
+            token_mint.as_ref(),
+            &[self.treasury.treasury_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount, self.token_mint.decimals)?;
 
-        // Let's Put This Token Price Feed Id into The Registry
-        let price_feed_id = price_feed_id_hex.to_string();
-        token_price_registry.token_price_mapping.push(TokenPriceMapping { token_mint, price_feed_id});
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -1,33 +1,35 @@
 
 
-use anchor_lang::{prelude::*, system_program::{Transfer, transfer}};
+use anchor_lang::prelude::*;
 
-use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, 
-transfer_checked, TransferChecked}};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}};
 
-use crate::states::{accounts::*, errors::*, constants::*};
+use crate::{states::{accounts::*, errors::*, constants::*}, utils::*};
 
-use pyth_solana_receiver_sdk::{ID as PYTH_PROGRAM_ID, price_update::{get_feed_id_from_hex, PriceUpdateV2}};
+use pyth_solana_receiver_sdk::{ID as PYTH_PROGRAM_ID, price_update::PriceUpdateV2};
 
 
 
 
 //** BORROWER POSITION INFO */
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(collateral_token: Pubkey, borrowing_token: Pubkey)]
 pub struct BorrowerPositionInfo<'info> {
     #[account(mut)]
     pub borrower: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
     #[account(
-        constraint = all_whitelisted_tokens.tokens_whitelisted.contains(&token_to_borrow.key()) @LendanaError::NotWhitelistedToken,
+        constraint = borrowing_token_config.is_whitelisted @LendanaError::NotWhitelistedToken,
         constraint = borrowing_token.key() == token_to_borrow.key() @LendanaError::MismatchBorrowToken,
     )]
     pub token_to_borrow: InterfaceAccount<'info, Mint>,
 
     #[account(
-        constraint = (all_whitelisted_tokens.tokens_whitelisted.contains(&token_collateral.key()) ||
+        constraint = (collateral_token_config.is_whitelisted ||
         token_collateral.key() == NATIVE_SOL_MINT_ADDRESS) @LendanaError::NotWhitelistedToken,
         constraint = token_collateral.key() != token_to_borrow.key() @LendanaError::BorrowingSameToken,
         constraint = token_collateral.key() == collateral_token @LendanaError::MismatchCollateralToken,
@@ -56,25 +58,25 @@ pub struct BorrowerPositionInfo<'info> {
     )]
     pub borrower_borrowing_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // Global Registry Whitelisted: Ensure borrowing and Collateral tokens are whitelisted
-    #[account(
-        seeds = [b"all_whitelisted_tokens"],
-        bump = all_whitelisted_tokens.tokens_whitelisted_bump,
-    )]
-    pub all_whitelisted_tokens: Box<Account<'info, AllWhitelistedTokens>>,
-
     // Borrowing Token Escrow Account to track total lent and Borrowed tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_to_borrow.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
         bump = borrowing_token_escrow.token_vault_bump
     )]
     pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
 
+    // Protocol-Wide Pause Switch
+    #[account(
+        seeds = [b"protocol_state"],
+        bump = protocol_state.protocol_state_bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
     // Collateral Token Escrow Account to track total lent and Borrowed tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_collateral.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
         bump = collateral_token_escrow.token_vault_bump
     )]
     pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
@@ -87,6 +89,20 @@ pub struct BorrowerPositionInfo<'info> {
     )]
     pub borrowing_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    // Borrowing Token Treasury, Collecting Protocol Fees
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_treasury.treasury_bump,
+    )]
+    pub borrowing_treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_treasury,
+    )]
+    pub borrowing_treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
      // The Associated Collateral Token Esrow Vault
      #[account(
         mut,
@@ -95,18 +111,30 @@ pub struct BorrowerPositionInfo<'info> {
     )]
     pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // The SOL Collateral Vault PDA
+    // Token Configs Of The Collateral And Borrowing Tokens: Whitelist Status, Price Feeds And Risk Parameters
     #[account(
-        mut,
-        seeds = [b"sol_collateral_vault"],
-        bump = sol_collateral_vault.vault_bump,
+        seeds = [b"token_config", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_config.token_config_bump,
+    )]
+    pub collateral_token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_config.token_config_bump,
     )]
-    pub sol_collateral_vault: Box<Account<'info, SolCollateralVault>>,
+    pub borrowing_token_config: Box<Account<'info, TokenConfig>>,
+
+    // Optional E-Mode Category Holding Both The Collateral And Borrowing Tokens
+    #[account(
+        seeds = [b"emode_category", market.key().as_ref(), emode_category.category_id.to_le_bytes().as_ref()],
+        bump = emode_category.emode_bump,
+    )]
+    pub emode_category: Option<Account<'info, EModeCategory>>,
 
     // Borrower Position ID Counter
     #[account(
         mut,
-        seeds = [b"borrowers_position_id_counter"],
+        seeds = [b"borrowers_position_id_counter", market.key().as_ref()],
         bump = borrowers_position_id_counter.borrower_position_id_bump,
     )]
     pub borrowers_position_id_counter: Box<Account<'info, BorrowerPositionIDCounter>>,
@@ -116,18 +144,20 @@ pub struct BorrowerPositionInfo<'info> {
         init,
         payer = borrower,
         space = 8 + BorrowerPosition::INIT_SPACE,
-        seeds = [b"borrower_position", borrower.key().as_ref(), token_to_borrow.key().as_ref()],
+        seeds = [b"borrower_position", market.key().as_ref(), borrower.key().as_ref(), token_to_borrow.key().as_ref()],
         bump
     )]
     pub borrower_position: Box<Account<'info, BorrowerPosition>>,
 
-    // Get Token Price Feed IDs From The Token Price Registry
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
     #[account(
-        mut,
-        seeds = [b"token_price_registry"],
-        bump = tokens_price_feed_registry.registry_bump,
+        init,
+        payer = borrower,
+        space = 8 + SolCollateralEscrow::INIT_SPACE,
+        seeds = [b"sol_collateral", borrower_position.key().as_ref()],
+        bump
     )]
-    pub tokens_price_feed_registry: Box<Account<'info, TokenPriceFeedRegistry>>,
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
 
     /// CHECK: This is safe as Pyth Program, no data will be read or write to
     #[account(
@@ -145,8 +175,40 @@ pub struct BorrowerPositionInfo<'info> {
 
 impl<'info> BorrowerPositionInfo<'info> {
 
+    // Move The Origination Fee From The Borrowing Vault To The Treasury, Returning What The Treasury Received
+    pub fn transfer_origination_fee_to_treasury(&mut self, origination_fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        if origination_fee == 0 {
+            return Ok(0);
+        }
+
+        let borrowing_token = self.token_to_borrow.key();
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrowing_token_vault.to_account_info(),
+            to: self.borrowing_treasury_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.borrowing_token_escrow.to_account_info(),
+        };
+
+        let market = self.market.key();
+        let seeds = &[
+            b"token_escrow",
+            market.as_ref(),
+            borrowing_token.as_ref(),
+            &[self.borrowing_token_escrow.token_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), origination_fee, self.token_to_borrow.decimals)?;
+
+        // Transfer Fee Mints Deliver Less Than Was Sent
+        amount_received(&self.token_to_borrow.to_account_info(), origination_fee)
+    }
+
     // ASSOCIATED FUNCTION TO LOCK BORROWER'S Collateral
-    pub fn lock_borrower_collateral(&mut self, borrowing_amount: u64) -> Result<u64> {
+    pub fn lock_borrower_collateral(&mut self, borrowing_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
     
         // Get Required collateral
         let required_collateral_amount = self.calculate_collateral_needed(borrowing_amount)?;
@@ -154,16 +216,19 @@ impl<'info> BorrowerPositionInfo<'info> {
         // Collateral Could Be native SOL or an SPL Token, so need to handle either case differently
         match self.token_collateral.key(){
             NATIVE_SOL_MINT_ADDRESS => {
-                // Handle CPI transfers of SOL from borrower to SolCollateralVault pda
-                let cpi_program = self.system_program.to_account_info();
-                let cpi_accounts = Transfer {
-                    from: self.borrower.to_account_info(),
-                    to: self.sol_collateral_vault.to_account_info(),
-                };
-                let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-                transfer(cpi_ctx, required_collateral_amount)?;
+                // Native SOL Is Held In The Position's Own Escrow
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                deposit_sol_collateral(sol_collateral_escrow, self.borrower.to_account_info(), self.system_program.to_account_info(), required_collateral_amount)?;
             },
             _ => {
+                // Collateral Deposits Count Towards The Collateral Token Supply Cap
+                ensure_within_cap(
+                    self.collateral_token_escrow.total_lent_tokens,
+                    required_collateral_amount,
+                    self.collateral_token_config.risk_parameters.supply_cap,
+                    LendanaError::SupplyCapExceeded,
+                )?;
+
                 // Handle CPI transfers of SPL Tokens
                 let cpi_program = self.token_program.to_account_info();
                 let cpi_accounts = TransferChecked {
@@ -174,7 +239,7 @@ impl<'info> BorrowerPositionInfo<'info> {
                 };
 
                 let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-                transfer_checked(cpi_ctx, required_collateral_amount, self.token_collateral.decimals)?;
+                transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_collateral.to_account_info(), required_collateral_amount)?, self.token_collateral.decimals)?;
             }
         }
 
@@ -183,7 +248,7 @@ impl<'info> BorrowerPositionInfo<'info> {
 
 
     // ASSOCIATED FUNCTION TO TRANSFER BORROW TOKENS TO BORROWER 
-    pub fn transfer_tokens_to_borrower(&mut self, borrowing_amount: u64) -> Result<()> {
+    pub fn transfer_tokens_to_borrower(&mut self, borrowing_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
         // Transfer the borrowing amount to the borrower's ATA
 
         let borrowing_token = self.token_to_borrow.key();
@@ -195,77 +260,58 @@ impl<'info> BorrowerPositionInfo<'info> {
             authority: self.borrowing_token_escrow.to_account_info(),
         };
 
+        let market = self.market.key();
         let seeds = &[
             b"token_escrow",
+            market.as_ref(),
             borrowing_token.as_ref(),
             &[self.borrowing_token_escrow.token_vault_bump],
         ];
         let signer_seeds = &[&seeds[..]];
 
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        transfer_checked(cpi_ctx, borrowing_amount, self.token_to_borrow.decimals)?;
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), borrowing_amount, self.token_to_borrow.decimals)?;
+
+        // Borrowed Native SOL Is Handed Over As Lamports
+        unwrap_native_sol(&self.borrower_borrowing_ata, self.borrower.to_account_info(), self.token_program.to_account_info())?;
 
         Ok(())
     }
 
-    // A method to calculate the required collateral amount to collateralize based on borrowing amount and LTV
-    fn calculate_collateral_needed(&mut self, borrowing_amount: u64) -> Result<u64> {
+    // ASSOCIATED FUNCTION TO COUNT THE BORROW AGAINST AN ISOLATED COLLATERAL'S DEBT CEILING
+    pub fn track_isolated_debt(&mut self, borrowing_amount: u64) -> Result<u64> {
 
-        // Get the price of the collateral token in USD
-        let collateral_token = &self.token_collateral.key();
-        let collateral_price_feed_id_hex = match self.tokens_price_feed_registry.token_price_mapping.iter()
-            .find(|m| &m.token_mint == collateral_token)
-            .map(|m| m.price_feed_id.clone()){
-                Some(feed_id) => feed_id,
-                None => return Ok(0),
-            };
-
-        let collateral_feed_id = match get_feed_id_from_hex(&collateral_price_feed_id_hex) {
-            Ok(feed_id) => feed_id,
-            Err(_) => return Ok(0),
-        };
+        // Collateral Not In Isolation Mode Has No Ceiling To Track
+        if !self.collateral_token_escrow.isolation_mode {
+            return Ok(0);
+        }
 
-        let collateral_price = self.collateral_price_update.get_price_no_older_than(
-            &Clock::get()?, MAX_PRICE_FEED_AGE, &collateral_feed_id)?;
-        let actual_collateral_price_value = collateral_price.price
-            .checked_mul(10u64.pow(collateral_price.exponent.try_into().unwrap()).try_into().unwrap());
-
-        // Get the price of the borrowing token in USD
-        let borrowing_token = &self.token_to_borrow.key();
-        let borrowing_price_feed_id_hex = match self.tokens_price_feed_registry.token_price_mapping.iter()
-            .find(|m| &m.token_mint == borrowing_token)
-            .map(|m| m.price_feed_id.clone()){
-                Some(feed_id) => feed_id,
-                None => return Ok(0),
-            };
-        let borrowing_feed_id = match get_feed_id_from_hex(&borrowing_price_feed_id_hex) {
-            Ok(feed_id) => feed_id,
-            Err(_) => return Ok(0),
-        };
-        
-        let borrowing_token_price = self.borrowing_price_update.get_price_no_older_than(
-            &Clock::get()?, MAX_PRICE_FEED_AGE, &borrowing_feed_id)?;
-        let actual_borrowing_price_value = borrowing_token_price.price
-            .checked_mul(10u64.pow(borrowing_token_price.exponent.try_into().unwrap()).try_into().unwrap());
+        let borrowing_price = get_mint_price(&self.borrowing_token_config, &self.borrowing_price_update, &self.token_to_borrow.to_account_info())?;
+        let borrowing_value = token_usd_value(borrowing_amount, self.token_to_borrow.decimals, &borrowing_price)?;
 
+        add_isolated_debt(&mut self.collateral_token_escrow, &self.borrowing_token_config.risk_parameters, borrowing_value)
+    }
 
-        // Get Price of Borrowing Amount, and Calculate Required Collateral
-        let borrowing_amount_price = borrowing_amount.checked_mul(actual_borrowing_price_value.unwrap().try_into().unwrap());
+    // A method to calculate the required collateral amount to collateralize based on borrowing amount and LTV
+    fn calculate_collateral_needed(&mut self, borrowing_amount: u64) -> Result<u64> {
 
-        /* 1. Min Collateral Ratio + Max Interest = Required Collateral */
-        const EXPECTED_COLLATERAL_RATIO_AND_MAX_INTEREST_BPS: u64 = MAX_ALLOWABLE_INTEREST_RATE_BPS + MIN_COLLATERAL_RATIO; // 127% in BPS
+        // Get the USD prices of the collateral and borrowing tokens
+        let collateral_price = get_mint_price(&self.collateral_token_config, &self.collateral_price_update, &self.token_collateral.to_account_info())?;
+        let borrowing_price = get_mint_price(&self.borrowing_token_config, &self.borrowing_price_update, &self.token_to_borrow.to_account_info())?;
 
-        let required_collateral_value = borrowing_amount_price
-            .unwrap()
-            .checked_mul(EXPECTED_COLLATERAL_RATIO_AND_MAX_INTEREST_BPS)
-            .unwrap()
-            .checked_div(10000)
-            .unwrap();
-        
-        let required_collateral_amount = required_collateral_value
-            .checked_div(actual_collateral_price_value.unwrap().try_into().unwrap())
-            .ok_or(LendanaError::GetCollateralError)?;
+        // Value the borrowing amount, and size the collateral to the max LTV of the pair's e-mode category or the collateral token
+        let borrowing_value = token_usd_value(borrowing_amount, self.token_to_borrow.decimals, &borrowing_price)?;
+        let collateral_risk_parameters = effective_risk_parameters(&self.collateral_token_config, Clock::get()?.unix_timestamp)?;
+        let pair_parameters = pair_liquidation_parameters(
+            &collateral_risk_parameters,
+            self.emode_category.as_deref(),
+            &self.token_collateral.key(),
+            &self.token_to_borrow.key(),
+        );
+        let collateral_value = required_collateral_value(borrowing_value, pair_parameters.max_ltv_bps)?;
 
+        let required_collateral_amount = usd_value_to_token_amount(collateral_value, self.token_collateral.decimals, &collateral_price)?;
+        require!(required_collateral_amount > 0, LendanaError::GetCollateralError);
 
         Ok(required_collateral_amount)
     }
@@ -275,6 +321,7 @@ impl<'info> BorrowerPositionInfo<'info> {
 
 // Can Only Modify Position If There Is No Match Yet: Loan Terms Or Topping Up Amount
 // MODIFY BORROWER POSITION   
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ModifyBorrowerPosition<'info> {
 
@@ -284,6 +331,8 @@ pub struct ModifyBorrowerPosition<'info> {
     )]
     pub borrower: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
     #[account(
         constraint = token_to_borrow.key() == borrower_position.borrowing_token @LendanaError::MismatchBorrowToken,
     )]
@@ -307,9 +356,10 @@ pub struct ModifyBorrowerPosition<'info> {
      )]
      pub borrower_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
  
-     // Init if borrower does not have an ATA for the borrowing token
+     // Init if borrower does not have an ATA for the borrowing token, as borrowed native SOL is unwrapped
      #[account(
-        mut,
+        init_if_needed,
+        payer = borrower,
          associated_token::mint = token_to_borrow,
          associated_token::authority = borrower,
      )]
@@ -318,15 +368,22 @@ pub struct ModifyBorrowerPosition<'info> {
     // Borrowing Token Escrow Account to track total lent and Borrowed tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_to_borrow.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
         bump = borrowing_token_escrow.token_vault_bump
     )]
     pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
 
+    // Protocol-Wide Pause Switch
+    #[account(
+        seeds = [b"protocol_state"],
+        bump = protocol_state.protocol_state_bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
     // Collateral Token Escrow Account to track total lent and Borrowed tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_collateral.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
         bump = collateral_token_escrow.token_vault_bump
     )]
     pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
@@ -338,6 +395,20 @@ pub struct ModifyBorrowerPosition<'info> {
     )]
     pub borrowing_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    // Borrowing Token Treasury, Collecting Protocol Fees
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_treasury.treasury_bump,
+    )]
+    pub borrowing_treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_treasury,
+    )]
+    pub borrowing_treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
      // The Associated Collateral Token Esrow Vault
      #[account(
         mut,
@@ -346,18 +417,30 @@ pub struct ModifyBorrowerPosition<'info> {
     )]
     pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // The SOL Collateral Vault PDA
+    // Token Configs Of The Collateral And Borrowing Tokens: Whitelist Status, Price Feeds And Risk Parameters
     #[account(
-        mut,
-        seeds = [b"sol_collateral_vault"],
-        bump = sol_collateral_vault.vault_bump,
+        seeds = [b"token_config", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_config.token_config_bump,
+    )]
+    pub collateral_token_config: Box<Account<'info, TokenConfig>>,
+
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_config.token_config_bump,
     )]
-    pub sol_collateral_vault: Box<Account<'info, SolCollateralVault>>,
+    pub borrowing_token_config: Box<Account<'info, TokenConfig>>,
+
+    // The Position's E-Mode Category, Required Only If It Was Opened In E-Mode
+    #[account(
+        seeds = [b"emode_category", market.key().as_ref(), borrower_position.emode_category.to_le_bytes().as_ref()],
+        bump = emode_category.emode_bump,
+    )]
+    pub emode_category: Option<Account<'info, EModeCategory>>,
 
     // Borrower Position ID Counter
     #[account(
         //mut,
-        seeds = [b"borrowers_position_id_counter"],
+        seeds = [b"borrowers_position_id_counter", market.key().as_ref()],
         bump = borrowers_position_id_counter.borrower_position_id_bump,
     )]
     pub borrowers_position_id_counter: Box<Account<'info, BorrowerPositionIDCounter>>,
@@ -365,18 +448,20 @@ pub struct ModifyBorrowerPosition<'info> {
     // Get Borrower Position
     #[account(
         mut,
-        seeds = [b"borrower_position", borrower.key().as_ref(), token_to_borrow.key().as_ref()],
+        seeds = [b"borrower_position", market.key().as_ref(), borrower.key().as_ref(), token_to_borrow.key().as_ref()],
         bump = borrower_position.borrower_position_bump
     )]
     pub borrower_position: Box<Account<'info, BorrowerPosition>>,
 
-     // Get Token Price Feed IDs From The Token Price Registry
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
     #[account(
         mut,
-        seeds = [b"token_price_registry"],
-        bump = tokens_price_feed_registry.registry_bump,
+        seeds = [b"sol_collateral", borrower_position.key().as_ref()],
+        bump = sol_collateral_escrow.escrow_bump,
     )]
-    pub tokens_price_feed_registry: Box<Account<'info, TokenPriceFeedRegistry>>,
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
+
+     // Get Token Price Feed IDs From The Token Price Registry
 
     /// CHECK: This is safe as Pyth Program, no data will be read or write to
     #[account(
@@ -395,8 +480,40 @@ pub struct ModifyBorrowerPosition<'info> {
 
 impl<'info> ModifyBorrowerPosition<'info> {
 
+    // Move The Origination Fee From The Borrowing Vault To The Treasury, Returning What The Treasury Received
+    pub fn transfer_origination_fee_to_treasury(&mut self, origination_fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        if origination_fee == 0 {
+            return Ok(0);
+        }
+
+        let borrowing_token = self.token_to_borrow.key();
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrowing_token_vault.to_account_info(),
+            to: self.borrowing_treasury_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.borrowing_token_escrow.to_account_info(),
+        };
+
+        let market = self.market.key();
+        let seeds = &[
+            b"token_escrow",
+            market.as_ref(),
+            borrowing_token.as_ref(),
+            &[self.borrowing_token_escrow.token_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), origination_fee, self.token_to_borrow.decimals)?;
+
+        // Transfer Fee Mints Deliver Less Than Was Sent
+        amount_received(&self.token_to_borrow.to_account_info(), origination_fee)
+    }
+
      // ASSOCIATED FUNCTION TO LOCK BORROWER'S Collateral
-     pub fn lock_borrower_collateral(&mut self, borrowing_amount: u64) -> Result<u64> {
+     pub fn lock_borrower_collateral(&mut self, borrowing_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
     
         // Get Required collateral
         let required_collateral_amount = self.calculate_collateral_needed(borrowing_amount)?;
@@ -404,16 +521,19 @@ impl<'info> ModifyBorrowerPosition<'info> {
         // Collateral Could Be native SOL or an SPL Token, so need to handle either case differently
         match self.token_collateral.key(){
             NATIVE_SOL_MINT_ADDRESS => {
-                // Handle CPI transfers of SOL from borrower to SolCollateralVault pda
-                let cpi_program = self.system_program.to_account_info();
-                let cpi_accounts = Transfer {
-                    from: self.borrower.to_account_info(),
-                    to: self.sol_collateral_vault.to_account_info(),
-                };
-                let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-                transfer(cpi_ctx, required_collateral_amount)?;
+                // Native SOL Is Held In The Position's Own Escrow
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                deposit_sol_collateral(sol_collateral_escrow, self.borrower.to_account_info(), self.system_program.to_account_info(), required_collateral_amount)?;
             },
             _ => {
+                // Collateral Deposits Count Towards The Collateral Token Supply Cap
+                ensure_within_cap(
+                    self.collateral_token_escrow.total_lent_tokens,
+                    required_collateral_amount,
+                    self.collateral_token_config.risk_parameters.supply_cap,
+                    LendanaError::SupplyCapExceeded,
+                )?;
+
                 // Handle CPI transfers of SPL Tokens
                 let cpi_program = self.token_program.to_account_info();
                 let cpi_accounts = TransferChecked {
@@ -424,7 +544,7 @@ impl<'info> ModifyBorrowerPosition<'info> {
                 };
 
                 let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-                transfer_checked(cpi_ctx, required_collateral_amount, self.token_collateral.decimals)?;
+                transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_collateral.to_account_info(), required_collateral_amount)?, self.token_collateral.decimals)?;
             }
         }
 
@@ -433,7 +553,7 @@ impl<'info> ModifyBorrowerPosition<'info> {
 
 
     // ASSOCIATED FUNCTION TO TRANSFER BORROW TOKENS TO BORROWER 
-    pub fn transfer_tokens_to_borrower(&mut self, borrowing_amount: u64) -> Result<()> {
+    pub fn transfer_tokens_to_borrower(&mut self, borrowing_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
         // Transfer the borrowing amount to the borrower's ATA
 
         let borrowing_token = self.token_to_borrow.key();
@@ -445,77 +565,58 @@ impl<'info> ModifyBorrowerPosition<'info> {
             authority: self.borrowing_token_escrow.to_account_info(),
         };
 
+        let market = self.market.key();
         let seeds = &[
             b"token_escrow",
+            market.as_ref(),
             borrowing_token.as_ref(),
             &[self.borrowing_token_escrow.token_vault_bump],
         ];
         let signer_seeds = &[&seeds[..]];
 
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        transfer_checked(cpi_ctx, borrowing_amount, self.token_to_borrow.decimals)?;
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), borrowing_amount, self.token_to_borrow.decimals)?;
+
+        // Borrowed Native SOL Is Handed Over As Lamports
+        unwrap_native_sol(&self.borrower_borrowing_ata, self.borrower.to_account_info(), self.token_program.to_account_info())?;
 
         Ok(())
     }
 
-    // A method to calculate the required collateral amount to collateralize based on borrowing amount and LTV
-    fn calculate_collateral_needed(&mut self, borrowing_amount: u64) -> Result<u64> {
+    // ASSOCIATED FUNCTION TO COUNT THE BORROW AGAINST AN ISOLATED COLLATERAL'S DEBT CEILING
+    pub fn track_isolated_debt(&mut self, borrowing_amount: u64) -> Result<u64> {
 
-        // Get the price of the collateral token in USD
-        let collateral_token = &self.token_collateral.key();
-        let collateral_price_feed_id_hex = match self.tokens_price_feed_registry.token_price_mapping.iter()
-            .find(|m| &m.token_mint == collateral_token)
-            .map(|m| m.price_feed_id.clone()){
-                Some(feed_id) => feed_id,
-                None => return Ok(0),
-            };
-
-        let collateral_feed_id = match get_feed_id_from_hex(&collateral_price_feed_id_hex) {
-            Ok(feed_id) => feed_id,
-            Err(_) => return Ok(0),
-        };
+        // Collateral Not In Isolation Mode Has No Ceiling To Track
+        if !self.collateral_token_escrow.isolation_mode {
+            return Ok(0);
+        }
 
-        let collateral_price = self.collateral_price_update.get_price_no_older_than(
-            &Clock::get()?, MAX_PRICE_FEED_AGE, &collateral_feed_id)?;
-        let actual_collateral_price_value = collateral_price.price
-            .checked_mul(10u64.pow(collateral_price.exponent.try_into().unwrap()).try_into().unwrap());
-
-        // Get the price of the borrowing token in USD
-        let borrowing_token = &self.token_to_borrow.key();
-        let borrowing_price_feed_id_hex = match self.tokens_price_feed_registry.token_price_mapping.iter()
-            .find(|m| &m.token_mint == borrowing_token)
-            .map(|m| m.price_feed_id.clone()){
-                Some(feed_id) => feed_id,
-                None => return Ok(0),
-            };
-        let borrowing_feed_id = match get_feed_id_from_hex(&borrowing_price_feed_id_hex) {
-            Ok(feed_id) => feed_id,
-            Err(_) => return Ok(0),
-        };
-        
-        let borrowing_token_price = self.borrowing_price_update.get_price_no_older_than(
-            &Clock::get()?, MAX_PRICE_FEED_AGE, &borrowing_feed_id)?;
-        let actual_borrowing_price_value = borrowing_token_price.price
-            .checked_mul(10u64.pow(borrowing_token_price.exponent.try_into().unwrap()).try_into().unwrap());
+        let borrowing_price = get_mint_price(&self.borrowing_token_config, &self.borrowing_price_update, &self.token_to_borrow.to_account_info())?;
+        let borrowing_value = token_usd_value(borrowing_amount, self.token_to_borrow.decimals, &borrowing_price)?;
 
+        add_isolated_debt(&mut self.collateral_token_escrow, &self.borrowing_token_config.risk_parameters, borrowing_value)
+    }
 
-        // Get Price of Borrowing Amount, and Calculate Required Collateral
-        let borrowing_amount_price = borrowing_amount.checked_mul(actual_borrowing_price_value.unwrap().try_into().unwrap());
+    // A method to calculate the required collateral amount to collateralize based on borrowing amount and LTV
+    fn calculate_collateral_needed(&mut self, borrowing_amount: u64) -> Result<u64> {
 
-        /* 1. Min Collateral Ratio + Max Interest = Required Collateral */
-        const EXPECTED_COLLATERAL_RATIO_AND_MAX_INTEREST_BPS: u64 = MAX_ALLOWABLE_INTEREST_RATE_BPS + MIN_COLLATERAL_RATIO; // 127% in BPS
+        // Get the USD prices of the collateral and borrowing tokens
+        let collateral_price = get_mint_price(&self.collateral_token_config, &self.collateral_price_update, &self.token_collateral.to_account_info())?;
+        let borrowing_price = get_mint_price(&self.borrowing_token_config, &self.borrowing_price_update, &self.token_to_borrow.to_account_info())?;
 
-        let required_collateral_value = borrowing_amount_price
-            .unwrap()
-            .checked_mul(EXPECTED_COLLATERAL_RATIO_AND_MAX_INTEREST_BPS)
-            .unwrap()
-            .checked_div(10000)
-            .unwrap();
-        
-        let required_collateral_amount = required_collateral_value
-            .checked_div(actual_collateral_price_value.unwrap().try_into().unwrap())
-            .ok_or(LendanaError::GetCollateralError)?;
+        // Value the borrowing amount, and size the collateral to the max LTV of the pair's e-mode category or the collateral token
+        let borrowing_value = token_usd_value(borrowing_amount, self.token_to_borrow.decimals, &borrowing_price)?;
+        let collateral_risk_parameters = effective_risk_parameters(&self.collateral_token_config, Clock::get()?.unix_timestamp)?;
+        let pair_parameters = pair_liquidation_parameters(
+            &collateral_risk_parameters,
+            self.emode_category.as_deref(),
+            &self.token_collateral.key(),
+            &self.token_to_borrow.key(),
+        );
+        let collateral_value = required_collateral_value(borrowing_value, pair_parameters.max_ltv_bps)?;
 
+        let required_collateral_amount = usd_value_to_token_amount(collateral_value, self.token_collateral.decimals, &collateral_price)?;
+        require!(required_collateral_amount > 0, LendanaError::GetCollateralError);
 
         Ok(required_collateral_amount)
     }
@@ -524,6 +625,7 @@ impl<'info> ModifyBorrowerPosition<'info> {
 
 
 // CANCEL BORROWING ORDER
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CancelBorrowOrder<'info> {
 
@@ -533,6 +635,8 @@ pub struct CancelBorrowOrder<'info> {
     )]
     pub borrower: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
     #[account(
         constraint = token_to_borrow.key() == borrower_position.borrowing_token @LendanaError::MismatchBorrowToken,
     )]
@@ -556,9 +660,10 @@ pub struct CancelBorrowOrder<'info> {
      )]
      pub borrower_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
  
-     // Init if borrower does not have an ATA for the borrowing token
+     // Init if borrower does not have an ATA for the borrowing token, as borrowed native SOL is unwrapped
      #[account(
-        mut,
+        init_if_needed,
+        payer = borrower,
          associated_token::mint = token_to_borrow,
          associated_token::authority = borrower,
      )]
@@ -567,7 +672,7 @@ pub struct CancelBorrowOrder<'info> {
     // Borrowing Token Escrow Account to track total lent and Borrowed tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_to_borrow.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
         bump = borrowing_token_escrow.token_vault_bump
     )]
     pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
@@ -575,7 +680,7 @@ pub struct CancelBorrowOrder<'info> {
     // Collateral Token Escrow Account to track total lent and Borrowed tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_collateral.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
         bump = collateral_token_escrow.token_vault_bump
     )]
     pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
@@ -595,18 +700,10 @@ pub struct CancelBorrowOrder<'info> {
     )]
     pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // The SOL Collateral Vault PDA
-    #[account(
-        mut,
-        seeds = [b"sol_collateral_vault"],
-        bump = sol_collateral_vault.vault_bump,
-    )]
-    pub sol_collateral_vault: Box<Account<'info, SolCollateralVault>>,
-
     // Borrower Position ID Counter
     #[account(
         //mut,
-        seeds = [b"borrowers_position_id_counter"],
+        seeds = [b"borrowers_position_id_counter", market.key().as_ref()],
         bump = borrowers_position_id_counter.borrower_position_id_bump,
     )]
     pub borrowers_position_id_counter: Box<Account<'info, BorrowerPositionIDCounter>>,
@@ -615,18 +712,21 @@ pub struct CancelBorrowOrder<'info> {
     #[account(
         mut,
         close = borrower,
-        seeds = [b"borrower_position", borrower.key().as_ref(), token_to_borrow.key().as_ref()],
+        seeds = [b"borrower_position", market.key().as_ref(), borrower.key().as_ref(), token_to_borrow.key().as_ref()],
         bump = borrower_position.borrower_position_bump
     )]
     pub borrower_position: Box<Account<'info, BorrowerPosition>>,
 
-     // Get Token Price Feed IDs From The Token Price Registry
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
     #[account(
         mut,
-        seeds = [b"token_price_registry"],
-        bump = tokens_price_feed_registry.registry_bump,
+        close = borrower,
+        seeds = [b"sol_collateral", borrower_position.key().as_ref()],
+        bump = sol_collateral_escrow.escrow_bump,
     )]
-    pub tokens_price_feed_registry: Box<Account<'info, TokenPriceFeedRegistry>>,
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
+
+     // Get Token Price Feed IDs From The Token Price Registry
 
     /// CHECK: This is safe as Pyth Program, no data will be read or write to
     #[account(
@@ -644,11 +744,15 @@ pub struct CancelBorrowOrder<'info> {
 impl<'info> CancelBorrowOrder<'info> {
 
     // Method 1: Refund Borrow Tokens
-    pub fn refund_tokens_to_borrow_vault(&mut self) -> Result<()> {
+    pub fn refund_tokens_to_borrow_vault(&mut self, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
         // Transfer the borrowed Tokens to the Borrow Vault ATA
 
         
         let borrowing_amount = self.borrower_position.borrowing_amount;
+
+        // Native SOL Is Wrapped From The Borrower's Lamports
+        wrap_native_sol(&self.borrower_borrowing_ata, self.borrower.to_account_info(), self.token_program.to_account_info(), self.system_program.to_account_info(), borrowing_amount)?;
+
         let cpi_program = self.token_program.to_account_info();
         let cpi_accounts = TransferChecked {
             from: self.borrower_borrowing_ata.to_account_info(),
@@ -658,35 +762,23 @@ impl<'info> CancelBorrowOrder<'info> {
         };
 
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        transfer_checked(cpi_ctx, borrowing_amount, self.token_to_borrow.decimals)?;
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), borrowing_amount, self.token_to_borrow.decimals)?;
 
         Ok(())
     }
 
 
     // Method 2: Unlock And Send Borrower's Collateral
-    pub fn unlock_borrower_collateral(&mut self) -> Result<()> {
+    pub fn unlock_borrower_collateral(&mut self, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
 
         // Collateral Could Be Native SOL or One of The Whitelisted Tokens
         let collateral_to_unlock = self.borrower_position.collateral_amount;
 
         match self.token_collateral.key(){
             NATIVE_SOL_MINT_ADDRESS => {
-                let cpi_program = self.system_program.to_account_info();
-                let cpi_accounts = Transfer {
-                    from: self.sol_collateral_vault.to_account_info(),
-                    to: self.borrower.to_account_info()
-                };
-
-                let seeds = &[
-                    b"sol_collateral_vault".as_ref(),
-                    &[self.sol_collateral_vault.vault_bump],
-                ];
-                let signer_seeds = &[&seeds[..]];
-
-                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-                
-                transfer(cpi_ctx, collateral_to_unlock)?;
+                // The Escrow Is Closed To The Borrower Afterwards, Returning Its Rent
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                release_sol_collateral(sol_collateral_escrow, self.borrower.to_account_info(), collateral_to_unlock)?;
             },
             // IF COLLATERAL TOKEN IS ANY SPL TOKEN
             _ => {
@@ -699,8 +791,10 @@ impl<'info> CancelBorrowOrder<'info> {
                     authority: self.collateral_token_escrow.to_account_info()
                 };
 
+                let market = self.market.key();
                 let seeds = &[
                     b"token_escrow",
+                    market.as_ref(),
                     collateral_token.as_ref(),
                     &[self.collateral_token_escrow.token_vault_bump]
                 ];
@@ -708,7 +802,7 @@ impl<'info> CancelBorrowOrder<'info> {
 
                 let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
 
-                transfer_checked(cpi_ctx, collateral_to_unlock, self.token_collateral.decimals)?;
+                transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), collateral_to_unlock, self.token_collateral.decimals)?;
             }
         }
         Ok(())
@@ -0,0 +1,185 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as instructions_sysvar};
+
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}};
+
+use crate::{states::accounts::*, utils::*};
+
+
+
+//** FLASH BORROW IDLE LIQUIDITY FROM A TOKEN VAULT */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashBorrowFromVault<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Init if borrower does not have an ATA for the flash borrowed token
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        associated_token::mint = token_mint,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Token Escrow Tracks The Outstanding Flash Loan
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_mint.key().as_ref()],
+        bump = token_escrow.token_vault_bump,
+    )]
+    pub token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // Protocol-Wide Pause Switch
+    #[account(
+        seeds = [b"protocol_state"],
+        bump = protocol_state.protocol_state_bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    // The Associated Token Escrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = token_escrow,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Instructions sysvar, read to find the matching flash repay; address is checked
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FlashBorrowFromVault<'info> {
+
+    // ASSOCIATED FUNCTION TO LEND VAULT TOKENS TO THE FLASH BORROWER
+    pub fn transfer_flash_loan_to_borrower(&mut self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let token_mint = self.token_mint.key();
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.token_vault.to_account_info(),
+            to: self.borrower_token_account.to_account_info(),
+            mint: self.token_mint.to_account_info(),
+            authority: self.token_escrow.to_account_info(),
+        };
+
+        let market = self.market.key();
+        let seeds = &[
+            b"token_escrow",
+            market.as_ref(),
+            token_mint.as_ref(),
+            &[self.token_escrow.token_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount, self.token_mint.decimals)?;
+
+        Ok(())
+    }
+}
+
+
+
+//** REPAY A FLASH LOAN PLUS ITS FEE, LATER IN THE SAME TRANSACTION */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashRepayToVault<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_mint.key().as_ref()],
+        bump = token_escrow.token_vault_bump,
+    )]
+    pub token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = token_escrow,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Token Treasury, Collecting The Reserve Factor's Cut Of The Fee
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_mint.key().as_ref()],
+        bump = treasury.treasury_bump,
+    )]
+    pub treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FlashRepayToVault<'info> {
+
+    // ASSOCIATED FUNCTION TO RETURN THE FLASH LOAN TO THE VAULT
+    pub fn repay_flash_loan_to_vault(&mut self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrower_token_account.to_account_info(),
+            to: self.token_vault.to_account_info(),
+            mint: self.token_mint.to_account_info(),
+            authority: self.borrower.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_mint.to_account_info(), amount)?, self.token_mint.decimals)?;
+
+        Ok(())
+    }
+
+    // Pay The Whole Flash Loan Fee Into The Treasury
+    pub fn pay_fee_to_treasury(&mut self, fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        if fee == 0 {
+            return Ok(());
+        }
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrower_token_account.to_account_info(),
+            to: self.treasury_vault.to_account_info(),
+            mint: self.token_mint.to_account_info(),
+            authority: self.borrower.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_mint.to_account_info(), fee)?, self.token_mint.decimals)?;
+
+        Ok(())
+    }
+}
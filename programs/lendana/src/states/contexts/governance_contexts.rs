@@ -0,0 +1,381 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}
+};
+
+use crate::{states::{accounts::*, errors::*}, utils::*};
+
+
+/*
+The Admin Sets Up Or Retunes Listing Governance Through A Matured Timelock Proposal */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureListingGovernance<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == admin_account.admin_address.key() @ LendanaError::OnlyAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    pub governance_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ListingGovernance::INIT_SPACE,
+        seeds = [b"listing_governance"],
+        bump
+    )]
+    pub listing_governance: Account<'info, ListingGovernance>,
+
+    /// CHECK: Data-Less PDA That Proposes And Makes Listings Once Voted Through; Granted Listing Roles Like Any Other Member
+    #[account(
+        seeds = [b"listing_governance_signer"],
+        bump
+    )]
+    pub governance_signer: UncheckedAccount<'info>,
+
+    // A Matured Timelock Proposal For Exactly This Change, Consumed Here
+    #[account(mut)]
+    pub timelock_proposal: Account<'info, TimelockProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+Anyone Proposes Listing A Token With Its Price Feed And Risk Parameters */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeListing<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        constraint = market.curator.is_none() @ LendanaError::CuratedMarketListing
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [b"listing_governance"],
+        bump = listing_governance.governance_bump
+    )]
+    pub listing_governance: Account<'info, ListingGovernance>,
+
+    pub mint_token: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ListingProposal::INIT_SPACE,
+        seeds = [b"listing_proposal", listing_governance.next_listing_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub listing_proposal: Account<'info, ListingProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+A Governance Token Holder Locks Tokens As Weight For Or Against A Listing, Once Per Listing */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoteOnListing<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"listing_governance"],
+        bump = listing_governance.governance_bump
+    )]
+    pub listing_governance: Account<'info, ListingGovernance>,
+
+    #[account(
+        address = listing_governance.governance_mint @ LendanaError::MismatchedTokenMint
+    )]
+    pub governance_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = governance_mint,
+        token::authority = voter,
+        token::token_program = token_program,
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Locked Vote Weight, Held Until Each Voter Withdraws It
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = governance_mint,
+        associated_token::authority = listing_governance,
+        associated_token::token_program = token_program,
+    )]
+    pub governance_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub listing_proposal: Account<'info, ListingProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + ListingVote::INIT_SPACE,
+        seeds = [b"listing_vote", listing_proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub listing_vote: Account<'info, ListingVote>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> VoteOnListing<'info> {
+    pub fn lock_vote_weight(&self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        let cpi_accounts = TransferChecked {
+            from: self.voter_token_account.to_account_info(),
+            to: self.governance_vault.to_account_info(),
+            mint: self.governance_mint.to_account_info(),
+            authority: self.voter.to_account_info(),
+        };
+
+        let cpi_program = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+
+        transfer_tokens(cpi_program.with_remaining_accounts(extra_accounts.to_vec()), amount, self.governance_mint.decimals)?;
+
+        // Only What Reaches The Vault Counts As Weight
+        amount_received(&self.governance_mint.to_account_info(), amount)
+    }
+}
+
+/*
+A Voter Takes Back Their Locked Tokens Once Voting On The Listing Has Ended */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawListingVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"listing_governance"],
+        bump = listing_governance.governance_bump
+    )]
+    pub listing_governance: Account<'info, ListingGovernance>,
+
+    #[account(
+        address = listing_governance.governance_mint @ LendanaError::MismatchedTokenMint
+    )]
+    pub governance_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = governance_mint,
+        token::authority = voter,
+        token::token_program = token_program,
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = governance_mint,
+        associated_token::authority = listing_governance,
+        associated_token::token_program = token_program,
+    )]
+    pub governance_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub listing_proposal: Account<'info, ListingProposal>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"listing_vote", listing_proposal.key().as_ref(), voter.key().as_ref()],
+        bump = listing_vote.vote_bump
+    )]
+    pub listing_vote: Account<'info, ListingVote>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawListingVote<'info> {
+    pub fn unlock_vote_weight(&self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let cpi_accounts = TransferChecked {
+            from: self.governance_vault.to_account_info(),
+            to: self.voter_token_account.to_account_info(),
+            mint: self.governance_mint.to_account_info(),
+            authority: self.listing_governance.to_account_info(),
+        };
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"listing_governance", &[self.listing_governance.governance_bump]]];
+        let cpi_program = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+        transfer_tokens(cpi_program.with_remaining_accounts(extra_accounts.to_vec()), amount, self.governance_mint.decimals)
+    }
+}
+
+/*
+Anyone Settles A Listing Once Voting Ends; A Passed Listing Is Queued On The Timelock By The Governance Signer,
+And Queued Again If One Of Its Proposals Lapses */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueListing<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"listing_governance"],
+        bump = listing_governance.governance_bump
+    )]
+    pub listing_governance: Account<'info, ListingGovernance>,
+
+    /// CHECK: Governance Signer PDA, Paying For And Proposing The Timelock Proposals
+    #[account(
+        mut,
+        seeds = [b"listing_governance_signer"],
+        bump = listing_governance.signer_bump
+    )]
+    pub governance_signer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub listing_proposal: Account<'info, ListingProposal>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Account<'info, TrustedEntities>,
+
+    #[account(
+        mut,
+        seeds = [b"timelock"],
+        bump = timelock.timelock_bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    /// CHECK: Created By queue_proposal, Which Checks Its Seeds Against The Timelock's Next Proposal Ids
+    #[account(mut)]
+    pub whitelist_proposal: UncheckedAccount<'info>,
+
+    /// CHECK: Created By queue_proposal, Which Checks Its Seeds Against The Timelock's Next Proposal Ids
+    #[account(mut)]
+    pub risk_proposal: UncheckedAccount<'info>,
+
+    /// CHECK: Created By queue_proposal, Which Checks Its Seeds Against The Timelock's Next Proposal Ids
+    #[account(mut)]
+    pub price_feed_proposal: UncheckedAccount<'info>,
+
+    // Only When Re-Queueing: One Of The Listing's Current Proposals, Cancelled Or Expired
+    #[account(
+        constraint = listing_proposal.timelock_proposals.contains(&stale_proposal.key()) @ LendanaError::ProposalActionMismatch
+    )]
+    pub stale_proposal: Option<Account<'info, TimelockProposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+Anyone Executes A Queued Listing Once Its Timelock Proposals Mature, Whitelisting The Token,
+Then Setting Its Risk Parameters And Price Feed As The Governance Signer */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteListing<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        address = listing_proposal.market @ LendanaError::MismatchedMarket
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [b"listing_governance"],
+        bump = listing_governance.governance_bump
+    )]
+    pub listing_governance: Box<Account<'info, ListingGovernance>>,
+
+    /// CHECK: Governance Signer PDA, Holding The Listing Roles And Paying For The Token's Accounts
+    #[account(
+        mut,
+        seeds = [b"listing_governance_signer"],
+        bump = listing_governance.signer_bump
+    )]
+    pub governance_signer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub listing_proposal: Box<Account<'info, ListingProposal>>,
+
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Box<Account<'info, TrustedEntities>>,
+
+    #[account(
+        address = listing_proposal.token_mint @ LendanaError::MismatchedTokenMint
+    )]
+    pub mint_token: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Created Or Updated By whitelist_token, Which Checks Its Seeds
+    #[account(mut)]
+    pub token_config: UncheckedAccount<'info>,
+
+    /// CHECK: Created By whitelist_token, Which Checks Its Seeds
+    #[account(mut)]
+    pub token_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Created By whitelist_token, Which Checks It Is The Escrow's Associated Token Account
+    #[account(mut)]
+    pub token_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Created By whitelist_token, Which Checks Its Seeds
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Created By whitelist_token, Which Checks It Is The Treasury's Associated Token Account
+    #[account(mut)]
+    pub treasury_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Must Be The Listing's Whitelisting Proposal; Consumed By whitelist_token
+    #[account(
+        mut,
+        address = listing_proposal.timelock_proposals[0] @ LendanaError::ProposalActionMismatch
+    )]
+    pub whitelist_proposal: UncheckedAccount<'info>,
+
+    /// CHECK: Must Be The Listing's Risk Parameters Proposal; Consumed By set_risk_config
+    #[account(
+        mut,
+        address = listing_proposal.timelock_proposals[1] @ LendanaError::ProposalActionMismatch
+    )]
+    pub risk_proposal: UncheckedAccount<'info>,
+
+    /// CHECK: Must Be The Listing's Price Feed Proposal; Consumed By add_price
+    #[account(
+        mut,
+        address = listing_proposal.timelock_proposals[2] @ LendanaError::ProposalActionMismatch
+    )]
+    pub price_feed_proposal: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -1,46 +1,51 @@
 use anchor_lang::prelude::*;
 
 use anchor_spl::{associated_token::AssociatedToken, 
-    token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked}
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}
 };
 
-use crate::states::{accounts::*, errors::*};
+use crate::{states::{accounts::*, errors::*}, utils::*};
 
 
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct LenderPositionInfo<'info> {
 
     #[account(mut)]
     pub lender: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
     #[account(
-        constraint = all_whitelisted_tokens.tokens_whitelisted.contains(&token_to_lend.key()) @LendanaError::NotWhitelistedToken,
+        constraint = token_config.is_whitelisted @LendanaError::NotWhitelistedToken,
     )]
     pub token_to_lend: InterfaceAccount<'info, Mint>,
 
+    // Init if lender lends native SOL without holding a wSOL account
     #[account(
-        mut,
+        init_if_needed,
+        payer = lender,
         associated_token::mint = token_to_lend,
         associated_token::authority = lender,
     )]
     pub lender_ata: InterfaceAccount<'info, TokenAccount>,
 
-    // Global Registry Whitelisted: Ensure lending token is whitelisted
-    #[account(
-        seeds = [b"all_whitelisted_tokens"],
-        bump = all_whitelisted_tokens.tokens_whitelisted_bump,
-    )]
-    pub all_whitelisted_tokens: Account<'info, AllWhitelistedTokens>,
-
     // Token Escrow Account to track total lent and borrowed tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_to_lend.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_lend.key().as_ref()],
         bump = token_escrow.token_vault_bump
     )]
     pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
 
+    // Protocol-Wide Pause Switch
+    #[account(
+        seeds = [b"protocol_state"],
+        bump = protocol_state.protocol_state_bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
     // The Associated Token Esrow Vault
     #[account(
         mut,
@@ -49,10 +54,17 @@ pub struct LenderPositionInfo<'info> {
     )]
     pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
+    // Token Risk Config: Enforces The Supply Cap
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_lend.key().as_ref()],
+        bump = token_config.token_config_bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
     // Lender Position ID Counter
     #[account(
         mut,
-        seeds = [b"lenders_position_id_counter"],
+        seeds = [b"lenders_position_id_counter", market.key().as_ref()],
         bump = lenders_position_id_counter.lender_position_id_bump,
     )]
     pub lenders_position_id_counter: Account<'info, LenderPositionIDCounter>,
@@ -62,7 +74,7 @@ pub struct LenderPositionInfo<'info> {
         init,
         payer = lender,
         space = 8 + LenderPosition::INIT_SPACE,
-        seeds = [b"lender_position", lender.key().as_ref(), token_to_lend.key().as_ref()],
+        seeds = [b"lender_position", market.key().as_ref(), lender.key().as_ref(), token_to_lend.key().as_ref()],
         bump
     )]
     pub lender_position: Account<'info, LenderPosition>,
@@ -75,7 +87,10 @@ pub struct LenderPositionInfo<'info> {
 }
 
 impl<'info> LenderPositionInfo<'info> {
-    pub fn deposit_to_token_vault(&mut self, amount: u64) -> Result<()> {
+    pub fn deposit_to_token_vault(&mut self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        // Native SOL Is Wrapped From The Lender's Lamports
+        wrap_native_sol(&self.lender_ata, self.lender.to_account_info(), self.token_program.to_account_info(), self.system_program.to_account_info(), amount)?;
 
         // Let's CPI into the token transfer
         let token_program =self.token_program.to_account_info();
@@ -89,9 +104,10 @@ impl<'info> LenderPositionInfo<'info> {
         
         let cpi_program = CpiContext::new(token_program, cpi_accounts);
 
-        transfer_checked(cpi_program, amount, self.token_to_lend.decimals)?;
+        transfer_tokens(cpi_program.with_remaining_accounts(extra_accounts.to_vec()), amount, self.token_to_lend.decimals)?;
 
-        Ok(())
+        // Transfer Fee Mints Deliver Less Than Was Sent
+        amount_received(&self.token_to_lend.to_account_info(), amount)
     }
 }
 
@@ -99,6 +115,7 @@ impl<'info> LenderPositionInfo<'info> {
 
 
 // ---------- MODIFY LENDER POSITION EITHER VIA CHANGING LOAN TERMS OR TOPPING UP LENDING AMOUNT ----------
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ModifyLenderPosition<'info> {
 
@@ -108,10 +125,14 @@ pub struct ModifyLenderPosition<'info> {
     )]
     pub lender: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
     pub token_to_lend: InterfaceAccount<'info, Mint>,
 
+    // Init if lender lends native SOL without holding a wSOL account
     #[account(
-        mut,
+        init_if_needed,
+        payer = lender,
         associated_token::mint = token_to_lend,
         associated_token::authority = lender,
     )]
@@ -121,11 +142,18 @@ pub struct ModifyLenderPosition<'info> {
     // Token Escrow Account to track total lent tokens
     #[account(
         mut,
-        seeds = [b"token_escrow", token_to_lend.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_lend.key().as_ref()],
         bump = token_escrow.token_vault_bump
     )]
     pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
 
+    // Protocol-Wide Pause Switch
+    #[account(
+        seeds = [b"protocol_state"],
+        bump = protocol_state.protocol_state_bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
     // The Associated Token Esrow Vault
     #[account(
         mut,
@@ -134,10 +162,17 @@ pub struct ModifyLenderPosition<'info> {
     )]
     pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
+    // Token Risk Config: Enforces The Supply Cap
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_lend.key().as_ref()],
+        bump = token_config.token_config_bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
     // Lender Position
     #[account(
         mut,
-        seeds = [b"lender_position", lender.key().as_ref(), token_to_lend.key().as_ref()],
+        seeds = [b"lender_position", market.key().as_ref(), lender.key().as_ref(), token_to_lend.key().as_ref()],
         bump = lender_position.lender_position_bump,
     )]
     pub lender_position: Account<'info, LenderPosition>,
@@ -150,7 +185,10 @@ pub struct ModifyLenderPosition<'info> {
 }
 
 impl<'info> ModifyLenderPosition<'info> {
-    pub fn increase_lending_amount(&mut self, amount: u64) -> Result<()> {
+    pub fn increase_lending_amount(&mut self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        // Native SOL Is Wrapped From The Lender's Lamports
+        wrap_native_sol(&self.lender_ata, self.lender.to_account_info(), self.token_program.to_account_info(), self.system_program.to_account_info(), amount)?;
 
         // Let's CPI into the token transfer
         let token_program =self.token_program.to_account_info();
@@ -164,9 +202,10 @@ impl<'info> ModifyLenderPosition<'info> {
         
         let cpi_program = CpiContext::new(token_program, cpi_accounts);
 
-        transfer_checked(cpi_program, amount, self.token_to_lend.decimals)?;
+        transfer_tokens(cpi_program.with_remaining_accounts(extra_accounts.to_vec()), amount, self.token_to_lend.decimals)?;
 
-        Ok(())
+        // Transfer Fee Mints Deliver Less Than Was Sent
+        amount_received(&self.token_to_lend.to_account_info(), amount)
     }
 }
 
@@ -174,20 +213,25 @@ impl<'info> ModifyLenderPosition<'info> {
 
 
 // ---------- CANCEL LENDING ORDER ----------
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CancelLendingOrder<'info> {
 
     #[account(
         mut,
         constraint = lender.key() == lender_position.lender_pubkey @LendanaError::UnauthorizedLender,
-        constraint = lender_position.is_matched == false @LendanaError::OrderAlreadyMatched,
+        constraint = !lender_position.is_matched @LendanaError::OrderAlreadyMatched,
     )]
     pub lender: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
     pub token_to_lend: InterfaceAccount<'info, Mint>,
 
+    // Init if lender no longer holds an account for the lent token, e.g. native SOL lenders
     #[account(
-        mut,
+        init_if_needed,
+        payer = lender,
         associated_token::mint = token_to_lend,
         associated_token::authority = lender,
     )]
@@ -195,7 +239,7 @@ pub struct CancelLendingOrder<'info> {
 
     #[account(
         mut,
-        seeds = [b"token_escrow", token_to_lend.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_lend.key().as_ref()],
         bump = token_escrow.token_vault_bump
     )]
     pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
@@ -212,7 +256,7 @@ pub struct CancelLendingOrder<'info> {
     #[account(
         mut,
         close = lender,
-        seeds = [b"lender_position", lender.key().as_ref(), token_to_lend.key().as_ref()],
+        seeds = [b"lender_position", market.key().as_ref(), lender.key().as_ref(), token_to_lend.key().as_ref()],
         bump = lender_position.lender_position_bump,
     )]
     pub lender_position: Account<'info, LenderPosition>,
@@ -225,7 +269,7 @@ pub struct CancelLendingOrder<'info> {
 }
 
 impl<'info> CancelLendingOrder<'info> {
-    pub fn refund_tokens_to_lender(&mut self) -> Result<()> {
+    pub fn refund_tokens_to_lender(&mut self, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
 
         // Let's CPI into the token transfer
         let token_program =self.token_program.to_account_info();
@@ -239,8 +283,10 @@ impl<'info> CancelLendingOrder<'info> {
 
         let token_to_refund = self.token_to_lend.key();
 
+        let market = self.market.key();
         let seeds = &[
             b"token_escrow", 
+            market.as_ref(),
             token_to_refund.as_ref(),
             &[self.token_escrow.token_vault_bump]
             ];
@@ -249,7 +295,10 @@ impl<'info> CancelLendingOrder<'info> {
         
         let cpi_program = CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
 
-        transfer_checked(cpi_program, self.lender_position.lending_amount, self.token_to_lend.decimals)?;
+        transfer_tokens(cpi_program.with_remaining_accounts(extra_accounts.to_vec()), self.lender_position.lending_amount, self.token_to_lend.decimals)?;
+
+        // Refunded Native SOL Is Handed Back As Lamports
+        unwrap_native_sol(&self.lender_ata, self.lender.to_account_info(), self.token_program.to_account_info())?;
 
         Ok(())
     }
@@ -262,10 +311,12 @@ pub struct WithdrawLendingInterest<'info> {
     #[account(
         mut,
         constraint = lender.key() == lender_position.lender_pubkey @LendanaError::UnauthorizedLender,
-        constraint = lender_position.is_matched == true @LendanaError::OrderNotMatched,
+        constraint = lender_position.is_matched @LendanaError::OrderNotMatched,
     )]
     pub lender: Signer<'info>,
 
+    pub market: Box<Account<'info, Market>>,
+
     pub token_to_lend: InterfaceAccount<'info, Mint>,
 
     #[account(
@@ -277,7 +328,7 @@ pub struct WithdrawLendingInterest<'info> {
 
     #[account(
         mut,
-        seeds = [b"token_escrow", token_to_lend.key().as_ref()],
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_lend.key().as_ref()],
         bump = token_escrow.token_vault_bump
     )]
     pub token_escrow: Account<'info, LentBorrowedTokenEscrow>,
@@ -294,7 +345,7 @@ pub struct WithdrawLendingInterest<'info> {
     #[account(
         mut,
         close = lender,
-        seeds = [b"lender_position", lender.key().as_ref(), token_to_lend.key().as_ref()],
+        seeds = [b"lender_position", market.key().as_ref(), lender.key().as_ref(), token_to_lend.key().as_ref()],
         bump = lender_position.lender_position_bump,
     )]
     pub lender_position: Account<'info, LenderPosition>,
@@ -0,0 +1,709 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}};
+
+use crate::{states::{accounts::*, errors::*, constants::*}, utils::*};
+
+use pyth_solana_receiver_sdk::{ID as PYTH_PROGRAM_ID, price_update::PriceUpdateV2};
+
+
+
+//** LIQUIDATE AN UNHEALTHY OR EXPIRED BORROWER POSITION */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LiquidateBorrowPosition<'info> {
+    #[account(
+        mut,
+        constraint = is_allowed_liquidator(&trusted_roles, &liquidator.key()) @ LendanaError::LiquidatorNotAllowlisted,
+    )]
+    pub liquidator: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    // Liquidator Allowlist, Open To Anyone While No Liquidator Role Is Granted
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Box<Account<'info, TrustedEntities>>,
+
+    /// CHECK: Only receives leftover collateral and the position rent; address is checked against the position
+    #[account(
+        mut,
+        address = borrower_position.borrower_pubkey @LendanaError::UnauthorizedBorrower,
+    )]
+    pub borrower: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = token_to_borrow.key() == borrower_position.borrowing_token @LendanaError::MismatchBorrowToken,
+    )]
+    pub token_to_borrow: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = token_collateral.key() == borrower_position.collateral_token @LendanaError::MismatchCollateralToken,
+    )]
+    pub token_collateral: Box<InterfaceAccount<'info, Mint>>,
+
+    // Pyth Price Update oracle Account for the Collateral and Borrowing Token
+    pub collateral_price_update: Account<'info, PriceUpdateV2>,
+
+    pub borrowing_price_update: Account<'info, PriceUpdateV2>,
+
+    // Liquidator Repays The Debt From This Account
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = liquidator,
+    )]
+    pub liquidator_borrowing_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Liquidator Receives The Seized Collateral Here
+    #[account(
+        init_if_needed,
+        payer = liquidator,
+        associated_token::mint = token_collateral,
+        associated_token::authority = liquidator,
+    )]
+    pub liquidator_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrower Receives Any Collateral Left After The Seizure
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_escrow.token_vault_bump
+    )]
+    pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // Collateral Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_escrow.token_vault_bump
+    )]
+    pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_token_escrow,
+    )]
+    pub borrowing_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Treasury, Collecting Protocol Fees
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_treasury.treasury_bump,
+    )]
+    pub borrowing_treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_treasury,
+    )]
+    pub borrowing_treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Config: Reserve Factor On Interest
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_config.token_config_bump,
+    )]
+    pub borrowing_token_config: Box<Account<'info, TokenConfig>>,
+
+    // The Associated Collateral Token Esrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = collateral_token_escrow,
+    )]
+    pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Collateral Token Config: Liquidation Threshold And Bonus Outside E-Mode
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_config.token_config_bump,
+    )]
+    pub collateral_token_config: Box<Account<'info, TokenConfig>>,
+
+    // The Position's E-Mode Category, Required Only If It Was Opened In E-Mode
+    #[account(
+        seeds = [b"emode_category", market.key().as_ref(), borrower_position.emode_category.to_le_bytes().as_ref()],
+        bump = emode_category.emode_bump,
+    )]
+    pub emode_category: Option<Account<'info, EModeCategory>>,
+
+    // The Liquidated Borrower Position, Closed To The Borrower
+    #[account(
+        mut,
+        close = borrower,
+        seeds = [b"borrower_position", market.key().as_ref(), borrower.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrower_position.borrower_position_bump
+    )]
+    pub borrower_position: Box<Account<'info, BorrowerPosition>>,
+
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
+    #[account(
+        mut,
+        close = borrower,
+        seeds = [b"sol_collateral", borrower_position.key().as_ref()],
+        bump = sol_collateral_escrow.escrow_bump,
+    )]
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
+
+    /// CHECK: This is safe as Pyth Program, no data will be read or write to
+    #[account(
+        address = PYTH_PROGRAM_ID
+    )]
+    pub pyth_program: UncheckedAccount<'info>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+impl<'info> LiquidateBorrowPosition<'info> {
+
+    // Pay The Reserve Factor Share Of The Interest Into The Treasury
+    pub fn pay_reserve_fee_to_treasury(&mut self, reserve_fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        if reserve_fee == 0 {
+            return Ok(());
+        }
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.liquidator_borrowing_ata.to_account_info(),
+            to: self.borrowing_treasury_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.liquidator.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), reserve_fee)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+
+    // Method 1: Check The Position Can Be Liquidated, Returning The Debt To Repay And The Collateral To Seize
+    pub fn calculate_liquidation(&self) -> Result<(u64, u64)> {
+
+        let borrower_position = &self.borrower_position;
+        let now = Clock::get()?.unix_timestamp;
+
+        // Positions Opened In E-Mode Use The Category's Threshold And Bonus While Both Tokens Remain In It
+        require_position_emode(borrower_position.emode_category, self.emode_category.as_deref())?;
+        let risk_parameters = pair_liquidation_parameters(
+            &self.collateral_token_config.risk_parameters,
+            self.emode_category.as_deref(),
+            &self.token_collateral.key(),
+            &self.token_to_borrow.key(),
+        );
+
+        // Debt Is The Principal Plus Interest Accrued So Far
+        let interest = accrued_interest(borrower_position.borrowing_amount, &borrower_position.borrowing_terms, borrower_position.borrowing_start, now)?;
+        let debt_amount = borrower_position.borrowing_amount.checked_add(interest).ok_or(LendanaError::MathOverflow)?;
+
+        let collateral_price = get_mint_price(&self.collateral_token_config, &self.collateral_price_update, &self.token_collateral.to_account_info())?;
+        let borrowing_price = get_mint_price(&self.borrowing_token_config, &self.borrowing_price_update, &self.token_to_borrow.to_account_info())?;
+
+        let debt_value = token_usd_value(debt_amount, self.token_to_borrow.decimals, &borrowing_price)?;
+        let collateral_value = token_usd_value(borrower_position.collateral_amount, self.token_collateral.decimals, &collateral_price)?;
+
+        // Positions Past Their Loan Duration Are Liquidatable Regardless Of Health
+        let loan_end = borrower_position.borrowing_start
+            .saturating_add(borrower_position.borrowing_terms.lending_duration as i64);
+        require!(
+            now >= loan_end || is_liquidatable(collateral_value, debt_value, risk_parameters.liquidation_threshold_bps)?,
+            LendanaError::PositionNotLiquidatable
+        );
+
+        // Liquidator Seizes The Debt Value Plus The Bonus, Capped At The Locked Collateral
+        let seize_value = apply_bps(debt_value, BPS_DENOMINATOR + risk_parameters.liquidation_bonus_bps)?;
+        let seized_collateral = usd_value_to_token_amount(seize_value, self.token_collateral.decimals, &collateral_price)?
+            .min(borrower_position.collateral_amount);
+
+        Ok((debt_amount, seized_collateral))
+    }
+
+
+    // Method 2: Liquidator Repays The Borrower's Debt Into The Borrowing Vault
+    pub fn repay_debt_to_borrow_vault(&mut self, debt_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.liquidator_borrowing_ata.to_account_info(),
+            to: self.borrowing_token_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.liquidator.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), debt_amount)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+
+
+    // Method 3: Send Seized Collateral To The Liquidator, And What Remains Back To The Borrower
+    pub fn seize_borrower_collateral(&mut self, seized_collateral: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let leftover_collateral = self.borrower_position.collateral_amount
+            .checked_sub(seized_collateral)
+            .ok_or(LendanaError::MathOverflow)?;
+
+        match self.token_collateral.key(){
+            NATIVE_SOL_MINT_ADDRESS => {
+                // The Escrow Is Closed To The Borrower Afterwards, Returning Its Rent
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                release_sol_collateral(sol_collateral_escrow, self.liquidator.to_account_info(), seized_collateral)?;
+                release_sol_collateral(sol_collateral_escrow, self.borrower.to_account_info(), leftover_collateral)?;
+            },
+            // IF COLLATERAL TOKEN IS ANY SPL TOKEN
+            _ => {
+                let collateral_token = self.token_collateral.key();
+                let market = self.market.key();
+                let seeds = &[
+                    b"token_escrow",
+                    market.as_ref(),
+                    collateral_token.as_ref(),
+                    &[self.collateral_token_escrow.token_vault_bump]
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                for (recipient, amount) in [
+                    (self.liquidator_collateral_ata.to_account_info(), seized_collateral),
+                    (self.borrower_collateral_ata.to_account_info(), leftover_collateral),
+                ] {
+                    if amount == 0 {
+                        continue;
+                    }
+                    let cpi_accounts = TransferChecked {
+                        from: self.collateral_token_vault.to_account_info(),
+                        mint: self.token_collateral.to_account_info(),
+                        to: recipient,
+                        authority: self.collateral_token_escrow.to_account_info()
+                    };
+                    let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+                    transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount, self.token_collateral.decimals)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+//** LIQUIDATE PART OR ALL OF AN UNHEALTHY OR EXPIRED MULTI-COLLATERAL OBLIGATION */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LiquidateObligation<'info> {
+    #[account(
+        mut,
+        constraint = is_allowed_liquidator(&trusted_roles, &liquidator.key()) @ LendanaError::LiquidatorNotAllowlisted,
+    )]
+    pub liquidator: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    // Liquidator Allowlist, Open To Anyone While No Liquidator Role Is Granted
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Box<Account<'info, TrustedEntities>>,
+
+    /// CHECK: Only identifies the obligation owner; address is checked against the obligation
+    #[account(
+        address = obligation.borrower_pubkey @LendanaError::UnauthorizedBorrower,
+    )]
+    pub borrower: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = token_to_borrow.key() == obligation.borrowing_token @LendanaError::MismatchBorrowToken,
+    )]
+    pub token_to_borrow: Box<InterfaceAccount<'info, Mint>>,
+
+    // The Deposited Collateral Token The Liquidator Chooses To Seize
+    pub token_collateral: Box<InterfaceAccount<'info, Mint>>,
+
+    pub borrowing_price_update: Account<'info, PriceUpdateV2>,
+
+    // Liquidator Repays The Debt From This Account
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = liquidator,
+    )]
+    pub liquidator_borrowing_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Liquidator Receives The Seized Collateral Here
+    #[account(
+        init_if_needed,
+        payer = liquidator,
+        associated_token::mint = token_collateral,
+        associated_token::authority = liquidator,
+    )]
+    pub liquidator_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_escrow.token_vault_bump
+    )]
+    pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // Collateral Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_escrow.token_vault_bump
+    )]
+    pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_token_escrow,
+    )]
+    pub borrowing_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Treasury, Collecting Protocol Fees
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_treasury.treasury_bump,
+    )]
+    pub borrowing_treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_treasury,
+    )]
+    pub borrowing_treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Config: Reserve Factor On Interest
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_config.token_config_bump,
+    )]
+    pub borrowing_token_config: Box<Account<'info, TokenConfig>>,
+
+    // The Associated Collateral Token Esrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = collateral_token_escrow,
+    )]
+    pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"obligation", market.key().as_ref(), borrower.key().as_ref()],
+        bump = obligation.obligation_bump
+    )]
+    pub obligation: Box<Account<'info, BorrowerObligation>>,
+
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
+    #[account(
+        mut,
+        seeds = [b"sol_collateral", obligation.key().as_ref()],
+        bump = sol_collateral_escrow.escrow_bump,
+    )]
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+impl<'info> LiquidateObligation<'info> {
+
+    // Pay The Reserve Factor Share Of The Interest Into The Treasury
+    pub fn pay_reserve_fee_to_treasury(&mut self, reserve_fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        if reserve_fee == 0 {
+            return Ok(());
+        }
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.liquidator_borrowing_ata.to_account_info(),
+            to: self.borrowing_treasury_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.liquidator.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), reserve_fee)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+
+    // Method 1: Liquidator Repays Part Of The Obligation's Debt Into The Borrowing Vault
+    pub fn repay_debt_to_borrow_vault(&mut self, repay_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.liquidator_borrowing_ata.to_account_info(),
+            to: self.borrowing_token_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.liquidator.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), repay_amount)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+
+
+    // Method 2: Send The Seized Collateral To The Liquidator
+    pub fn transfer_seized_collateral(&mut self, seized_collateral: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        match self.token_collateral.key(){
+            NATIVE_SOL_MINT_ADDRESS => {
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                release_sol_collateral(sol_collateral_escrow, self.liquidator.to_account_info(), seized_collateral)?;
+            },
+            _ => {
+                let collateral_token = self.token_collateral.key();
+                let market = self.market.key();
+                let seeds = &[
+                    b"token_escrow",
+                    market.as_ref(),
+                    collateral_token.as_ref(),
+                    &[self.collateral_token_escrow.token_vault_bump]
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_accounts = TransferChecked {
+                    from: self.collateral_token_vault.to_account_info(),
+                    mint: self.token_collateral.to_account_info(),
+                    to: self.liquidator_collateral_ata.to_account_info(),
+                    authority: self.collateral_token_escrow.to_account_info()
+                };
+                let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+                transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), seized_collateral, self.token_collateral.decimals)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+//** LIQUIDATE ONE BORROW OF AN UNHEALTHY OR EXPIRED MARGIN ACCOUNT */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LiquidateMarginAccount<'info> {
+    #[account(
+        mut,
+        constraint = is_allowed_liquidator(&trusted_roles, &liquidator.key()) @ LendanaError::LiquidatorNotAllowlisted,
+    )]
+    pub liquidator: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    // Liquidator Allowlist, Open To Anyone While No Liquidator Role Is Granted
+    #[account(
+        seeds = [b"trusted_entities"],
+        bump = trusted_roles.trusted_entities_bump,
+    )]
+    pub trusted_roles: Box<Account<'info, TrustedEntities>>,
+
+    /// CHECK: Only identifies the margin account owner; address is checked against the margin account
+    #[account(
+        address = margin_account.borrower_pubkey @LendanaError::UnauthorizedBorrower,
+    )]
+    pub borrower: UncheckedAccount<'info>,
+
+    // The Borrowed Token The Liquidator Chooses To Repay
+    pub token_to_borrow: Box<InterfaceAccount<'info, Mint>>,
+
+    // The Deposited Collateral Token The Liquidator Chooses To Seize
+    pub token_collateral: Box<InterfaceAccount<'info, Mint>>,
+
+    // Liquidator Repays The Debt From This Account
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = liquidator,
+    )]
+    pub liquidator_borrowing_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Liquidator Receives The Seized Collateral Here
+    #[account(
+        init_if_needed,
+        payer = liquidator,
+        associated_token::mint = token_collateral,
+        associated_token::authority = liquidator,
+    )]
+    pub liquidator_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_escrow.token_vault_bump
+    )]
+    pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // Collateral Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_escrow.token_vault_bump
+    )]
+    pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_token_escrow,
+    )]
+    pub borrowing_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Treasury, Collecting Protocol Fees
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_treasury.treasury_bump,
+    )]
+    pub borrowing_treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_treasury,
+    )]
+    pub borrowing_treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Config: Reserve Factor On Interest
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_config.token_config_bump,
+    )]
+    pub borrowing_token_config: Box<Account<'info, TokenConfig>>,
+
+    // The Associated Collateral Token Esrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = collateral_token_escrow,
+    )]
+    pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"margin_account", market.key().as_ref(), borrower.key().as_ref()],
+        bump = margin_account.margin_account_bump
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
+    #[account(
+        mut,
+        seeds = [b"sol_collateral", margin_account.key().as_ref()],
+        bump = sol_collateral_escrow.escrow_bump,
+    )]
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+impl<'info> LiquidateMarginAccount<'info> {
+
+    // Pay The Reserve Factor Share Of The Interest Into The Treasury
+    pub fn pay_reserve_fee_to_treasury(&mut self, reserve_fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        if reserve_fee == 0 {
+            return Ok(());
+        }
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.liquidator_borrowing_ata.to_account_info(),
+            to: self.borrowing_treasury_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.liquidator.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), reserve_fee)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+
+    // Method 1: Liquidator Repays Part Of The Chosen Borrow Into The Borrowing Vault
+    pub fn repay_debt_to_borrow_vault(&mut self, repay_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.liquidator_borrowing_ata.to_account_info(),
+            to: self.borrowing_token_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.liquidator.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), repay_amount)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+
+
+    // Method 2: Send The Seized Collateral To The Liquidator
+    pub fn transfer_seized_collateral(&mut self, seized_collateral: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        match self.token_collateral.key(){
+            NATIVE_SOL_MINT_ADDRESS => {
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                release_sol_collateral(sol_collateral_escrow, self.liquidator.to_account_info(), seized_collateral)?;
+            },
+            _ => {
+                let collateral_token = self.token_collateral.key();
+                let market = self.market.key();
+                let seeds = &[
+                    b"token_escrow",
+                    market.as_ref(),
+                    collateral_token.as_ref(),
+                    &[self.collateral_token_escrow.token_vault_bump]
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_accounts = TransferChecked {
+                    from: self.collateral_token_vault.to_account_info(),
+                    mint: self.token_collateral.to_account_info(),
+                    to: self.liquidator_collateral_ata.to_account_info(),
+                    authority: self.collateral_token_escrow.to_account_info()
+                };
+                let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+                transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), seized_collateral, self.token_collateral.decimals)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{accounts::*, errors::*};
+
+
+/*
+Anyone Opens A Curated Market Under An Unused Id, Posting The Creation Deposit.
+Curated Markets Are Seeded Apart From Admin Markets, So Their Ids Never Collide With Ones The Admin Has Queued */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct CreateCuratedMarket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Market::INIT_SPACE,
+        seeds = [b"curated_market", market_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + LenderPositionIDCounter::INIT_SPACE,
+        seeds = [b"lenders_position_id_counter", market.key().as_ref()],
+        bump,
+    )]
+    pub lenders_position_id_counter: Box<Account<'info, LenderPositionIDCounter>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BorrowerPositionIDCounter::INIT_SPACE,
+        seeds = [b"borrowers_position_id_counter", market.key().as_ref()],
+        bump,
+    )]
+    pub borrowers_position_id_counter: Box<Account<'info, BorrowerPositionIDCounter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+A Market's Curator Hands The Role To A New Address */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMarketCurator<'info> {
+    #[account(
+        constraint = market.curator == Some(curator.key()) @ LendanaError::NotMarketCurator
+    )]
+    pub curator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !market.is_closed @ LendanaError::MarketClosed,
+        seeds = [b"curated_market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.market_bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+}
+
+/*
+A Curated Market's Creator Closes It Once No Token Is Listed, Reclaiming The Creation Deposit.
+The Market Account Stays Behind Marked Closed, So Nobody Can Reopen Its Id And Inherit The Configs,
+Categories And Positions Still Seeded By It */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseCuratedMarket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"curated_market", market.market_id.to_le_bytes().as_ref()],
+        bump = market.market_bump,
+        has_one = creator @ LendanaError::NotMarketCreator,
+        constraint = !market.is_closed @ LendanaError::MarketClosed,
+        constraint = market.listed_tokens == 0 @ LendanaError::MarketHasListedTokens
+    )]
+    pub market: Box<Account<'info, Market>>,
+}
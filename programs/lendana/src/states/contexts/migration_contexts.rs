@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+
+/*
+Anyone Can Bring An Account Up To The Current Layout, Paying Any Extra Rent; The Account's Data Is Kept As It Is */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Checked Against The Migrated Type's Owner And Discriminator By The Handler, Since Older Layouts Do Not Deserialize
+    #[account(mut)]
+    pub migrated_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
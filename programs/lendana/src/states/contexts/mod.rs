@@ -1,8 +1,24 @@
 pub mod admin_contexts;
 pub mod lender_contexts;
 pub mod borrower_contexts;
+pub mod liquidator_contexts;
+pub mod obligation_contexts;
+pub mod margin_contexts;
+pub mod flash_loan_contexts;
+pub mod multisig_contexts;
+pub mod governance_contexts;
+pub mod migration_contexts;
+pub mod market_contexts;
 
 
 pub use admin_contexts::*;
 pub use lender_contexts::*;
-pub use borrower_contexts::*;
\ No newline at end of file
+pub use borrower_contexts::*;
+pub use liquidator_contexts::*;
+pub use obligation_contexts::*;
+pub use margin_contexts::*;
+pub use flash_loan_contexts::*;
+pub use multisig_contexts::*;
+pub use governance_contexts::*;
+pub use migration_contexts::*;
+pub use market_contexts::*;
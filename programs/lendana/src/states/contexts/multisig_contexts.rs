@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::{program::Lendana, states::{accounts::*, errors::*}};
+
+/*
+The Current Admin Sets Up The Multisig; It Only Becomes The Admin Once Its Signer Is Proposed And Accepts */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateAdminMultisig<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == admin_account.admin_address.key() @ LendanaError::OnlyAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = admin_account.admin_bump
+    )]
+    pub admin_account: Account<'info, Administrator>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminMultisig::INIT_SPACE,
+        seeds = [b"admin_multisig"],
+        bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    /// CHECK: Data-Less PDA That Signs For The Multisig; Holds Only Lamports To Pay For What It Creates
+    #[account(
+        seeds = [b"admin_multisig_signer"],
+        bump
+    )]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+The Multisig Changes Its Own Owners Or Threshold, So This Only Runs As An Executed Multisig Transaction */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateAdminMultisig<'info> {
+    #[account(
+        seeds = [b"admin_multisig_signer"],
+        bump = admin_multisig.signer_bump
+    )]
+    pub multisig_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.multisig_bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+}
+
+/*
+An Owner Proposes A Lendana Instruction For The Multisig Signer To Sign */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeMultisigTransaction<'info> {
+    #[account(
+        mut,
+        constraint = admin_multisig.owners.contains(&owner.key()) @ LendanaError::NotMultisigOwner
+    )]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.multisig_bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + MultisigTransaction::INIT_SPACE,
+        seeds = [b"multisig_transaction", admin_multisig.next_transaction_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub multisig_transaction: Box<Account<'info, MultisigTransaction>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+An Owner Approves A Proposed Transaction */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveMultisigTransaction<'info> {
+    #[account(
+        constraint = admin_multisig.owners.contains(&owner.key()) @ LendanaError::NotMultisigOwner
+    )]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.multisig_bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    #[account(mut)]
+    pub multisig_transaction: Box<Account<'info, MultisigTransaction>>,
+}
+
+/*
+Anyone Executes A Transaction That Reached The Threshold; Its Accounts Are Passed As Remaining Accounts */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteMultisigTransaction<'info> {
+    pub executor: Signer<'info>,
+
+    // Read Only Here, So An Executed Owner Change Is Not Overwritten On Exit
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.multisig_bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+
+    /// CHECK: The Multisig Signer PDA, Signing The Inner Instruction Through Its Seeds
+    #[account(
+        mut,
+        seeds = [b"admin_multisig_signer"],
+        bump = admin_multisig.signer_bump
+    )]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub multisig_transaction: Box<Account<'info, MultisigTransaction>>,
+
+    pub lendana_program: Program<'info, Lendana>,
+}
@@ -0,0 +1,518 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}};
+
+use crate::{states::{accounts::*, errors::*, constants::*}, utils::*};
+
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+
+
+//** DEPOSIT COLLATERAL INTO THE BORROWER'S MULTI-COLLATERAL OBLIGATION */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositObligationCollateral<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        constraint = (collateral_token_config.is_whitelisted ||
+        token_collateral.key() == NATIVE_SOL_MINT_ADDRESS) @LendanaError::NotWhitelistedToken,
+    )]
+    pub token_collateral: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Collateral Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_escrow.token_vault_bump
+    )]
+    pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // The Associated Collateral Token Esrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = collateral_token_escrow,
+    )]
+    pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Collateral Token Config: Enforces The Supply Cap
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_config.token_config_bump,
+    )]
+    pub collateral_token_config: Box<Account<'info, TokenConfig>>,
+
+    // Borrower Obligation, Created On First Deposit
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        space = 8 + BorrowerObligation::INIT_SPACE,
+        seeds = [b"obligation", market.key().as_ref(), borrower.key().as_ref()],
+        bump
+    )]
+    pub obligation: Box<Account<'info, BorrowerObligation>>,
+
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        space = 8 + SolCollateralEscrow::INIT_SPACE,
+        seeds = [b"sol_collateral", obligation.key().as_ref()],
+        bump
+    )]
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositObligationCollateral<'info> {
+
+    // ASSOCIATED FUNCTION TO LOCK THE DEPOSITED COLLATERAL
+    pub fn lock_obligation_collateral(&mut self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        // Collateral Could Be native SOL or an SPL Token, so need to handle either case differently
+        match self.token_collateral.key(){
+            NATIVE_SOL_MINT_ADDRESS => {
+                // Native SOL Is Held In The Position's Own Escrow
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                deposit_sol_collateral(sol_collateral_escrow, self.borrower.to_account_info(), self.system_program.to_account_info(), amount)?;
+                Ok(amount)
+            },
+            _ => {
+                let cpi_program = self.token_program.to_account_info();
+                let cpi_accounts = TransferChecked {
+                    from: self.borrower_collateral_ata.to_account_info(),
+                    to: self.collateral_token_vault.to_account_info(),
+                    mint: self.token_collateral.to_account_info(),
+                    authority: self.borrower.to_account_info(),
+                };
+
+                let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+                transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount, self.token_collateral.decimals)?;
+
+                // Transfer Fee Mints Deliver Less Than Was Sent
+                amount_received(&self.token_collateral.to_account_info(), amount)
+            }
+        }
+    }
+}
+
+
+
+//** WITHDRAW COLLATERAL FROM THE OBLIGATION, KEEPING ANY DEBT WITHIN THE BORROW LIMIT */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawObligationCollateral<'info> {
+    #[account(
+        mut,
+        constraint = borrower.key() == obligation.borrower_pubkey @LendanaError::UnauthorizedBorrower,
+    )]
+    pub borrower: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    pub token_collateral: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_collateral_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Collateral Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_collateral.key().as_ref()],
+        bump = collateral_token_escrow.token_vault_bump
+    )]
+    pub collateral_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // The Associated Collateral Token Esrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_collateral,
+        associated_token::authority = collateral_token_escrow,
+    )]
+    pub collateral_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Pyth Price Update, Mint And Token Config Of The Debt Token, Required While The Obligation Has Debt
+    pub borrowing_price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    pub token_to_borrow: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), obligation.borrowing_token.as_ref()],
+        bump = borrowing_token_config.token_config_bump,
+    )]
+    pub borrowing_token_config: Option<Box<Account<'info, TokenConfig>>>,
+
+    #[account(
+        mut,
+        seeds = [b"obligation", market.key().as_ref(), borrower.key().as_ref()],
+        bump = obligation.obligation_bump
+    )]
+    pub obligation: Box<Account<'info, BorrowerObligation>>,
+
+    // The Position's Own SOL Collateral Escrow, Required Only For Native SOL Collateral
+    #[account(
+        mut,
+        seeds = [b"sol_collateral", obligation.key().as_ref()],
+        bump = sol_collateral_escrow.escrow_bump,
+    )]
+    pub sol_collateral_escrow: Option<Box<Account<'info, SolCollateralEscrow>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawObligationCollateral<'info> {
+
+    // ASSOCIATED FUNCTION TO RELEASE WITHDRAWN COLLATERAL TO THE BORROWER
+    pub fn unlock_obligation_collateral(&mut self, amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        match self.token_collateral.key(){
+            NATIVE_SOL_MINT_ADDRESS => {
+                let sol_collateral_escrow = required_sol_escrow(&mut self.sol_collateral_escrow)?;
+                release_sol_collateral(sol_collateral_escrow, self.borrower.to_account_info(), amount)?;
+            },
+            _ => {
+                let collateral_token = self.token_collateral.key();
+                let cpi_program = self.token_program.to_account_info();
+                let cpi_accounts = TransferChecked {
+                    from: self.collateral_token_vault.to_account_info(),
+                    mint: self.token_collateral.to_account_info(),
+                    to: self.borrower_collateral_ata.to_account_info(),
+                    authority: self.collateral_token_escrow.to_account_info()
+                };
+
+                let market = self.market.key();
+                let seeds = &[
+                    b"token_escrow",
+                    market.as_ref(),
+                    collateral_token.as_ref(),
+                    &[self.collateral_token_escrow.token_vault_bump]
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount, self.token_collateral.decimals)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+//** BORROW AGAINST THE WHOLE COLLATERAL BASKET OF THE OBLIGATION */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BorrowAgainstObligation<'info> {
+    #[account(
+        mut,
+        constraint = borrower.key() == obligation.borrower_pubkey @LendanaError::UnauthorizedBorrower,
+    )]
+    pub borrower: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        constraint = borrowing_token_config.is_whitelisted @LendanaError::NotWhitelistedToken,
+    )]
+    pub token_to_borrow: Box<InterfaceAccount<'info, Mint>>,
+
+    pub borrowing_price_update: Account<'info, PriceUpdateV2>,
+
+    // Init if borrower does not have an ATA for the borrowing token
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_borrowing_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_escrow.token_vault_bump
+    )]
+    pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // Protocol-Wide Pause Switch
+    #[account(
+        seeds = [b"protocol_state"],
+        bump = protocol_state.protocol_state_bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    // The Associated Borrowing Token Esrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_token_escrow,
+    )]
+    pub borrowing_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Treasury, Collecting Protocol Fees
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_treasury.treasury_bump,
+    )]
+    pub borrowing_treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_treasury,
+    )]
+    pub borrowing_treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Config: Borrow Cap And Isolation Eligibility
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_config.token_config_bump,
+    )]
+    pub borrowing_token_config: Box<Account<'info, TokenConfig>>,
+
+    // Escrow Of The Obligation's Isolated Collateral, Required Only If It Holds One
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), isolated_collateral_escrow.lending_borrowing_token.as_ref()],
+        bump = isolated_collateral_escrow.token_vault_bump
+    )]
+    pub isolated_collateral_escrow: Option<Box<Account<'info, LentBorrowedTokenEscrow>>>,
+
+    #[account(
+        mut,
+        seeds = [b"obligation", market.key().as_ref(), borrower.key().as_ref()],
+        bump = obligation.obligation_bump
+    )]
+    pub obligation: Box<Account<'info, BorrowerObligation>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BorrowAgainstObligation<'info> {
+
+    // Move The Origination Fee From The Borrowing Vault To The Treasury, Returning What The Treasury Received
+    pub fn transfer_origination_fee_to_treasury(&mut self, origination_fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<u64> {
+
+        if origination_fee == 0 {
+            return Ok(0);
+        }
+
+        let borrowing_token = self.token_to_borrow.key();
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrowing_token_vault.to_account_info(),
+            to: self.borrowing_treasury_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.borrowing_token_escrow.to_account_info(),
+        };
+
+        let market = self.market.key();
+        let seeds = &[
+            b"token_escrow",
+            market.as_ref(),
+            borrowing_token.as_ref(),
+            &[self.borrowing_token_escrow.token_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), origination_fee, self.token_to_borrow.decimals)?;
+
+        // Transfer Fee Mints Deliver Less Than Was Sent
+        amount_received(&self.token_to_borrow.to_account_info(), origination_fee)
+    }
+
+    // ASSOCIATED FUNCTION TO TRANSFER BORROW TOKENS TO BORROWER
+    pub fn transfer_tokens_to_borrower(&mut self, borrowing_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let borrowing_token = self.token_to_borrow.key();
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrowing_token_vault.to_account_info(),
+            to: self.borrower_borrowing_ata.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.borrowing_token_escrow.to_account_info(),
+        };
+
+        let market = self.market.key();
+        let seeds = &[
+            b"token_escrow",
+            market.as_ref(),
+            borrowing_token.as_ref(),
+            &[self.borrowing_token_escrow.token_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), borrowing_amount, self.token_to_borrow.decimals)?;
+
+        // Borrowed Native SOL Is Handed Over As Lamports
+        unwrap_native_sol(&self.borrower_borrowing_ata, self.borrower.to_account_info(), self.token_program.to_account_info())?;
+
+        Ok(())
+    }
+}
+
+
+
+//** REPAY THE OBLIGATION'S DEBT, INTEREST FIRST */
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RepayObligationDebt<'info> {
+    #[account(
+        mut,
+        constraint = borrower.key() == obligation.borrower_pubkey @LendanaError::UnauthorizedBorrower,
+    )]
+    pub borrower: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        constraint = token_to_borrow.key() == obligation.borrowing_token @LendanaError::MismatchBorrowToken,
+    )]
+    pub token_to_borrow: Box<InterfaceAccount<'info, Mint>>,
+
+    // Init if borrower repays native SOL without holding a wSOL account
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_borrowing_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Escrow Account to track total lent and Borrowed tokens
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_escrow.token_vault_bump
+    )]
+    pub borrowing_token_escrow: Box<Account<'info, LentBorrowedTokenEscrow>>,
+
+    // The Associated Borrowing Token Esrow Vault
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_token_escrow,
+    )]
+    pub borrowing_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Treasury, Collecting Protocol Fees
+    #[account(
+        seeds = [b"treasury", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_treasury.treasury_bump,
+    )]
+    pub borrowing_treasury: Box<Account<'info, TokenTreasury>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_to_borrow,
+        associated_token::authority = borrowing_treasury,
+    )]
+    pub borrowing_treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Borrowing Token Config: Reserve Factor On Interest
+    #[account(
+        seeds = [b"token_config", market.key().as_ref(), token_to_borrow.key().as_ref()],
+        bump = borrowing_token_config.token_config_bump,
+    )]
+    pub borrowing_token_config: Box<Account<'info, TokenConfig>>,
+
+    // Escrow Of The Obligation's Isolated Collateral, Required Only If It Holds One
+    #[account(
+        mut,
+        seeds = [b"token_escrow", market.key().as_ref(), isolated_collateral_escrow.lending_borrowing_token.as_ref()],
+        bump = isolated_collateral_escrow.token_vault_bump
+    )]
+    pub isolated_collateral_escrow: Option<Box<Account<'info, LentBorrowedTokenEscrow>>>,
+
+    #[account(
+        mut,
+        seeds = [b"obligation", market.key().as_ref(), borrower.key().as_ref()],
+        bump = obligation.obligation_bump
+    )]
+    pub obligation: Box<Account<'info, BorrowerObligation>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RepayObligationDebt<'info> {
+
+    // Wrap Native SOL From The Borrower's Lamports To Cover The Repayment
+    pub fn wrap_repayment(&mut self, repay_amount: u64) -> Result<()> {
+        wrap_native_sol(&self.borrower_borrowing_ata, self.borrower.to_account_info(), self.token_program.to_account_info(), self.system_program.to_account_info(), repay_amount)
+    }
+
+    // Pay The Reserve Factor Share Of The Interest Into The Treasury
+    pub fn pay_reserve_fee_to_treasury(&mut self, reserve_fee: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        if reserve_fee == 0 {
+            return Ok(());
+        }
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrower_borrowing_ata.to_account_info(),
+            to: self.borrowing_treasury_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.borrower.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), reserve_fee)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+
+    // ASSOCIATED FUNCTION TO RETURN BORROWED TOKENS TO THE BORROWING VAULT
+    pub fn repay_tokens_to_borrow_vault(&mut self, repay_amount: u64, extra_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.borrower_borrowing_ata.to_account_info(),
+            to: self.borrowing_token_vault.to_account_info(),
+            mint: self.token_to_borrow.to_account_info(),
+            authority: self.borrower.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer_tokens(cpi_ctx.with_remaining_accounts(extra_accounts.to_vec()), amount_to_send(&self.token_to_borrow.to_account_info(), repay_amount)?, self.token_to_borrow.decimals)?;
+
+        Ok(())
+    }
+}
@@ -57,12 +57,276 @@ pub enum LendanaError {
     #[msg("Token Price Feeds Can Only Be Updated By Authorized Role")]
     UnauthorizedPriceUpdater,
 
-    #[msg("Token Already Has A Price Feed In Registry")]
+    #[msg("Token Already Has A Price Feed")]
     TokenPriceAlreadyExists,
 
-    #[msg("Token Price Feed ID Not Found In Registry")]
+    #[msg("Token Has No Price Feed ID")]
     PriceFeedIDNotFound,
 
     #[msg("Getting Collateral Required For Borrowing Fails")]
     GetCollateralError,
-}
\ No newline at end of file
+
+    #[msg("Only Callable By Risk Manager")]
+    OnlyRiskManager,
+
+    #[msg("Risk Parameters Are Out Of Bounds")]
+    InvalidRiskParameters,
+
+    #[msg("Token Config Does Not Match Token Mint")]
+    MismatchedRiskConfig,
+
+    #[msg("Lending Would Exceed Token Supply Cap")]
+    SupplyCapExceeded,
+
+    #[msg("Borrowing Would Exceed Token Borrow Cap")]
+    BorrowCapExceeded,
+
+    #[msg("Oracle Price Is Invalid")]
+    InvalidOraclePrice,
+
+    #[msg("Arithmetic Overflow In Risk Calculation")]
+    MathOverflow,
+
+    #[msg("Borrower Position Is Healthy And Cannot Be Liquidated")]
+    PositionNotLiquidatable,
+
+    #[msg("Token Cannot Be Borrowed Against Isolated Collateral")]
+    NotBorrowableInIsolation,
+
+    #[msg("Borrowing Would Exceed Isolated Collateral Debt Ceiling")]
+    IsolationDebtCeilingExceeded,
+
+    #[msg("E-Mode Category ID Must Be Non-Zero")]
+    InvalidEModeCategory,
+
+    #[msg("Too Many Tokens In E-Mode Category")]
+    TooManyEModeTokens,
+
+    #[msg("E-Mode Category Name Is Too Long")]
+    EModeNameTooLong,
+
+    #[msg("Collateral And Borrowing Tokens Are Not Both In The E-Mode Category")]
+    NotInEModeCategory,
+
+    #[msg("E-Mode Category Does Not Match Borrower Position")]
+    EModeCategoryMismatch,
+
+    #[msg("Account Already Holds The Maximum Number Of Collateral Tokens")]
+    TooManyCollateralDeposits,
+
+    #[msg("Isolated Collateral Must Be The Only Collateral In An Obligation")]
+    IsolatedCollateralNotSole,
+
+    #[msg("Collateral Token Not Deposited In Account")]
+    CollateralNotDeposited,
+
+    #[msg("Withdrawal Exceeds Deposited Collateral")]
+    InsufficientCollateral,
+
+    #[msg("Remaining Accounts Do Not Match The Collateral Deposits And Borrows")]
+    InvalidHealthAccounts,
+
+    #[msg("Obligation Would Exceed Its Borrow Limit")]
+    ObligationBorrowLimitExceeded,
+
+    #[msg("Obligation Already Has Debt In Another Token")]
+    ObligationDebtTokenMismatch,
+
+    #[msg("Obligation Has No Outstanding Debt")]
+    NoOutstandingDebt,
+
+    #[msg("Borrowing Price Update And Mint Are Required While Obligation Has Debt")]
+    MissingBorrowingPriceUpdate,
+
+    #[msg("Isolated Collateral Escrow Is Required For This Obligation")]
+    MissingIsolatedCollateralEscrow,
+
+    #[msg("Margin Account Already Has The Maximum Number Of Borrowed Tokens")]
+    TooManyMarginBorrows,
+
+    #[msg("Margin Account Has No Debt In This Token")]
+    MarginDebtNotFound,
+
+    #[msg("Margin Account Would Exceed Its Borrow Limit")]
+    MarginBorrowLimitExceeded,
+
+    #[msg("Isolated Collateral Cannot Be Cross-Margined")]
+    IsolatedCollateralNotMarginable,
+
+    #[msg("Margin Account Cannot Borrow A Token It Holds As Collateral")]
+    MarginTokenConflict,
+
+    #[msg("Withdrawal Exceeds The Fees Collected In The Treasury")]
+    InsufficientTreasuryFees,
+
+    #[msg("A Flash Loan Is Already Outstanding For This Token")]
+    FlashLoanActive,
+
+    #[msg("No Flash Loan Is Outstanding For This Token")]
+    NoActiveFlashLoan,
+
+    #[msg("Flash Borrow Must Be Followed By A Matching Flash Repay In The Same Transaction")]
+    MissingFlashRepay,
+
+    #[msg("Flash Borrow Must Be Called Directly, Not Through CPI")]
+    FlashLoanCpiNotAllowed,
+
+    #[msg("Token Mint Has An Unsupported Token-2022 Extension")]
+    UnsupportedMintExtension,
+
+    #[msg("Native SOL Collateral Requires The Position's SOL Collateral Escrow")]
+    MissingSolCollateralEscrow,
+
+    #[msg("SOL Collateral Escrow Holds Fewer Lamports Than Its Recorded Collateral")]
+    SolCollateralOutOfSync,
+
+    #[msg("No Admin Transfer Is Pending")]
+    NoPendingAdmin,
+
+    #[msg("Only The Pending Admin Can Accept The Admin Role")]
+    NotPendingAdmin,
+
+    #[msg("Program Data Account Does Not Belong To This Program")]
+    InvalidProgramData,
+
+    #[msg("Only The Program's Upgrade Authority Can Bootstrap The Protocol")]
+    NotUpgradeAuthority,
+
+    #[msg("Only Callable By Treasurer")]
+    OnlyTreasurer,
+
+    #[msg("Liquidator Is Not On The Liquidator Allowlist")]
+    LiquidatorNotAllowlisted,
+
+    #[msg("Address Already Holds This Role")]
+    RoleAlreadyGranted,
+
+    #[msg("Address Does Not Hold This Role")]
+    RoleNotGranted,
+
+    #[msg("Trusted Roles Registry Is Full")]
+    TooManyRoleAssignments,
+
+    #[msg("Only Callable By Pauser")]
+    OnlyPauser,
+
+    #[msg("Protocol Is Paused")]
+    ProtocolPaused,
+
+    #[msg("Token Is Paused")]
+    TokenPaused,
+
+    #[msg("Token Is Being Delisted")]
+    TokenDelisted,
+
+    #[msg("Token Is Not Being Delisted")]
+    TokenNotDelisted,
+
+    #[msg("Token Still Has Lent, Borrowed, Flash Loaned Or Treasury Balances")]
+    DelistingNotComplete,
+
+    #[msg("LTV Wind-Down Target Must Be Above Zero And Not Above The Current Max LTV")]
+    InvalidLtvWindDown,
+
+    #[msg("Price Feed ID Is Not A Valid 32-Byte Hex String")]
+    InvalidPriceFeedId,
+
+    #[msg("Timelock Delay Is Above The Maximum")]
+    InvalidTimelockDelay,
+
+    #[msg("Signer Is Not Allowed To Propose This Change")]
+    NotAllowedToPropose,
+
+    #[msg("Timelock Proposal Is Not Queued")]
+    ProposalNotQueued,
+
+    #[msg("Timelock Proposal Has Not Matured Yet")]
+    ProposalNotMatured,
+
+    #[msg("Timelock Proposal Has Expired")]
+    ProposalExpired,
+
+    #[msg("Timelock Proposal Does Not Match This Change")]
+    ProposalActionMismatch,
+
+    #[msg("Only The Proposer Or The Admin Can Cancel A Proposal")]
+    OnlyProposerOrAdmin,
+
+    #[msg("Multisig Owners Must Be Unique And At Most The Maximum")]
+    InvalidMultisigOwners,
+
+    #[msg("Multisig Threshold Must Be Between One And The Number Of Owners")]
+    InvalidMultisigThreshold,
+
+    #[msg("Signer Is Not A Multisig Owner")]
+    NotMultisigOwner,
+
+    #[msg("Owner Has Already Approved This Transaction")]
+    AlreadyApproved,
+
+    #[msg("Not Enough Owners Have Approved This Transaction")]
+    MultisigThresholdNotMet,
+
+    #[msg("Multisig Transaction Has Already Been Executed")]
+    MultisigTransactionExecuted,
+
+    #[msg("Multisig Owners Changed Since This Transaction Was Proposed")]
+    StaleMultisigTransaction,
+
+    #[msg("Multisig Transaction Has Too Many Accounts Or Too Much Data")]
+    MultisigTransactionTooLarge,
+
+    #[msg("Listing Governance Needs A Quorum And A Voting Period Within Bounds, And Keeps Its Mint")]
+    InvalidListingGovernance,
+
+    #[msg("Voting On This Listing Has Closed")]
+    ListingVotingClosed,
+
+    #[msg("Voting On This Listing Is Still Open")]
+    ListingVotingOpen,
+
+    #[msg("Listing Proposal Is Not In The Expected Status")]
+    InvalidListingStatus,
+
+    #[msg("Vote Weight Must Be Above Zero")]
+    InvalidVoteWeight,
+
+    #[msg("Account Is Not A Lendana Account Of This Type, Or Is Larger Than Its Current Layout")]
+    InvalidMigrationAccount,
+
+    #[msg("Account Is Already On The Current Layout Version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Account Belongs To A Different Market")]
+    MismatchedMarket,
+
+    #[msg("Market Name Must Be At Most 32 Bytes")]
+    InvalidMarketName,
+
+    #[msg("Lending Durations Must Be Distinct And Supported By The Program")]
+    InvalidLendingDurations,
+
+    #[msg("Only The Market's Curator Can Do This")]
+    NotMarketCurator,
+
+    #[msg("Curated Markets List Tokens Through Their Curator, Not Listing Governance")]
+    CuratedMarketListing,
+
+    #[msg("Borrows Added To Existing Debt Must Use Its Loan Terms")]
+    LoanTermsMismatch,
+
+    #[msg("Proposals Queued By Listing Governance Are Executed Only Through Execute Listing")]
+    GovernanceQueuedProposal,
+
+    #[msg("The Listing's Timelock Proposals Can Still Be Executed")]
+    ListingProposalsLive,
+
+    #[msg("Only The Market's Creator Can Do This")]
+    NotMarketCreator,
+
+    #[msg("The Market Still Has Listed Tokens")]
+    MarketHasListedTokens,
+
+    #[msg("The Market Is Closed")]
+    MarketClosed,
+}
@@ -0,0 +1,842 @@
+use anchor_lang::prelude::*;
+
+use pyth_solana_receiver_sdk::price_update::Price;
+
+use crate::states::accounts::*;
+
+
+
+/* A PYTH PRICE AS READ BY AN INSTRUCTION */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct OraclePrice {
+    pub price: i64,
+
+    pub conf: u64,
+
+    pub exponent: i32,
+
+    pub publish_time: i64,
+}
+
+impl From<Price> for OraclePrice {
+    fn from(price: Price) -> Self {
+        OraclePrice {
+            price: price.price,
+            conf: price.conf,
+            exponent: price.exponent,
+            publish_time: price.publish_time,
+        }
+    }
+}
+
+
+
+//** ADMIN EVENTS */
+
+#[event]
+pub struct ProtocolBootstrapped {
+    pub admin: Pubkey,
+
+    pub upgrade_authority: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub admin: Pubkey,
+
+    pub pending_admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub admin: Pubkey,
+
+    pub cancelled_admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+
+    pub new_admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+
+    pub member: Pubkey,
+
+    pub admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+
+    pub member: Pubkey,
+
+    pub admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolPauseUpdated {
+    pub pauser: Pubkey,
+
+    pub paused: bool,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenPauseUpdated {
+    pub pauser: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub paused: bool,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenWhitelisted {
+    pub token_mint: Pubkey,
+
+    pub whitelister: Pubkey,
+
+    pub isolation_debt_ceiling: Option<u64>,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenDelistingStarted {
+    pub token_mint: Pubkey,
+
+    pub whitelister: Pubkey,
+
+    pub ltv_wind_down: Option<LtvWindDown>,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenDelistingFinalized {
+    pub token_mint: Pubkey,
+
+    pub whitelister: Pubkey,
+
+    // Left In The Vaults And Swept To The Market's Curator, Or The Admin For Admin Markets
+    pub swept_amount: u64,
+
+    pub swept_to: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TimelockProposalQueued {
+    pub proposal_id: u64,
+
+    pub proposer: Pubkey,
+
+    pub action: GovernanceAction,
+
+    pub eta: i64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TimelockProposalCancelled {
+    pub proposal_id: u64,
+
+    pub canceller: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TimelockDelayUpdated {
+    pub previous_delay: i64,
+
+    pub delay: i64,
+
+    pub admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceFeedMapped {
+    pub token_mint: Pubkey,
+
+    pub price_feed_id: [u8; 32],
+
+    pub oracle_manager: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceFeedUpdated {
+    pub token_mint: Pubkey,
+
+    pub previous_price_feed_id: [u8; 32],
+
+    pub price_feed_id: [u8; 32],
+
+    pub oracle_manager: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceFeedRemoved {
+    pub token_mint: Pubkey,
+
+    pub previous_price_feed_id: [u8; 32],
+
+    pub oracle_manager: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RiskConfigUpdated {
+    pub token_mint: Pubkey,
+
+    pub risk_parameters: RiskParameters,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IsolationModeUpdated {
+    pub token_mint: Pubkey,
+
+    pub isolation_debt_ceiling: Option<u64>,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub token_mint: Pubkey,
+
+    pub recipient: Pubkey,
+
+    pub amount: u64,
+
+    pub remaining_fees: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EModeCategoryUpdated {
+    pub category_id: u8,
+
+    pub name: String,
+
+    pub emode_parameters: EModeParameters,
+
+    pub token_mints: Vec<Pubkey>,
+
+    pub timestamp: i64,
+}
+
+
+
+//** LENDER EVENTS */
+
+#[event]
+pub struct LendOrderCreated {
+    pub lender: Pubkey,
+
+    pub lender_position_id: u64,
+
+    pub lending_token: Pubkey,
+
+    pub lending_amount: u64,
+
+    pub lending_terms: LoanTerms,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LendOrderModified {
+    pub lender: Pubkey,
+
+    pub lender_position_id: u64,
+
+    pub added_amount: u64,
+
+    pub lending_amount: u64,
+
+    pub lending_terms: LoanTerms,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LendOrderCancelled {
+    pub lender: Pubkey,
+
+    pub lender_position_id: u64,
+
+    pub lending_token: Pubkey,
+
+    pub refunded_amount: u64,
+
+    pub timestamp: i64,
+}
+
+
+
+//** BORROWER EVENTS */
+
+#[event]
+pub struct BorrowOrderCreated {
+    pub borrower: Pubkey,
+
+    pub borrower_position_id: u64,
+
+    pub collateral_token: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub collateral_amount: u64,
+
+    pub borrowing_amount: u64,
+
+    pub borrowing_terms: LoanTerms,
+
+    pub emode_category: u8,
+
+    pub collateral_price: OraclePrice,
+
+    pub borrowing_price: OraclePrice,
+
+    pub origination_fee: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BorrowOrderModified {
+    pub borrower: Pubkey,
+
+    pub borrower_position_id: u64,
+
+    pub additional_borrow_amount: u64,
+
+    pub additional_collateral_amount: u64,
+
+    pub borrowing_amount: u64,
+
+    pub collateral_amount: u64,
+
+    pub borrowing_terms: LoanTerms,
+
+    // Prices Are Only Read When Borrowing More
+    pub collateral_price: Option<OraclePrice>,
+
+    pub borrowing_price: Option<OraclePrice>,
+
+    pub origination_fee: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BorrowOrderCancelled {
+    pub borrower: Pubkey,
+
+    pub borrower_position_id: u64,
+
+    pub returned_borrow_amount: u64,
+
+    pub refunded_collateral_amount: u64,
+
+    pub timestamp: i64,
+}
+
+
+
+//** LIQUIDATION EVENTS */
+
+#[event]
+pub struct PositionLiquidated {
+    pub liquidator: Pubkey,
+
+    pub borrower: Pubkey,
+
+    pub borrower_position_id: u64,
+
+    pub collateral_token: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub debt_repaid: u64,
+
+    pub collateral_seized: u64,
+
+    pub collateral_price: OraclePrice,
+
+    pub borrowing_price: OraclePrice,
+
+    pub reserve_fee: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ObligationLiquidated {
+    pub liquidator: Pubkey,
+
+    pub borrower: Pubkey,
+
+    pub collateral_token: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub debt_repaid: u64,
+
+    pub collateral_seized: u64,
+
+    pub debt_value: u128,
+
+    pub liquidation_limit: u128,
+
+    pub collateral_price: OraclePrice,
+
+    pub borrowing_price: OraclePrice,
+
+    pub reserve_fee: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarginAccountLiquidated {
+    pub liquidator: Pubkey,
+
+    pub borrower: Pubkey,
+
+    pub collateral_token: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub debt_repaid: u64,
+
+    pub collateral_seized: u64,
+
+    pub debt_value: u128,
+
+    pub liquidation_limit: u128,
+
+    pub collateral_price: OraclePrice,
+
+    pub borrowing_price: OraclePrice,
+
+    pub reserve_fee: u64,
+
+    pub timestamp: i64,
+}
+
+
+
+//** OBLIGATION EVENTS */
+
+#[event]
+pub struct ObligationCollateralDeposited {
+    pub borrower: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub amount: u64,
+
+    pub deposited_amount: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ObligationCollateralWithdrawn {
+    pub borrower: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub amount: u64,
+
+    pub remaining_amount: u64,
+
+    // Collateral Prices Are Only Read While The Obligation Has Debt
+    pub collateral_prices: Vec<OraclePrice>,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ObligationBorrowed {
+    pub borrower: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub amount: u64,
+
+    pub borrowing_amount: u64,
+
+    pub borrowing_terms: LoanTerms,
+
+    pub debt_value: u128,
+
+    pub borrow_limit: u128,
+
+    pub collateral_prices: Vec<OraclePrice>,
+
+    pub borrowing_price: OraclePrice,
+
+    pub origination_fee: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ObligationRepaid {
+    pub borrower: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub interest_paid: u64,
+
+    pub principal_paid: u64,
+
+    pub borrowing_amount: u64,
+
+    pub reserve_fee: u64,
+
+    pub timestamp: i64,
+}
+
+
+
+//** MARGIN ACCOUNT EVENTS */
+
+#[event]
+pub struct MarginCollateralDeposited {
+    pub borrower: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub amount: u64,
+
+    pub deposited_amount: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarginCollateralWithdrawn {
+    pub borrower: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub amount: u64,
+
+    pub remaining_amount: u64,
+
+    // Prices Are Only Read While The Margin Account Has Borrows
+    pub collateral_prices: Vec<OraclePrice>,
+
+    pub borrow_prices: Vec<OraclePrice>,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarginBorrowed {
+    pub borrower: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub amount: u64,
+
+    pub borrowing_amount: u64,
+
+    pub borrowing_terms: LoanTerms,
+
+    pub debt_value: u128,
+
+    pub borrow_limit: u128,
+
+    pub collateral_prices: Vec<OraclePrice>,
+
+    pub borrow_prices: Vec<OraclePrice>,
+
+    pub origination_fee: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarginDebtRepaid {
+    pub borrower: Pubkey,
+
+    pub borrowing_token: Pubkey,
+
+    pub interest_paid: u64,
+
+    pub principal_paid: u64,
+
+    pub borrowing_amount: u64,
+
+    pub reserve_fee: u64,
+
+    pub timestamp: i64,
+}
+
+
+
+//** FLASH LOAN EVENTS */
+
+#[event]
+pub struct FlashBorrowed {
+    pub borrower: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub amount: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FlashRepaid {
+    pub borrower: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub amount: u64,
+
+    pub fee: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminMultisigCreated {
+    pub owners: Vec<Pubkey>,
+
+    pub threshold: u8,
+
+    pub multisig_signer: Pubkey,
+
+    pub admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminMultisigUpdated {
+    pub owners: Vec<Pubkey>,
+
+    pub threshold: u8,
+
+    pub owner_set_seqno: u32,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionProposed {
+    pub transaction_id: u64,
+
+    pub proposer: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionApproved {
+    pub transaction_id: u64,
+
+    pub owner: Pubkey,
+
+    pub approvals: u8,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionExecuted {
+    pub transaction_id: u64,
+
+    pub executor: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingGovernanceConfigured {
+    pub governance_mint: Pubkey,
+
+    pub quorum: u64,
+
+    pub voting_period: i64,
+
+    pub governance_signer: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingProposed {
+    pub listing_id: u64,
+
+    pub proposer: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub price_feed_id: [u8; 32],
+
+    pub risk_parameters: RiskParameters,
+
+    pub isolation_debt_ceiling: Option<u64>,
+
+    pub voting_ends_at: i64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingVoteCast {
+    pub listing_id: u64,
+
+    pub voter: Pubkey,
+
+    pub weight: u64,
+
+    pub support: bool,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingVoteWithdrawn {
+    pub listing_id: u64,
+
+    pub voter: Pubkey,
+
+    pub weight: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingDecided {
+    pub listing_id: u64,
+
+    pub status: ListingStatus,
+
+    pub votes_for: u64,
+
+    pub votes_against: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingExecuted {
+    pub listing_id: u64,
+
+    pub token_mint: Pubkey,
+
+    pub executor: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+
+    pub previous_version: u8,
+
+    pub version: u8,
+
+    pub payer: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketCreated {
+    pub market: Pubkey,
+
+    pub market_id: u64,
+
+    pub name: String,
+
+    pub admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CuratedMarketCreated {
+    pub market: Pubkey,
+
+    pub market_id: u64,
+
+    pub name: String,
+
+    pub creator: Pubkey,
+
+    pub curator: Pubkey,
+
+    pub lending_durations: Vec<u64>,
+
+    pub creation_deposit: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CuratedMarketClosed {
+    pub market: Pubkey,
+
+    pub market_id: u64,
+
+    pub creator: Pubkey,
+
+    pub creation_deposit: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketCuratorChanged {
+    pub market: Pubkey,
+
+    pub previous_curator: Pubkey,
+
+    pub new_curator: Pubkey,
+
+    pub timestamp: i64,
+}
\ No newline at end of file
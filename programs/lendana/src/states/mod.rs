@@ -8,5 +8,5 @@ pub mod constants;
 pub use accounts::*;
 pub use contexts::*;
 pub use errors::*;
-//pub use events::*;
+pub use events::*;
 pub use constants::*;
\ No newline at end of file
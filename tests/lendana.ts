@@ -23,6 +23,15 @@ import {
   mintTo,
   mintToChecked,
   getAccount,
+  ExtensionType,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createInitializeInterestBearingMintInstruction,
+  getMint,
+  getInterestBearingMintConfigState,
+  getTransferFeeAmount,
 } from "@solana/spl-token";
 import { publicKey } from "@coral-xyz/anchor/dist/cjs/utils";
 import { expect } from "chai";
@@ -79,6 +88,7 @@ describe("lendana", () => {
   const deployer = provider.wallet;
   const lendanaAdmin = anchor.web3.Keypair.generate();
   const whitelister = anchor.web3.Keypair.generate();
+  const riskManager = anchor.web3.Keypair.generate();
   const lender1 = anchor.web3.Keypair.generate();
   const lender2 = anchor.web3.Keypair.generate();
   const borrower1 = anchor.web3.Keypair.generate();
@@ -93,8 +103,16 @@ describe("lendana", () => {
   let usdcTokenMint: PublicKey;
   let daiTokenMint: PublicKey;
   let ethTokenMint: PublicKey;
+  let stableTokenMint: PublicKey;
   const solMint = NATIVE_MINT;
 
+  // Every Token And Position In These Tests Lives In The First Market
+  const marketId = new BN(0);
+  const [marketPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("market"), marketId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
   // Price Feed IDs
   // Define the constants for the price Feed ID hex
   const usdcTokenPriceFeedIdHex =
@@ -111,12 +129,285 @@ describe("lendana", () => {
     await provider.connection.confirmTransaction(airdropSig);
   }
 
+  /** TIMELOCK HELPERS */
+  // Queues A Governance Action And Returns Its Proposal, Which Matures Right Away While The Delay Is Zero.
+  // Curators Pass The Curated Market The Action Is For
+  async function queueProposal(
+    proposer: Keypair,
+    action,
+    market: PublicKey | null = null
+  ) {
+    const [timelockPDA, timelockBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("timelock")],
+      program.programId
+    );
+    const timelockData = await program.account.timelock.fetch(timelockPDA);
+    const [proposalPDA, proposalBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("timelock_proposal"),
+        timelockData.nextProposalId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .queueProposal(action)
+      .accounts({
+        proposer: proposer.publicKey,
+        market,
+        //@ts-ignore
+        timelockProposal: proposalPDA,
+      })
+      .signers([proposer])
+      .rpc();
+    return proposalPDA;
+  }
+
+  // Proposes A Lendana Instruction To The Admin Multisig, Collects The Approvals And Executes It
+  async function executeThroughMultisig(
+    instruction: anchor.web3.TransactionInstruction,
+    proposer: Keypair,
+    approvers: Keypair[]
+  ) {
+    const [multisigPDA, multisigBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_multisig")],
+      program.programId
+    );
+    const multisigData = await program.account.adminMultisig.fetch(multisigPDA);
+    const [transactionPDA, transactionBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig_transaction"),
+        multisigData.nextTransactionId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .proposeMultisigTransaction(
+        instruction.keys.map((key) => ({
+          pubkey: key.pubkey,
+          isSigner: key.isSigner,
+          isWritable: key.isWritable,
+        })),
+        instruction.data
+      )
+      .accounts({
+        owner: proposer.publicKey,
+        //@ts-ignore
+        multisigTransaction: transactionPDA,
+      })
+      .signers([proposer])
+      .rpc();
+
+    for (const approver of approvers) {
+      await program.methods
+        .approveMultisigTransaction()
+        .accounts({
+          owner: approver.publicKey,
+          multisigTransaction: transactionPDA,
+        })
+        .signers([approver])
+        .rpc();
+    }
+
+    // The Multisig Signer Signs Inside The Program, So It Is Not A Signer Of The Outer Transaction
+    await program.methods
+      .executeMultisigTransaction()
+      .accounts({
+        executor: proposer.publicKey,
+        multisigTransaction: transactionPDA,
+      })
+      .remainingAccounts(
+        instruction.keys.map((key) => ({ ...key, isSigner: false }))
+      )
+      .signers([proposer])
+      .rpc();
+    return transactionPDA;
+  }
+
+  // Price Feed IDs Are Queued As Raw Bytes
+  function feedIdBytes(priceFeedIdHex: string) {
+    return Array.from(Buffer.from(priceFeedIdHex.replace(/^0x/, ""), "hex"));
+  }
+
   async function setupActors(provider, users, amount) {
     for (const user of users) {
       await airdropSol(provider, user, amount);
     }
   }
 
+  // Posts Fresh Pyth Updates For The Feeds, Then Sends The Instructions Built From Their Price Accounts Right After
+  async function sendWithPriceUpdates(
+    priceFeedIdsHex: string[],
+    buildInstructions: (
+      priceAccounts: PublicKey[]
+    ) => Promise<anchor.web3.TransactionInstruction[]>,
+    signers: Keypair[]
+  ) {
+    const priceUpdates = (
+      await priceServiceConnection.getLatestPriceUpdates(priceFeedIdsHex, {
+        encoding: "base64",
+      })
+    ).binary.data;
+    const pythSolanaReceiver = new PythSolanaReceiver({
+      connection: provider.connection,
+      wallet: provider.wallet as any,
+    });
+    const transactionBuilder = pythSolanaReceiver.newTransactionBuilder({
+      closeUpdateAccounts: false,
+    });
+    await transactionBuilder.addPostPriceUpdates(priceUpdates);
+    await transactionBuilder.addPriceConsumerInstructions(
+      async (getPriceUpdateAccount) => {
+        const instructions = await buildInstructions(
+          priceFeedIdsHex.map((priceFeedIdHex) =>
+            getPriceUpdateAccount(priceFeedIdHex)
+          )
+        );
+        return instructions.map((instruction) => ({ instruction, signers }));
+      }
+    );
+    const transactions = await transactionBuilder.buildVersionedTransactions({
+      computeUnitPriceMicroLamports: 100000,
+      tightComputeBudget: true,
+    });
+    // Signatures In Sending Order, The Program's Instructions Last
+    return await pythSolanaReceiver.provider.sendAll(transactions, {
+      skipPreflight: false,
+    });
+  }
+
+  // The Risk Manager Retunes A Token's Risk Parameters
+  async function setRiskParameters(tokenMint: PublicKey, riskParameters) {
+    await program.methods
+      .setRiskConfig(tokenMint, riskParameters)
+      .accounts({
+        market: marketPDA,
+        riskManagerRole: riskManager.publicKey,
+        mintToken: tokenMint,
+        timelockProposal: await queueProposal(riskManager, {
+          setRiskConfig: {
+            market: marketPDA,
+            tokenMint,
+            riskParameters,
+          },
+        }),
+      })
+      .signers([riskManager])
+      .rpc();
+  }
+
+  // The Whitelister Lists A Token In The Main Market And Maps Its Price Feed, Then The Risk Manager Sets Its Parameters
+  async function listToken(
+    tokenMint: PublicKey,
+    tokenProgram: PublicKey,
+    priceFeedIdHex: string,
+    riskParameters
+  ) {
+    await program.methods
+      .whitelistToken(tokenMint, null)
+      .accounts({
+        market: marketPDA,
+        whitelisterRole: whitelister.publicKey,
+        mintToken: tokenMint,
+        tokenProgram,
+        timelockProposal: await queueProposal(whitelister, {
+          whitelistToken: {
+            market: marketPDA,
+            tokenMint,
+            isolationDebtCeiling: null,
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+
+    await program.methods
+      .addPrice(tokenMint, priceFeedIdHex)
+      .accounts({
+        market: marketPDA,
+        oracleManager: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          addPriceFeed: {
+            market: marketPDA,
+            tokenMint,
+            priceFeedId: feedIdBytes(priceFeedIdHex),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+
+    await setRiskParameters(tokenMint, riskParameters);
+  }
+
+  // Creates A Token-2022 Mint Minted By The Whitelister, Its Extensions Initialized Before The Mint Itself
+  async function createToken2022Mint(
+    extensions: ExtensionType[],
+    initializeExtensions: (mint: PublicKey) => TransactionInstruction[]
+  ) {
+    const mint = Keypair.generate();
+    const mintLen = getMintLen(extensions);
+    const lamports =
+      await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: provider.wallet.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space: mintLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        ...initializeExtensions(mint.publicKey),
+        createInitializeMintInstruction(
+          mint.publicKey,
+          6,
+          whitelister.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [mint]
+    );
+    return mint.publicKey;
+  }
+
+  // Transactions Sent Alongside Price Updates Surface Program Errors Only In Their Logs
+  async function expectFailureWithLogs(action: Promise<unknown>, code: string) {
+    try {
+      await action;
+    } catch (error) {
+      expect(String(error.logs ?? error)).to.contain(code);
+      return;
+    }
+    expect.fail(`Expected the transaction to fail with ${code}`);
+  }
+
+  // Events Are Emitted As Self-CPIs Into The Program, Their Data Following An 8-Byte Event Instruction Tag
+  async function emittedEvents(signature: string) {
+    await provider.connection.confirmTransaction(signature, "confirmed");
+    const transaction = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const accountKeys = transaction.transaction.message.getAccountKeys();
+    return transaction.meta.innerInstructions
+      .flatMap((inner) => inner.instructions)
+      .filter((instruction) =>
+        accountKeys.get(instruction.programIdIndex).equals(program.programId)
+      )
+      .map((instruction) =>
+        program.coder.events.decode(
+          Buffer.from(anchor.utils.bytes.bs58.decode(instruction.data))
+            .subarray(8)
+            .toString("base64")
+        )
+      )
+      .filter((event) => event !== null) as { name: string; data: any }[];
+  }
+
   before(async () => {
     await airdropSol(provider, deployer.publicKey, 5);
 
@@ -125,6 +416,7 @@ describe("lendana", () => {
       [
         lendanaAdmin.publicKey,
         whitelister.publicKey,
+        riskManager.publicKey,
         lender1.publicKey,
         lender2.publicKey,
       ],
@@ -163,19 +455,41 @@ describe("lendana", () => {
     );
   });
 
-  it("TEST 1: ---------- ADMIN INITIALIZATION DONE CORRECTLY! -----------", async () => {
-    // Add your test here.
+  it("TEST 1: ---------- PROTOCOL BOOTSTRAPPED BY THE UPGRADE AUTHORITY ONLY -----------", async () => {
     const [adminAccountPDA, adminAccountBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("admin"), lendanaAdmin.publicKey.toBuffer()],
+        [Buffer.from("admin")],
         program.programId
       );
+    const [programDataPDA, programDataBump] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
 
-    // Call The Instruction
+    // Anyone Other Than The Upgrade Authority Is Rejected
+    try {
+      await program.methods
+        .bootstrap(lendanaAdmin.publicKey, new BN(0))
+        .accounts({
+          upgradeAuthority: lendanaAdmin.publicKey,
+          //@ts-ignore
+          programData: programDataPDA,
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("Bootstrap by a non upgrade authority should fail");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("NotUpgradeAuthority");
+    }
+
+    // The Deployer Holds The Upgrade Authority
     await program.methods
-      .initializeAdmin(lendanaAdmin.publicKey)
-      .accounts({})
-      .signers([])
+      .bootstrap(lendanaAdmin.publicKey, new BN(0))
+      .accounts({
+        upgradeAuthority: deployer.publicKey,
+        //@ts-ignore
+        programData: programDataPDA,
+      })
       .rpc();
 
     const adminData = await program.account.administrator.fetch(
@@ -184,46 +498,140 @@ describe("lendana", () => {
     expect(adminData.adminAddress.toBuffer()).to.deep.equal(
       lendanaAdmin.publicKey.toBuffer()
     );
-  });
 
-  it("TEST 2: ----------- INITIALIZATION OF GLOBAL TRUSTED ENTITIES DONE CORRECTLY  ----------------", async () => {
-    // Get AdminAccount
     const [globalTrustedPDA, globalTrustedBump] =
       PublicKey.findProgramAddressSync(
         [Buffer.from("trusted_entities")],
         program.programId
       );
+    const globalTrustedData = await program.account.trustedEntities.fetch(
+      globalTrustedPDA
+    );
+    expect(globalTrustedData.trustedRoles.length).to.eq(0);
+
+    const [protocolStatePDA, protocolStateBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("protocol_state")],
+        program.programId
+      );
+    const protocolStateData = await program.account.protocolState.fetch(
+      protocolStatePDA
+    );
+    expect(protocolStateData.paused).to.be.false;
+
+    const [timelockPDA, timelockBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("timelock")],
+      program.programId
+    );
+    const timelockData = await program.account.timelock.fetch(timelockPDA);
+    expect(timelockData.delay.toNumber()).to.eq(0);
+    expect(timelockData.nextProposalId.toNumber()).to.eq(0);
+
+    // A Second Bootstrap Fails
+    try {
+      await program.methods
+        .bootstrap(whitelister.publicKey, new BN(0))
+        .accounts({
+          upgradeAuthority: deployer.publicKey,
+          //@ts-ignore
+          programData: programDataPDA,
+        })
+        .rpc();
+      expect.fail("Bootstrapping twice should fail");
+    } catch (error) {
+      expect(error.toString()).to.contain("already in use");
+    }
+  });
 
+  it("TEST 1B: ---------- ADMIN ROLE IS HANDED OVER IN TWO STEPS AND CAN BE CANCELLED -----------", async () => {
     const [adminAccountPDA, adminAccountBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("admin"), lendanaAdmin.publicKey.toBuffer()],
+        [Buffer.from("admin")],
         program.programId
       );
+    const nextAdmin = Keypair.generate();
+    // The Nominee Queues The Hand Back Once Admin, Paying For The Proposal
+    await airdropSol(provider, nextAdmin.publicKey, 1);
 
-    // Call The Instruction
+    // Admin Proposes, Then Cancels
     await program.methods
-      .initializeTrustedAuthority()
+      .proposeAdmin(nextAdmin.publicKey)
       .accounts({
         admin: lendanaAdmin.publicKey,
-        // @ts-ignore
-        adminAccount: adminAccountPDA,
-        trustedRoles: globalTrustedPDA,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          proposeAdmin: { newAdmin: nextAdmin.publicKey },
+        }),
       })
       .signers([lendanaAdmin])
       .rpc();
+    await program.methods
+      .cancelAdminProposal()
+      .accounts({ admin: lendanaAdmin.publicKey })
+      .signers([lendanaAdmin])
+      .rpc();
 
-    // Get Account Data
-    const globalTrustedData = await program.account.trustedEntities.fetch(
-      globalTrustedPDA
-    );
-    expect(globalTrustedData.trustedRoles.length).to.eq(0);
+    let adminData = await program.account.administrator.fetch(adminAccountPDA);
+    expect(adminData.pendingAdmin).to.eq(null);
+
+    // Accepting Without A Pending Proposal Fails
+    try {
+      await program.methods
+        .acceptAdmin()
+        .accounts({ pendingAdmin: nextAdmin.publicKey })
+        .signers([nextAdmin])
+        .rpc();
+      expect.fail("Accepting a cancelled proposal should fail");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("NotPendingAdmin");
+    }
+
+    // Admin Proposes Again And The Nominee Accepts
+    await program.methods
+      .proposeAdmin(nextAdmin.publicKey)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          proposeAdmin: { newAdmin: nextAdmin.publicKey },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+    await program.methods
+      .acceptAdmin()
+      .accounts({ pendingAdmin: nextAdmin.publicKey })
+      .signers([nextAdmin])
+      .rpc();
+
+    adminData = await program.account.administrator.fetch(adminAccountPDA);
+    expect(adminData.adminAddress).to.deep.equal(nextAdmin.publicKey);
+    expect(adminData.pendingAdmin).to.eq(null);
+
+    // Hand The Role Back For The Remaining Tests
+    await program.methods
+      .proposeAdmin(lendanaAdmin.publicKey)
+      .accounts({
+        admin: nextAdmin.publicKey,
+        timelockProposal: await queueProposal(nextAdmin, {
+          proposeAdmin: { newAdmin: lendanaAdmin.publicKey },
+        }),
+      })
+      .signers([nextAdmin])
+      .rpc();
+    await program.methods
+      .acceptAdmin()
+      .accounts({ pendingAdmin: lendanaAdmin.publicKey })
+      .signers([lendanaAdmin])
+      .rpc();
+
+    adminData = await program.account.administrator.fetch(adminAccountPDA);
+    expect(adminData.adminAddress).to.deep.equal(lendanaAdmin.publicKey);
   });
 
   it("TEST 3: ----------- ADDING A WHITELISTER ROLE DONE CORRECTLY  ---------------", async () => {
     // Get The PDAs
     const [adminAccountPDA, adminAccountBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("admin"), lendanaAdmin.publicKey.toBuffer()],
+        [Buffer.from("admin")],
         program.programId
       );
 
@@ -233,115 +641,482 @@ describe("lendana", () => {
         program.programId
       );
 
-    // Call The Instruction
-    await program.methods
-      .grantWhitelister(whitelister.publicKey)
-      .accounts({
-        admin: lendanaAdmin.publicKey,
-        //@ts-ignore
-        adminAccount: adminAccountPDA,
-        trustedRoles: globalTrustedPDA,
-      })
-      .signers([lendanaAdmin])
-      .rpc();
+    // The Whitelister Also Manages The Price Feed Registry
+    for (const role of [{ whitelister: {} }, { oracleManager: {} }]) {
+      await program.methods
+        .grantRole(role, whitelister.publicKey)
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          //@ts-ignore
+          adminAccount: adminAccountPDA,
+          trustedRoles: globalTrustedPDA,
+          timelockProposal: await queueProposal(lendanaAdmin, {
+            grantRole: { role, member: whitelister.publicKey },
+          }),
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+    }
 
     const trustedRolesData = await program.account.trustedEntities.fetch(
       globalTrustedPDA
     );
-    expect(trustedRolesData.trustedRoles.length).to.eq(1);
-  });
+    expect(trustedRolesData.trustedRoles.length).to.eq(2);
+    expect(trustedRolesData.trustedRoles[0].member).to.deep.equal(
+      whitelister.publicKey
+    );
+    expect(trustedRolesData.trustedRoles[0].role).to.deep.equal({
+      whitelister: {},
+    });
 
-  it("TEST 5: ---------------- INITIALIZING A GLOBAL TOKEN WHITELIST REGISTRY AND POSITION COUNTERS ----------", async () => {
-    // Getting The PDAs
-    const [globalWhitelistedTokensPDA, globalWhitelistedTokensBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("all_whitelisted_tokens")],
-        program.programId
-      );
+    // Granting The Same Role Twice Fails
+    try {
+      await program.methods
+        .grantRole({ whitelister: {} }, whitelister.publicKey)
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          timelockProposal: await queueProposal(lendanaAdmin, {
+            grantRole: { role: { whitelister: {} }, member: whitelister.publicKey },
+          }),
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("Duplicate role grant should fail");
+    } catch (err) {
+      expect(err.toString()).to.include("RoleAlreadyGranted");
+    }
 
-    const [lenderPositionCounterPDA, lenderPositionCounterBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("lenders_position_id_counter")],
-        program.programId
-      );
+    // Only The Admin Can Grant Roles
+    try {
+      await program.methods
+        .grantRole({ treasurer: {} }, whitelister.publicKey)
+        .accounts({
+          admin: whitelister.publicKey,
+          timelockProposal: await queueProposal(lendanaAdmin, {
+            grantRole: { role: { treasurer: {} }, member: whitelister.publicKey },
+          }),
+        })
+        .signers([whitelister])
+        .rpc();
+      expect.fail("Non-admin role grant should fail");
+    } catch (err) {
+      expect(err.toString()).to.include("OnlyAdmin");
+    }
+  });
 
-    const [borrowerPositionCounterPDA, borrowerPositionCounterBump] =
+  it("TEST 3B: ----------- ROTATING, LISTING AND REVOKING A ROLE ---------------", async () => {
+    const [globalTrustedPDA, globalTrustedBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("borrowers_position_id_counter")],
+        [Buffer.from("trusted_entities")],
         program.programId
       );
 
-    const [priceFeedsRegistryPDA, priceFeedsRegistryBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("price_feed_registry")],
-        program.programId
-      );
+    // Grant The Pauser Role
+    await program.methods
+      .grantRole({ pauser: {} }, riskManager.publicKey)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          grantRole: { role: { pauser: {} }, member: riskManager.publicKey },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
 
-    const [solCollateralVaultPDA, solCollateralVaultBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("sol_collateral_vault")],
-        program.programId
-      );
+    let trustedRolesData = await program.account.trustedEntities.fetch(
+      globalTrustedPDA
+    );
+    expect(trustedRolesData.trustedRoles.length).to.eq(3);
 
+    // Rotate The Pauser Role To Another Address In One Step
     await program.methods
-      .initRegistriesAndCounters()
+      .rotateRole({ pauser: {} }, riskManager.publicKey, lender1.publicKey)
       .accounts({
-        whitelisterRole: whitelister.publicKey,
-        //@ts-ignore
-        tokensPriceFeedRegistry: priceFeedsRegistryPDA,
-        sol_collateral_vault: solCollateralVaultPDA,
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          rotateRole: {
+            role: { pauser: {} },
+            previousMember: riskManager.publicKey,
+            newMember: lender1.publicKey,
+          },
+        }),
       })
-      .signers([whitelister])
+      .signers([lendanaAdmin])
       .rpc();
 
-    const whitelistedRegistryData =
-      await program.account.allWhitelistedTokens.fetch(
-        globalWhitelistedTokensPDA
-      );
+    const pausers = await program.methods
+      .listRoleMembers({ pauser: {} })
+      .accounts({
+        //@ts-ignore
+        trustedRoles: globalTrustedPDA,
+      })
+      .view();
+    expect(pausers.length).to.eq(1);
+    expect(pausers[0]).to.deep.equal(lender1.publicKey);
 
-    const lendersPositionsData =
-      await program.account.lenderPositionIdCounter.fetch(
-        lenderPositionCounterPDA
-      );
-    const borrowersPositionsData =
-      await program.account.borrowerPositionIdCounter.fetch(
-        borrowerPositionCounterPDA
-      );
-    const priceFeedsRegistryData =
-      await program.account.tokenPriceFeedRegistry.fetch(priceFeedsRegistryPDA);
-    const solCollateralVaultData =
-      await program.account.solCollateralVault.fetch(solCollateralVaultPDA);
+    await program.methods
+      .revokeRole({ pauser: {} }, lender1.publicKey)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          revokeRole: { role: { pauser: {} }, member: lender1.publicKey },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
 
-    expect(whitelistedRegistryData.tokensWhitelisted.length).to.eq(0);
-    expect(lendersPositionsData.lendersCurrentPositionId.toNumber()).to.eq(0);
-    expect(borrowersPositionsData.borrowersCurrentPositionId.toNumber()).to.eq(
-      0
+    trustedRolesData = await program.account.trustedEntities.fetch(
+      globalTrustedPDA
+    );
+    expect(trustedRolesData.trustedRoles.length).to.eq(2);
+
+    // Revoking A Role That Is Not Held Fails
+    try {
+      await program.methods
+        .revokeRole({ pauser: {} }, lender1.publicKey)
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          timelockProposal: await queueProposal(lendanaAdmin, {
+            revokeRole: { role: { pauser: {} }, member: lender1.publicKey },
+          }),
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("Revoking an ungranted role should fail");
+    } catch (err) {
+      expect(err.toString()).to.include("RoleNotGranted");
+    }
+  });
+
+  it("TEST 3C: ----------- TIMELOCKED CHANGES WAIT FOR THEIR DELAY AND CAN BE CANCELLED ---------------", async () => {
+    const [timelockPDA, timelockBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("timelock")],
+      program.programId
     );
-    expect(priceFeedsRegistryData.tokenPriceMapping.length).to.eq(0);
-    expect(priceFeedsRegistryData.authority.toBuffer()).to.deep.equal(
-      whitelister.publicKey.toBuffer()
+
+    // Only The Admin Or The Role Making The Change May Queue It
+    try {
+      await queueProposal(lender1, {
+        grantRole: { role: { treasurer: {} }, member: lender1.publicKey },
+      });
+      expect.fail("A non-admin should not queue a role grant");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("NotAllowedToPropose");
+    }
+
+    // Queued While The Delay Is Zero, So It Matures Right Away
+    const resetDelayProposal = await queueProposal(lendanaAdmin, {
+      setTimelockDelay: { delay: new BN(0) },
+    });
+
+    // Raise The Delay To An Hour
+    await program.methods
+      .setTimelockDelay(new BN(3600))
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          setTimelockDelay: { delay: new BN(3600) },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+    let timelockData = await program.account.timelock.fetch(timelockPDA);
+    expect(timelockData.delay.toNumber()).to.eq(3600);
+
+    // A Change Queued Now Cannot Run Before The Hour Is Up
+    const slowProposal = await queueProposal(lendanaAdmin, {
+      grantRole: { role: { treasurer: {} }, member: lender2.publicKey },
+    });
+    try {
+      await program.methods
+        .grantRole({ treasurer: {} }, lender2.publicKey)
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          timelockProposal: slowProposal,
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("An unmatured proposal should not execute");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("ProposalNotMatured");
+    }
+
+    // A Proposal Only Executes The Exact Change It Was Queued For
+    try {
+      await program.methods
+        .grantRole({ treasurer: {} }, lender2.publicKey)
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          timelockProposal: resetDelayProposal,
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("A proposal for another change should not execute");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("ProposalActionMismatch");
+    }
+
+    // Only The Proposer Or The Admin Can Cancel
+    try {
+      await program.methods
+        .cancelProposal()
+        .accounts({
+          canceller: lender1.publicKey,
+          timelockProposal: slowProposal,
+        })
+        .signers([lender1])
+        .rpc();
+      expect.fail("A stranger should not cancel a proposal");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("OnlyProposerOrAdmin");
+    }
+
+    await program.methods
+      .cancelProposal()
+      .accounts({
+        canceller: lendanaAdmin.publicKey,
+        timelockProposal: slowProposal,
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+    const slowProposalData = await program.account.timelockProposal.fetch(
+      slowProposal
     );
-    expect(solCollateralVaultData.vaultBalance.toNumber()).to.eq(0);
-    expect(solCollateralVaultData.isActive).to.be.true;
+    expect(slowProposalData.status).to.deep.equal({ cancelled: {} });
+
+    // The Proposal Queued Before The Raise Brings The Delay Back To Zero
+    await program.methods
+      .setTimelockDelay(new BN(0))
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: resetDelayProposal,
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+    timelockData = await program.account.timelock.fetch(timelockPDA);
+    expect(timelockData.delay.toNumber()).to.eq(0);
+
+    // An Executed Proposal Cannot Be Replayed
+    try {
+      await program.methods
+        .setTimelockDelay(new BN(0))
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          timelockProposal: resetDelayProposal,
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("An executed proposal should not execute again");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("ProposalNotQueued");
+    }
   });
 
-  it("TEST 6:  ----------- WHITELISTING A TOKEN ADDRESS ----------", async () => {
-    // Get Global Token Registry
-    const [globalWhitelistedTokensPDA, globalWhitelistedTokensBump] =
+  it("TEST 3D: ----------- AN M-OF-N MULTISIG TAKES OVER AND HANDS BACK THE ADMIN ROLE ---------------", async () => {
+    const [adminAccountPDA, adminAccountBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("admin")],
+        program.programId
+      );
+    const [multisigPDA, multisigBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_multisig")],
+      program.programId
+    );
+    const [multisigSignerPDA, multisigSignerBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("all_whitelisted_tokens")],
+        [Buffer.from("admin_multisig_signer")],
         program.programId
       );
 
-    const [whitelisterPDA, whitelisterBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("whitelister"), whitelister.publicKey.toBuffer()],
+    // A Threshold Above The Number Of Owners Is Rejected
+    try {
+      await program.methods
+        .createAdminMultisig([lendanaAdmin.publicKey], 2)
+        .accounts({ admin: lendanaAdmin.publicKey })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("An unreachable threshold should be rejected");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidMultisigThreshold");
+    }
+
+    // Two Of Three Owners
+    await program.methods
+      .createAdminMultisig(
+        [lendanaAdmin.publicKey, whitelister.publicKey, riskManager.publicKey],
+        2
+      )
+      .accounts({ admin: lendanaAdmin.publicKey })
+      .signers([lendanaAdmin])
+      .rpc();
+    const multisigData = await program.account.adminMultisig.fetch(multisigPDA);
+    expect(multisigData.owners.length).to.eq(3);
+    expect(multisigData.threshold).to.eq(2);
+
+    // The Multisig Signer Pays For The Proposals It Queues
+    await airdropSol(provider, multisigSignerPDA, 1);
+
+    // The Admin Nominates The Multisig Signer, Which Accepts Through The Multisig
+    await program.methods
+      .proposeAdmin(multisigSignerPDA)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          proposeAdmin: { newAdmin: multisigSignerPDA },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+    await executeThroughMultisig(
+      await program.methods
+        .acceptAdmin()
+        .accounts({ pendingAdmin: multisigSignerPDA })
+        .instruction(),
+      lendanaAdmin,
+      [whitelister]
+    );
+    let adminData = await program.account.administrator.fetch(adminAccountPDA);
+    expect(adminData.adminAddress).to.deep.equal(multisigSignerPDA);
+
+    // One Approval Is Not Enough, And Only Owners Can Approve
+    const [timelockPDA, timelockBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("timelock")],
       program.programId
     );
+    let timelockData = await program.account.timelock.fetch(timelockPDA);
+    const [handBackProposalPDA, handBackProposalBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("timelock_proposal"),
+          timelockData.nextProposalId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    const queueHandBack = await program.methods
+      .queueProposal({ proposeAdmin: { newAdmin: lendanaAdmin.publicKey } })
+      .accounts({
+        proposer: multisigSignerPDA,
+        market: null,
+        //@ts-ignore
+        timelockProposal: handBackProposalPDA,
+      })
+      .instruction();
+    try {
+      await executeThroughMultisig(queueHandBack, lendanaAdmin, []);
+      expect.fail("A single approval should not execute");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("MultisigThresholdNotMet");
+    }
+    try {
+      await executeThroughMultisig(queueHandBack, whitelister, [lender1]);
+      expect.fail("A non-owner should not approve");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("NotMultisigOwner");
+    }
+
+    // The Multisig Queues And Executes The Hand Back, And The Admin Accepts
+    await executeThroughMultisig(queueHandBack, riskManager, [whitelister]);
+    await executeThroughMultisig(
+      await program.methods
+        .proposeAdmin(lendanaAdmin.publicKey)
+        .accounts({
+          admin: multisigSignerPDA,
+          timelockProposal: handBackProposalPDA,
+        })
+        .instruction(),
+      lendanaAdmin,
+      [riskManager]
+    );
+    await program.methods
+      .acceptAdmin()
+      .accounts({ pendingAdmin: lendanaAdmin.publicKey })
+      .signers([lendanaAdmin])
+      .rpc();
+    adminData = await program.account.administrator.fetch(adminAccountPDA);
+    expect(adminData.adminAddress).to.deep.equal(lendanaAdmin.publicKey);
+  });
+
+  it("TEST 5: ---------------- MARKET OPENED BY THE ADMIN WITH ITS OWN POSITION COUNTERS ----------", async () => {
+    // Only A Matured Proposal For This Exact Market Opens It
+    try {
+      await program.methods
+        .createMarket(marketId, "Main Market")
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          timelockProposal: await queueProposal(lendanaAdmin, {
+            createMarket: { marketId, name: "Another Market" },
+          }),
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+      expect.fail("Opening a market through a mismatched proposal should fail");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("ProposalActionMismatch");
+    }
+
+    await program.methods
+      .createMarket(marketId, "Main Market")
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          createMarket: { marketId, name: "Main Market" },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+
+    const marketData = await program.account.market.fetch(marketPDA);
+    expect(marketData.marketId.toNumber()).to.eq(0);
+    expect(marketData.name).to.eq("Main Market");
+
+    // Getting The PDAs
+    const [lenderPositionCounterPDA, lenderPositionCounterBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("lenders_position_id_counter"), marketPDA.toBuffer()],
+        program.programId
+      );
+
+    const [borrowerPositionCounterPDA, borrowerPositionCounterBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("borrowers_position_id_counter"), marketPDA.toBuffer()],
+        program.programId
+      );
+
+    const lendersPositionsData =
+      await program.account.lenderPositionIdCounter.fetch(
+        lenderPositionCounterPDA
+      );
+    const borrowersPositionsData =
+      await program.account.borrowerPositionIdCounter.fetch(
+        borrowerPositionCounterPDA
+      );
+
+    expect(lendersPositionsData.lendersCurrentPositionId.toNumber()).to.eq(0);
+    expect(borrowersPositionsData.borrowersCurrentPositionId.toNumber()).to.eq(
+      0
+    );
+  });
+
+  it("TEST 6:  ----------- WHITELISTING A TOKEN ADDRESS ----------", async () => {
+    // Token Config PDA
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
 
     // Token Escrow Vault PDA
     const [usdcTokenVaultPDA, usdcTokenEscrowVaultBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("token_escrow"), usdcTokenMint.toBuffer()],
+        [
+          Buffer.from("token_escrow"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
         program.programId
       );
 
@@ -355,36 +1130,41 @@ describe("lendana", () => {
 
     // Call The Instruction
     await program.methods
-      .whitelistToken(usdcTokenMint)
+      .whitelistToken(usdcTokenMint, null)
       .accounts({
+        market: marketPDA,
         whitelisterRole: whitelister.publicKey,
         //@ts-ignore
-        allWhitelistedTokens: globalWhitelistedTokensPDA,
-        whitelister: whitelisterPDA,
+        tokenConfig: usdcTokenConfigPDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         mintToken: usdcTokenMint,
         tokenVault: tokenVaultAddress,
         tokenEscrow: usdcTokenVaultPDA,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        timelockProposal: await queueProposal(whitelister, {
+          whitelistToken: {
+            market: marketPDA,
+            tokenMint: usdcTokenMint,
+            isolationDebtCeiling: null,
+          },
+        }),
       })
       .signers([whitelister])
       .rpc();
 
-    // Verify usdcToken has been added to global whitelisted tokens
-    const whitelistedRegistryData =
-      await program.account.allWhitelistedTokens.fetch(
-        globalWhitelistedTokensPDA
-      );
-    const tokenExists = whitelistedRegistryData.tokensWhitelisted.some(
-      (tokenPublicKey) => tokenPublicKey.equals(usdcTokenMint)
+    // Verify usdcToken's config has been created and marked whitelisted
+    const usdcTokenConfigData = await program.account.tokenConfig.fetch(
+      usdcTokenConfigPDA
     );
     const tokenVaultData = await getAccount(
       provider.connection,
       tokenVaultAddress
     );
-    expect(tokenExists).to.be.true;
-    expect(whitelistedRegistryData.tokensWhitelisted.length).to.eq(1);
+    expect(usdcTokenConfigData.isWhitelisted).to.be.true;
+    expect(usdcTokenConfigData.tokenMint).to.deep.equal(usdcTokenMint);
+    expect(usdcTokenConfigData.tokenEscrow).to.deep.equal(usdcTokenVaultPDA);
+    expect(usdcTokenConfigData.priceFeedId).to.be.null;
 
     // Query The Associated Token Escrow Vault creation
     const usdcTokenVaultPDAData =
@@ -394,90 +1174,479 @@ describe("lendana", () => {
     );
     expect(usdcTokenVaultPDAData.totalLentTokens.toNumber()).to.eq(0);
     expect(usdcTokenVaultPDAData.isActive).to.be.true;
+    expect(usdcTokenVaultPDAData.isolationMode).to.be.false;
 
     // Query The Associated Token Vault account balance to ensure it has no tokens
     expect(Number(tokenVaultData.amount)).to.eq(0);
   });
 
-  it("TEST 7:   ------------------- LENDER1 AND LENDER2 LENDS THEIR WHITELISTED USDC TOKEN   ---------------------", async () => {
-    /* + The User Got To Have Some Whitelisted Tokens already*/
-    // Add this at the start of your test
-
-    const lender1ATAaddress = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      lender1,
-      usdcTokenMint,
-      lender1.publicKey
-    );
-
-    const lender2ATAaddress = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      lender2,
-      usdcTokenMint,
-      lender2.publicKey
-    );
+  it("TEST 6C: UNHAPPY SCENARIO ----------- WHITELISTING A TOKEN-2022 MINT WITH A PERMANENT DELEGATE SHOULD FAIL ----------", async () => {
+    // Create A Token-2022 Mint Whose Permanent Delegate Could Drain The Vault
+    const delegatedMint = Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.PermanentDelegate]);
+    const lamports =
+      await provider.connection.getMinimumBalanceForRentExemption(mintLen);
 
-    const lender1UsdcToken = await mintTo(
-      provider.connection,
-      lender1,
-      usdcTokenMint,
-      lender1ATAaddress.address,
-      whitelister.publicKey,
-      700 * 10 ** 6,
-      [whitelister]
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: provider.wallet.publicKey,
+          newAccountPubkey: delegatedMint.publicKey,
+          space: mintLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializePermanentDelegateInstruction(
+          delegatedMint.publicKey,
+          provider.wallet.publicKey,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(
+          delegatedMint.publicKey,
+          6,
+          provider.wallet.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [delegatedMint]
     );
 
-    const lender2UsdcToken = await mintTo(
-      provider.connection,
-      lender2,
-      usdcTokenMint,
-      lender2ATAaddress.address,
-      whitelister.publicKey,
-      700 * 10 ** 6,
-      [whitelister]
-    );
+    try {
+      await program.methods
+        .whitelistToken(delegatedMint.publicKey, null)
+        .accounts({
+          market: marketPDA,
+          whitelisterRole: whitelister.publicKey,
+          mintToken: delegatedMint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          timelockProposal: await queueProposal(whitelister, {
+            whitelistToken: {
+              market: marketPDA,
+              tokenMint: delegatedMint.publicKey,
+              isolationDebtCeiling: null,
+            },
+          }),
+        })
+        .signers([whitelister])
+        .rpc();
+      expect.fail("A permanent delegate mint should be rejected");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("UnsupportedMintExtension");
+    }
+  });
 
-    // Get PDAs
-    const [globalWhitelistedTokensPDA, globalWhitelistedTokensBump] =
+  it("TEST 6B: ----------- RISK MANAGER SETS USDC AND SOL RISK PARAMETERS ----------", async () => {
+    const [adminAccountPDA, adminAccountBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("all_whitelisted_tokens")],
+        [Buffer.from("admin")],
         program.programId
       );
 
-    const [lenderPositionCounterPDA, lenderPositionCounterBump] =
+    const [globalTrustedPDA, globalTrustedBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("lenders_position_id_counter")],
+        [Buffer.from("trusted_entities")],
         program.programId
       );
 
-    const [tokenEscrowPDA, tokenEscrowBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("token_escrow"), usdcTokenMint.toBuffer()],
-      program.programId
-    );
+    // Admin Grants The Risk Manager Role
+    await program.methods
+      .grantRole({ riskManager: {} }, riskManager.publicKey)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        //@ts-ignore
+        adminAccount: adminAccountPDA,
+        trustedRoles: globalTrustedPDA,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          grantRole: { role: { riskManager: {} }, member: riskManager.publicKey },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
 
-    const tokenVaultAddress = getAssociatedTokenAddressSync(
-      usdcTokenMint,
-      tokenEscrowPDA,
-      true,
-      TOKEN_PROGRAM_ID,
-      ASSOCIATED_TOKEN_PROGRAM_ID
-    );
+    const usdcRiskParameters = {
+      maxLtvBps: new BN(8000),
+      liquidationThresholdBps: new BN(8500),
+      liquidationBonusBps: new BN(500),
+      supplyCap: new BN(5_000 * 10 ** 6),
+      borrowCap: new BN(4_000 * 10 ** 6),
+      borrowableInIsolation: true,
+      reserveFactorBps: new BN(1000),
+      originationFeeBps: new BN(10),
+    };
 
-    const [lender1PositionPDA, lender1PositionBump] =
-      PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("lender_position"),
-          lender1.publicKey.toBuffer(),
-          usdcTokenMint.toBuffer(),
-        ],
-        program.programId
-      );
+    const solRiskParameters = {
+      maxLtvBps: new BN(7000),
+      liquidationThresholdBps: new BN(7500),
+      liquidationBonusBps: new BN(800),
+      supplyCap: new BN(0),
+      borrowCap: new BN(0),
+      borrowableInIsolation: false,
+      reserveFactorBps: new BN(1500),
+      originationFeeBps: new BN(0),
+    };
 
-    const [lender2PositionPDA, lender2PositionBump] =
+    for (const [mint, riskParameters] of [
+      [usdcTokenMint, usdcRiskParameters],
+      [solMint, solRiskParameters],
+    ] as const) {
+      await program.methods
+        .setRiskConfig(mint, riskParameters)
+        .accounts({
+          market: marketPDA,
+          riskManagerRole: riskManager.publicKey,
+          mintToken: mint,
+          timelockProposal: await queueProposal(riskManager, {
+            setRiskConfig: {
+              market: marketPDA,
+              tokenMint: mint,
+              riskParameters,
+            },
+          }),
+        })
+        .signers([riskManager])
+        .rpc();
+    }
+
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
       PublicKey.findProgramAddressSync(
         [
-          Buffer.from("lender_position"),
-          lender2.publicKey.toBuffer(),
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const usdcTokenConfigData = await program.account.tokenConfig.fetch(
+      usdcTokenConfigPDA
+    );
+    expect(usdcTokenConfigData.tokenMint).to.deep.equal(usdcTokenMint);
+    expect(usdcTokenConfigData.riskParameters.maxLtvBps.toNumber()).to.eq(8000);
+    expect(
+      usdcTokenConfigData.riskParameters.supplyCap.toNumber()
+    ).to.eq(5_000 * 10 ** 6);
+    // Setting Risk Parameters Leaves The Whitelisting In Place
+    expect(usdcTokenConfigData.isWhitelisted).to.be.true;
+
+    // A Max LTV At Or Above The Liquidation Threshold Is Rejected
+    try {
+      await program.methods
+        .setRiskConfig(usdcTokenMint, {
+          ...usdcRiskParameters,
+          maxLtvBps: new BN(9000),
+        })
+        .accounts({
+          market: marketPDA,
+          riskManagerRole: riskManager.publicKey,
+          mintToken: usdcTokenMint,
+          timelockProposal: await queueProposal(riskManager, {
+            setRiskConfig: {
+              market: marketPDA,
+              tokenMint: usdcTokenMint,
+              riskParameters: { ...usdcRiskParameters, maxLtvBps: new BN(9000) },
+            },
+          }),
+        })
+        .signers([riskManager])
+        .rpc();
+      expect.fail("Invalid risk parameters should be rejected");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidRiskParameters");
+    }
+
+    // An Origination Fee Above The Protocol Maximum Is Rejected
+    try {
+      await program.methods
+        .setRiskConfig(usdcTokenMint, {
+          ...usdcRiskParameters,
+          originationFeeBps: new BN(1_001),
+        })
+        .accounts({
+          market: marketPDA,
+          riskManagerRole: riskManager.publicKey,
+          mintToken: usdcTokenMint,
+          timelockProposal: await queueProposal(riskManager, {
+            setRiskConfig: {
+              market: marketPDA,
+              tokenMint: usdcTokenMint,
+              riskParameters: { ...usdcRiskParameters, originationFeeBps: new BN(1_001) },
+            },
+          }),
+        })
+        .signers([riskManager])
+        .rpc();
+      expect.fail("Excessive origination fee should be rejected");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidRiskParameters");
+    }
+  });
+
+  it("TEST 6D: ----------- WHITELISTER DELISTS AN UNUSED TOKEN AND RECLAIMS ITS ACCOUNTS ----------", async () => {
+    const retiredMint = await createMint(
+      provider.connection,
+      whitelister,
+      whitelister.publicKey,
+      null,
+      6
+    );
+
+    const [retiredEscrowPDA, retiredEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_escrow"),
+          marketPDA.toBuffer(),
+          retiredMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    const [retiredTokenConfigPDA, retiredTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          retiredMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    await program.methods
+      .whitelistToken(retiredMint, null)
+      .accounts({
+        market: marketPDA,
+        whitelisterRole: whitelister.publicKey,
+        mintToken: retiredMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        timelockProposal: await queueProposal(whitelister, {
+          whitelistToken: {
+            market: marketPDA,
+            tokenMint: retiredMint,
+            isolationDebtCeiling: null,
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+
+    const retiredRiskParameters = {
+      maxLtvBps: new BN(7000),
+      liquidationThresholdBps: new BN(7500),
+      liquidationBonusBps: new BN(500),
+      supplyCap: new BN(0),
+      borrowCap: new BN(0),
+      borrowableInIsolation: false,
+      reserveFactorBps: new BN(1000),
+      originationFeeBps: new BN(0),
+    };
+    await program.methods
+      .setRiskConfig(retiredMint, retiredRiskParameters)
+      .accounts({
+        market: marketPDA,
+        riskManagerRole: riskManager.publicKey,
+        mintToken: retiredMint,
+        timelockProposal: await queueProposal(riskManager, {
+          setRiskConfig: {
+            market: marketPDA,
+            tokenMint: retiredMint,
+            riskParameters: retiredRiskParameters,
+          },
+        }),
+      })
+      .signers([riskManager])
+      .rpc();
+
+    // Start Delisting, Lowering The Max LTV To 50% Over A Day
+    await program.methods
+      .delistToken(retiredMint, new BN(5000), new BN(86_400))
+      .accounts({
+        market: marketPDA,
+        whitelisterRole: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          delistToken: {
+            market: marketPDA,
+            tokenMint: retiredMint,
+            targetMaxLtvBps: new BN(5000),
+            windDownDuration: new BN(86_400),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+
+    const retiredEscrowData =
+      await program.account.lentBorrowedTokenEscrow.fetch(retiredEscrowPDA);
+    expect(retiredEscrowData.isDelisted).to.be.true;
+
+    const retiredTokenConfigData = await program.account.tokenConfig.fetch(
+      retiredTokenConfigPDA
+    );
+    expect(
+      retiredTokenConfigData.ltvWindDown.startMaxLtvBps.toNumber()
+    ).to.eq(7000);
+    expect(
+      retiredTokenConfigData.ltvWindDown.targetMaxLtvBps.toNumber()
+    ).to.eq(5000);
+
+    // Delisting Twice Fails
+    try {
+      await program.methods
+        .delistToken(retiredMint, null, new BN(0))
+        .accounts({
+          market: marketPDA,
+          whitelisterRole: whitelister.publicKey,
+          timelockProposal: await queueProposal(whitelister, {
+            delistToken: {
+              market: marketPDA,
+              tokenMint: retiredMint,
+              targetMaxLtvBps: null,
+              windDownDuration: new BN(0),
+            },
+          }),
+        })
+        .signers([whitelister])
+        .rpc();
+      expect.fail("A token cannot be delisted twice");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("TokenDelisted");
+    }
+
+    // Tokens Sent Straight To The Vault Do Not Hold Finalization Up, And Are Swept To The Admin
+    const retiredVaultAddress = getAssociatedTokenAddressSync(
+      retiredMint,
+      retiredEscrowPDA,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      whitelister,
+      retiredMint,
+      retiredVaultAddress,
+      whitelister,
+      5
+    );
+    const adminRetiredATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      whitelister,
+      retiredMint,
+      lendanaAdmin.publicKey
+    );
+
+    // Nothing Was Ever Lent Or Borrowed, So The Delisting Can Be Finalized Right Away
+    await program.methods
+      .finalizeDelisting()
+      .accounts({
+        market: marketPDA,
+        whitelisterRole: whitelister.publicKey,
+        mintToken: retiredMint,
+        leftoversTokenAccount: adminRetiredATA.address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([whitelister])
+      .rpc();
+
+    const adminRetiredAccount = await getAccount(
+      provider.connection,
+      adminRetiredATA.address
+    );
+    expect(Number(adminRetiredAccount.amount)).to.eq(5);
+    expect(
+      await provider.connection.getAccountInfo(retiredVaultAddress)
+    ).to.be.null;
+
+    // The Config Is Kept With Its Risk Parameters, But No Longer Whitelisted
+    const finalizedTokenConfigData = await program.account.tokenConfig.fetch(
+      retiredTokenConfigPDA
+    );
+    expect(finalizedTokenConfigData.isWhitelisted).to.be.false;
+    expect(
+      finalizedTokenConfigData.riskParameters.maxLtvBps.toNumber()
+    ).to.eq(7000);
+    expect(
+      await provider.connection.getAccountInfo(retiredEscrowPDA)
+    ).to.be.null;
+  });
+
+  it("TEST 7:   ------------------- LENDER1 AND LENDER2 LENDS THEIR WHITELISTED USDC TOKEN   ---------------------", async () => {
+    /* + The User Got To Have Some Whitelisted Tokens already*/
+    // Add this at the start of your test
+
+    const lender1ATAaddress = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender1,
+      usdcTokenMint,
+      lender1.publicKey
+    );
+
+    const lender2ATAaddress = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender2,
+      usdcTokenMint,
+      lender2.publicKey
+    );
+
+    const lender1UsdcToken = await mintTo(
+      provider.connection,
+      lender1,
+      usdcTokenMint,
+      lender1ATAaddress.address,
+      whitelister.publicKey,
+      700 * 10 ** 6,
+      [whitelister]
+    );
+
+    const lender2UsdcToken = await mintTo(
+      provider.connection,
+      lender2,
+      usdcTokenMint,
+      lender2ATAaddress.address,
+      whitelister.publicKey,
+      700 * 10 ** 6,
+      [whitelister]
+    );
+
+    // Get PDAs
+    const [lenderPositionCounterPDA, lenderPositionCounterBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("lenders_position_id_counter"), marketPDA.toBuffer()],
+        program.programId
+      );
+
+    const [tokenEscrowPDA, tokenEscrowBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("token_escrow"),
+        marketPDA.toBuffer(),
+        usdcTokenMint.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const tokenVaultAddress = getAssociatedTokenAddressSync(
+      usdcTokenMint,
+      tokenEscrowPDA,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const [lender1PositionPDA, lender1PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
+          lender1.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    const [lender2PositionPDA, lender2PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
+          lender2.publicKey.toBuffer(),
           usdcTokenMint.toBuffer(),
         ],
         program.programId
@@ -498,11 +1667,11 @@ describe("lendana", () => {
     await program.methods
       .lendToken(new BN(450 * 10 ** 6), lender1LoanTerms)
       .accounts({
+        market: marketPDA,
         lender: lender1.publicKey,
         tokenToLend: usdcTokenMint,
         //@ts-ignore
         lenderAta: lender1ATAaddress.address,
-        allWhitelistedTokens: globalWhitelistedTokensPDA,
         tokenEscrow: tokenEscrowPDA,
         tokenVault: tokenVaultAddress,
         lenderPosition: lender1PositionPDA,
@@ -518,11 +1687,11 @@ describe("lendana", () => {
     await program.methods
       .lendToken(new BN(600 * 10 ** 6), lender2LoanTerms)
       .accounts({
+        market: marketPDA,
         lender: lender2.publicKey,
         tokenToLend: usdcTokenMint,
         //@ts-ignore
         lenderAta: lender2ATAaddress.address,
-        allWhitelistedTokens: globalWhitelistedTokensPDA,
         tokenEscrow: tokenEscrowPDA,
         tokenVault: tokenVaultAddress,
         lenderPosition: lender2PositionPDA,
@@ -584,6 +1753,69 @@ describe("lendana", () => {
     expect(Number(tokenVaultData.amount)).to.eq(1050 * 10 ** 6);
   });
 
+  it("TEST 7B: ------------------- ACCOUNTS CARRY THEIR LAYOUT VERSION AND MIGRATE ONLY ONCE   ---------------------", async () => {
+    const [lender1PositionPDA, lender1PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
+          lender1.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    const lender1PositionData = await program.account.lenderPosition.fetch(
+      lender1PositionPDA
+    );
+    expect(lender1PositionData.version).to.eq(1);
+    const usdcTokenConfigData = await program.account.tokenConfig.fetch(
+      usdcTokenConfigPDA
+    );
+    expect(usdcTokenConfigData.version).to.eq(1);
+
+    // A Position Created On The Current Layout Has Nothing To Migrate
+    try {
+      await program.methods
+        .migrateAccount({ lenderPosition: {} })
+        .accounts({
+          payer: lender1.publicKey,
+          migratedAccount: lender1PositionPDA,
+        })
+        .signers([lender1])
+        .rpc();
+      expect.fail("A current account cannot be migrated");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("AccountAlreadyMigrated");
+    }
+
+    // The Account Must Be Of The Type It Is Migrated As
+    try {
+      await program.methods
+        .migrateAccount({ tokenConfig: {} })
+        .accounts({
+          payer: lender1.publicKey,
+          migratedAccount: lender1PositionPDA,
+        })
+        .signers([lender1])
+        .rpc();
+      expect.fail("A lender position cannot be migrated as a token config");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal(
+        "AccountDiscriminatorMismatch"
+      );
+    }
+  });
+
   it("TEST 8:  UNHAPPY SCENARIO  ------------- LENDER2 TRIES TO LEND A NON-WHITELISTED TOKEN (daiTokenMint) SHOULD FAIL   ---------", async () => {
     const lender2ATAaddress = await getOrCreateAssociatedTokenAccount(
       provider.connection,
@@ -603,20 +1835,18 @@ describe("lendana", () => {
     );
 
     // Get PDAs
-    const [globalWhitelistedTokensPDA, globalWhitelistedTokensBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("all_whitelisted_tokens")],
-        program.programId
-      );
-
     const [lenderPositionCounterPDA, lenderPositionCounterBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("lenders_position_id_counter")],
+        [Buffer.from("lenders_position_id_counter"), marketPDA.toBuffer()],
         program.programId
       );
 
     const [tokenEscrowPDA, tokenEscrowBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("token_escrow"), daiTokenMint.toBuffer()],
+      [
+        Buffer.from("token_escrow"),
+        marketPDA.toBuffer(),
+        daiTokenMint.toBuffer(),
+      ],
       program.programId
     );
 
@@ -632,6 +1862,7 @@ describe("lendana", () => {
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
           lender2.publicKey.toBuffer(),
           daiTokenMint.toBuffer(),
         ],
@@ -648,11 +1879,11 @@ describe("lendana", () => {
       await program.methods
         .lendToken(new BN(700 * 10 ** 9), loanTerms)
         .accounts({
+          market: marketPDA,
           lender: lender2.publicKey,
           tokenToLend: daiTokenMint,
           //@ts-ignore
           lenderAta: lender2ATAaddress.address,
-          allWhitelistedTokens: globalWhitelistedTokensPDA,
           tokenEscrow: tokenEscrowPDA,
           tokenVault: tokenVaultAddress,
           lenderPosition: lender2PositionPDA,
@@ -672,7 +1903,11 @@ describe("lendana", () => {
   it("TEST 9: ------------  LENDER1 TRIES TO MODIFY HIS LENDING POSITION  --------------", async () => {
     // Get Required Accounts
     const [tokenEscrowPDA, tokenEscrowBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("token_escrow"), usdcTokenMint.toBuffer()],
+      [
+        Buffer.from("token_escrow"),
+        marketPDA.toBuffer(),
+        usdcTokenMint.toBuffer(),
+      ],
       program.programId
     );
 
@@ -688,6 +1923,7 @@ describe("lendana", () => {
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
           lender1.publicKey.toBuffer(),
           usdcTokenMint.toBuffer(),
         ],
@@ -711,6 +1947,7 @@ describe("lendana", () => {
     await program.methods
       .updateLenderPosition(newLoanTerms, new BN(35 * 10 ** 6))
       .accounts({
+        market: marketPDA,
         lender: lender1.publicKey,
         tokenToLend: usdcTokenMint,
         //@ts-ignore
@@ -759,11 +1996,13 @@ describe("lendana", () => {
     expect(Number(tokenVaultData.amount)).to.eq(1085 * 10 ** 6);
   });
 
-  // -------------------- CANCEL A LENDING ORDER TESTINGS -------------------------------
-  it("TEST 10: -------------- LENDER2 CANCELS HIS LENDING ORDER  --------------", async () => {
-    // Get Required Accounts
+  it("TEST 9B: ------------  PAUSER BLOCKS NEW LENDS PROTOCOL-WIDE AND PER TOKEN  --------------", async () => {
     const [tokenEscrowPDA, tokenEscrowBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("token_escrow"), usdcTokenMint.toBuffer()],
+      [
+        Buffer.from("token_escrow"),
+        marketPDA.toBuffer(),
+        usdcTokenMint.toBuffer(),
+      ],
       program.programId
     );
 
@@ -775,36 +2014,329 @@ describe("lendana", () => {
       ASSOCIATED_TOKEN_PROGRAM_ID
     );
 
-    const lender2ATAaddress = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      lender2,
-      usdcTokenMint,
-      lender2.publicKey
-    );
-    // Let's Check Lender2 balance prior to cancelling the order
-    const lender2ATADataBeforeCancel = await getAccount(
-      provider.connection,
-      lender2ATAaddress.address
-    );
-    expect(Number(lender2ATADataBeforeCancel.amount)).to.eq(100 * 10 ** 6);
-
-    const [lender2PositionPDA, lender2PositionBump] =
+    const [lender1PositionPDA, lender1PositionBump] =
       PublicKey.findProgramAddressSync(
         [
           Buffer.from("lender_position"),
-          lender2.publicKey.toBuffer(),
+          marketPDA.toBuffer(),
+          lender1.publicKey.toBuffer(),
           usdcTokenMint.toBuffer(),
         ],
         program.programId
       );
 
-    // Let's call the Cancel Order Instruction
-    await program.methods
-      .cancelLendOrder()
-      .accounts({
-        lender: lender2.publicKey,
-        tokenToLend: usdcTokenMint,
-        //@ts-ignore
+    const lender1ATAaddress = getAssociatedTokenAddressSync(
+      usdcTokenMint,
+      lender1.publicKey
+    );
+
+    const loanTerms = {
+      interestRate: new BN(700),
+      lendingDuration: new BN(15552000),
+    };
+
+    const topUpLending = () =>
+      program.methods
+        .updateLenderPosition(loanTerms, new BN(5 * 10 ** 6))
+        .accounts({
+          market: marketPDA,
+          lender: lender1.publicKey,
+          tokenToLend: usdcTokenMint,
+          //@ts-ignore
+          lenderAta: lender1ATAaddress,
+          tokenEscrow: tokenEscrowPDA,
+          tokenVault: tokenVaultAddress,
+          lenderPosition: lender1PositionPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([lender1])
+        .rpc();
+
+    // Admin Grants The Pauser Role
+    await program.methods
+      .grantRole({ pauser: {} }, riskManager.publicKey)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          grantRole: { role: { pauser: {} }, member: riskManager.publicKey },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+
+    // Only A Pauser Can Pause
+    try {
+      await program.methods
+        .setProtocolPause(true)
+        .accounts({
+          pauser: lender1.publicKey,
+        })
+        .signers([lender1])
+        .rpc();
+      expect.fail("Non-pauser should not pause the protocol");
+    } catch (err) {
+      expect(err.toString()).to.include("OnlyPauser");
+    }
+
+    // Protocol-Wide Pause Blocks Topping Up A Lend
+    await program.methods
+      .setProtocolPause(true)
+      .accounts({
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    try {
+      await topUpLending();
+      expect.fail("Lending should be blocked while the protocol is paused");
+    } catch (err) {
+      expect(err.toString()).to.include("ProtocolPaused");
+    }
+
+    await program.methods
+      .setProtocolPause(false)
+      .accounts({
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    // Pausing USDC Alone Blocks Lending USDC
+    await program.methods
+      .setTokenPause(usdcTokenMint, true)
+      .accounts({
+        market: marketPDA,
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    let tokenEscrowData = await program.account.lentBorrowedTokenEscrow.fetch(
+      tokenEscrowPDA
+    );
+    expect(tokenEscrowData.isActive).to.be.false;
+
+    try {
+      await topUpLending();
+      expect.fail("Lending should be blocked while the token is paused");
+    } catch (err) {
+      expect(err.toString()).to.include("TokenPaused");
+    }
+
+    await program.methods
+      .setTokenPause(usdcTokenMint, false)
+      .accounts({
+        market: marketPDA,
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    tokenEscrowData = await program.account.lentBorrowedTokenEscrow.fetch(
+      tokenEscrowPDA
+    );
+    expect(tokenEscrowData.isActive).to.be.true;
+    expect(tokenEscrowData.totalLentTokens.toNumber()).to.eq(1085 * 10 ** 6);
+  });
+
+  it("TEST 9C: ------------  RISK MANAGER AND PAUSER CHANGES ARE EMITTED AS PROGRAM EVENTS  --------------", async () => {
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const usdcRiskParameters = (
+      await program.account.tokenConfig.fetch(usdcTokenConfigPDA)
+    ).riskParameters;
+
+    const riskConfigSignature = await program.methods
+      .setRiskConfig(usdcTokenMint, usdcRiskParameters)
+      .accounts({
+        market: marketPDA,
+        riskManagerRole: riskManager.publicKey,
+        mintToken: usdcTokenMint,
+        timelockProposal: await queueProposal(riskManager, {
+          setRiskConfig: {
+            market: marketPDA,
+            tokenMint: usdcTokenMint,
+            riskParameters: usdcRiskParameters,
+          },
+        }),
+      })
+      .signers([riskManager])
+      .rpc();
+
+    const isolateSignature = await program.methods
+      .setIsolationMode(usdcTokenMint, new BN(1_000 * 10 ** 6))
+      .accounts({
+        market: marketPDA,
+        riskManagerRole: riskManager.publicKey,
+        timelockProposal: await queueProposal(riskManager, {
+          setIsolationMode: {
+            market: marketPDA,
+            tokenMint: usdcTokenMint,
+            isolationDebtCeiling: new BN(1_000 * 10 ** 6),
+          },
+        }),
+      })
+      .signers([riskManager])
+      .rpc();
+
+    const unisolateSignature = await program.methods
+      .setIsolationMode(usdcTokenMint, null)
+      .accounts({
+        market: marketPDA,
+        riskManagerRole: riskManager.publicKey,
+        timelockProposal: await queueProposal(riskManager, {
+          setIsolationMode: {
+            market: marketPDA,
+            tokenMint: usdcTokenMint,
+            isolationDebtCeiling: null,
+          },
+        }),
+      })
+      .signers([riskManager])
+      .rpc();
+
+    const pauseProtocolSignature = await program.methods
+      .setProtocolPause(true)
+      .accounts({
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    const unpauseProtocolSignature = await program.methods
+      .setProtocolPause(false)
+      .accounts({
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    const pauseTokenSignature = await program.methods
+      .setTokenPause(usdcTokenMint, true)
+      .accounts({
+        market: marketPDA,
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    const unpauseTokenSignature = await program.methods
+      .setTokenPause(usdcTokenMint, false)
+      .accounts({
+        market: marketPDA,
+        pauser: riskManager.publicKey,
+      })
+      .signers([riskManager])
+      .rpc();
+
+    // Each Transaction Carries Exactly One Event, Decoded From Its Self-CPI
+    const riskConfigEvents = await emittedEvents(riskConfigSignature);
+    expect(riskConfigEvents.length).to.eq(1);
+    expect(riskConfigEvents[0].name).to.eq("RiskConfigUpdated");
+    expect(riskConfigEvents[0].data.tokenMint.toBase58()).to.eq(usdcTokenMint.toBase58());
+    expect(
+      riskConfigEvents[0].data.riskParameters.maxLtvBps.toNumber()
+    ).to.eq(usdcRiskParameters.maxLtvBps.toNumber());
+
+    for (const [signature, isolationDebtCeiling] of [
+      [isolateSignature, 1_000 * 10 ** 6],
+      [unisolateSignature, null],
+    ] as const) {
+      const events = await emittedEvents(signature);
+      expect(events.length).to.eq(1);
+      expect(events[0].name).to.eq("IsolationModeUpdated");
+      expect(events[0].data.tokenMint.toBase58()).to.eq(usdcTokenMint.toBase58());
+      expect(
+        events[0].data.isolationDebtCeiling?.toNumber() ?? null
+      ).to.eq(isolationDebtCeiling);
+    }
+
+    for (const [signature, paused] of [
+      [pauseProtocolSignature, true],
+      [unpauseProtocolSignature, false],
+    ] as const) {
+      const events = await emittedEvents(signature);
+      expect(events.length).to.eq(1);
+      expect(events[0].name).to.eq("ProtocolPauseUpdated");
+      expect(events[0].data.pauser.toBase58()).to.eq(riskManager.publicKey.toBase58());
+      expect(events[0].data.paused).to.eq(paused);
+    }
+
+    for (const [signature, paused] of [
+      [pauseTokenSignature, true],
+      [unpauseTokenSignature, false],
+    ] as const) {
+      const events = await emittedEvents(signature);
+      expect(events.length).to.eq(1);
+      expect(events[0].name).to.eq("TokenPauseUpdated");
+      expect(events[0].data.pauser.toBase58()).to.eq(riskManager.publicKey.toBase58());
+      expect(events[0].data.tokenMint.toBase58()).to.eq(usdcTokenMint.toBase58());
+      expect(events[0].data.paused).to.eq(paused);
+    }
+  });
+
+  // -------------------- CANCEL A LENDING ORDER TESTINGS -------------------------------
+  it("TEST 10: -------------- LENDER2 CANCELS HIS LENDING ORDER  --------------", async () => {
+    // Get Required Accounts
+    const [tokenEscrowPDA, tokenEscrowBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("token_escrow"),
+        marketPDA.toBuffer(),
+        usdcTokenMint.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const tokenVaultAddress = getAssociatedTokenAddressSync(
+      usdcTokenMint,
+      tokenEscrowPDA,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const lender2ATAaddress = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender2,
+      usdcTokenMint,
+      lender2.publicKey
+    );
+    // Let's Check Lender2 balance prior to cancelling the order
+    const lender2ATADataBeforeCancel = await getAccount(
+      provider.connection,
+      lender2ATAaddress.address
+    );
+    expect(Number(lender2ATADataBeforeCancel.amount)).to.eq(100 * 10 ** 6);
+
+    const [lender2PositionPDA, lender2PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
+          lender2.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    // Let's call the Cancel Order Instruction
+    await program.methods
+      .cancelLendOrder()
+      .accounts({
+        market: marketPDA,
+        lender: lender2.publicKey,
+        tokenToLend: usdcTokenMint,
+        //@ts-ignore
         lenderAta: lender2ATAaddress.address,
         tokenEscrow: tokenEscrowPDA,
         tokenVault: tokenVaultAddress,
@@ -846,207 +2378,2958 @@ describe("lendana", () => {
     expect(lender2PositionData).to.eq(null);
   });
 
-  // -------------------    ADD A PRICE FEED ID FOR A WHITELISTED TOKEN      -------------------------
-  it("TEST 11:  ----------------- ADDING A PRICE FEED ID FOR USDC WHITELISTED TOKEN  ------------------", async () => {
-    const [priceFeedsRegistryPDA, priceFeedsRegistryBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("price_feed_registry")],
-        program.programId
-      );
-    // Call The Actual Instruction
-    await program.methods
-      .addPrice(usdcTokenMint, usdcTokenPriceFeedIdHex)
+  it("TEST 10B: -------------- LENDER1 FLASH BORROWS AND REPAYS USDC IN ONE TRANSACTION  --------------", async () => {
+    const [tokenEscrowPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("token_escrow"),
+        marketPDA.toBuffer(),
+        usdcTokenMint.toBuffer(),
+      ],
+      program.programId
+    );
+    const tokenVaultAddress = getAssociatedTokenAddressSync(
+      usdcTokenMint,
+      tokenEscrowPDA,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const vaultBefore = await getAccount(provider.connection, tokenVaultAddress);
+
+    const flashAmount = new BN(100 * 10 ** 6);
+    const flashBorrowIx = await program.methods
+      .flashBorrow(flashAmount)
       .accounts({
-        whitelister: whitelister.publicKey,
-        //@ts-ignore
-        tokensPriceFeedsRegistry: priceFeedsRegistryPDA,
+        market: marketPDA,
+        borrower: lender1.publicKey,
+        tokenMint: usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .signers([whitelister])
-      .rpc();
-
-    // Add the solMint too
-    await program.methods
-      .addPrice(solMint, solMintPriceFeedIdHex)
+      .instruction();
+    const flashRepayIx = await program.methods
+      .flashRepay()
       .accounts({
-        whitelister: whitelister.publicKey,
-        //@ts-ignore
-        tokensPriceFeedRegistry: priceFeedsRegistryPDA,
+        market: marketPDA,
+        borrower: lender1.publicKey,
+        tokenMint: usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .signers([whitelister])
-      .rpc();
+      .instruction();
 
-    // Get The Price Feeds Registry Data
-    const priceFeedsRegistryData =
-      await program.account.tokenPriceFeedRegistry.fetch(priceFeedsRegistryPDA);
+    // A Flash Borrow Without A Following Flash Repay Is Rejected
+    try {
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(flashBorrowIx),
+        [lender1]
+      );
+      expect.fail("Flash borrow without a repay should be rejected");
+    } catch (error) {
+      expect(error.toString()).to.include("MissingFlashRepay");
+    }
 
-    expect(priceFeedsRegistryData.tokenPriceMapping.length).to.eq(2);
-    expect(priceFeedsRegistryData.tokenPriceMapping[0].tokenMint).to.deep.equal(
-      usdcTokenMint
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(flashBorrowIx, flashRepayIx),
+      [lender1]
     );
-    expect(
-      priceFeedsRegistryData.tokenPriceMapping[0].priceFeedId.toString()
-    ).to.equal(usdcTokenPriceFeedIdHex);
-    expect(priceFeedsRegistryData.tokenPriceMapping[1].tokenMint).to.deep.equal(
-      solMint
+
+    // The Vault Gets Back Exactly What Was Borrowed; The 0.09% Fee Goes To The Treasury
+    const vaultAfter = await getAccount(provider.connection, tokenVaultAddress);
+    expect(Number(vaultAfter.amount)).to.eq(Number(vaultBefore.amount));
+
+    const tokenEscrowData = await program.account.lentBorrowedTokenEscrow.fetch(
+      tokenEscrowPDA
     );
-    expect(
-      priceFeedsRegistryData.tokenPriceMapping[1].priceFeedId.toString()
-    ).to.equal(solMintPriceFeedIdHex);
+    expect(tokenEscrowData.flashLoanAmount.toNumber()).to.eq(0);
+    expect(tokenEscrowData.treasuryFees.toNumber()).to.eq(90_000);
   });
 
-  // -----------------     BORROWING A TOKEN        ------------------------
-  it("TEST 12:  -----------------------  BORROWING A WHITELISTED USDC TOKEN WITH NATIVE SOL AS COLLATERAL   ---------", async () => {
-    // Get Required PDAs and Accounts
-    // Borrower Need To Have ATA for USDC token
-    const borrower1UsdcATAaddress = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      borrower1,
-      usdcTokenMint,
-      borrower1.publicKey
+  // -------------------    ADD A PRICE FEED ID FOR A WHITELISTED TOKEN      -------------------------
+  it("TEST 10C: -------------- LENDER2 LENDS RAW SOL, WRAPPED AND UNWRAPPED BY THE PROGRAM  --------------", async () => {
+    const [solEscrowPDA, solEscrowBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_escrow"), marketPDA.toBuffer(), solMint.toBuffer()],
+      program.programId
     );
 
-    const [globalWhitelistedTokensPDA, globalWhitelistedTokensBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("all_whitelisted_tokens")],
-        program.programId
-      );
-
-    const [BorrowerPositionCounterPDA, BorrowerPositionCounterBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("borrowers_position_id_counter")],
-        program.programId
-      );
+    const solVaultAddress = getAssociatedTokenAddressSync(
+      solMint,
+      solEscrowPDA,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
 
-    const [borrowingTokenEscrowPDA, borrowingTokenEscrowBump] =
-      PublicKey.findProgramAddressSync(
-        [Buffer.from("token_escrow"), usdcTokenMint.toBuffer()],
-        program.programId
-      );
+    // Whitelist wSOL So SOL Can Be Lent And Borrowed
+    await program.methods
+      .whitelistToken(solMint, null)
+      .accounts({
+        market: marketPDA,
+        whitelisterRole: whitelister.publicKey,
+        //@ts-ignore
+        tokenProgram: TOKEN_PROGRAM_ID,
+        mintToken: solMint,
+        tokenVault: solVaultAddress,
+        tokenEscrow: solEscrowPDA,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        timelockProposal: await queueProposal(whitelister, {
+          whitelistToken: {
+            market: marketPDA,
+            tokenMint: solMint,
+            isolationDebtCeiling: null,
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
 
-    const usdcTokenVaultAddress = getAssociatedTokenAddressSync(
-      usdcTokenMint,
-      borrowingTokenEscrowPDA,
-      true,
+    // Lender2 Holds No wSOL Account, Only Lamports
+    const lender2WsolAddress = getAssociatedTokenAddressSync(
+      solMint,
+      lender2.publicKey,
+      false,
       TOKEN_PROGRAM_ID,
       ASSOCIATED_TOKEN_PROGRAM_ID
     );
+    expect(await provider.connection.getAccountInfo(lender2WsolAddress)).to.eq(
+      null
+    );
 
-    const [borrower1PositionPDA, borrower1PositionBump] =
+    const [lender2SolPositionPDA, lender2SolPositionBump] =
       PublicKey.findProgramAddressSync(
         [
-          Buffer.from("borrower_position"),
-          borrower1.publicKey.toBuffer(),
-          usdcTokenMint.toBuffer(),
-        ],
+          Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
+          lender2.publicKey.toBuffer(),
+          solMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    await program.methods
+      .lendToken(new BN(2 * LAMPORTS_PER_SOL), {
+        interestRate: new BN(400),
+        lendingDuration: new BN(2592000),
+      })
+      .accounts({
+        market: marketPDA,
+        lender: lender2.publicKey,
+        tokenToLend: solMint,
+        //@ts-ignore
+        lenderAta: lender2WsolAddress,
+        tokenEscrow: solEscrowPDA,
+        tokenVault: solVaultAddress,
+        lenderPosition: lender2SolPositionPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([lender2])
+      .rpc();
+
+    const solVaultData = await getAccount(provider.connection, solVaultAddress);
+    expect(Number(solVaultData.amount)).to.eq(2 * LAMPORTS_PER_SOL);
+
+    // Cancelling Refunds The SOL As Lamports And Closes The wSOL Account
+    const lamportsBeforeCancel = await provider.connection.getBalance(
+      lender2.publicKey
+    );
+
+    await program.methods
+      .cancelLendOrder()
+      .accounts({
+        market: marketPDA,
+        lender: lender2.publicKey,
+        tokenToLend: solMint,
+        //@ts-ignore
+        lenderAta: lender2WsolAddress,
+        tokenEscrow: solEscrowPDA,
+        tokenVault: solVaultAddress,
+        lenderPosition: lender2SolPositionPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([lender2])
+      .rpc();
+
+    const lamportsAfterCancel = await provider.connection.getBalance(
+      lender2.publicKey
+    );
+    expect(lamportsAfterCancel - lamportsBeforeCancel).to.be.greaterThan(
+      2 * LAMPORTS_PER_SOL - 0.01 * LAMPORTS_PER_SOL
+    );
+    expect(await provider.connection.getAccountInfo(lender2WsolAddress)).to.eq(
+      null
+    );
+  });
+
+  it("TEST 11:  ----------------- ADDING A PRICE FEED ID FOR USDC WHITELISTED TOKEN  ------------------", async () => {
+    // Call The Actual Instruction
+    await program.methods
+      .addPrice(usdcTokenMint, usdcTokenPriceFeedIdHex)
+      .accounts({
+        market: marketPDA,
+        oracleManager: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          addPriceFeed: {
+            market: marketPDA,
+            tokenMint: usdcTokenMint,
+            priceFeedId: feedIdBytes(usdcTokenPriceFeedIdHex),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+
+    // Add the solMint too
+    await program.methods
+      .addPrice(solMint, solMintPriceFeedIdHex)
+      .accounts({
+        market: marketPDA,
+        oracleManager: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          addPriceFeed: {
+            market: marketPDA,
+            tokenMint: solMint,
+            priceFeedId: feedIdBytes(solMintPriceFeedIdHex),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+
+    // The Feed IDs Are Stored As Raw Bytes On Each Token's Config
+    for (const [mint, priceFeedIdHex] of [
+      [usdcTokenMint, usdcTokenPriceFeedIdHex],
+      [solMint, solMintPriceFeedIdHex],
+    ] as const) {
+      const [tokenConfigPDA, tokenConfigBump] =
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("token_config"), marketPDA.toBuffer(), mint.toBuffer()],
+          program.programId
+        );
+      const tokenConfigData = await program.account.tokenConfig.fetch(
+        tokenConfigPDA
+      );
+      expect(Buffer.from(tokenConfigData.priceFeedId).toString("hex")).to.equal(
+        priceFeedIdHex.replace(/^0x/, "")
+      );
+    }
+
+    // A Token Keeps The Feed It Was Mapped To
+    try {
+      await program.methods
+        .addPrice(usdcTokenMint, solMintPriceFeedIdHex)
+        .accounts({
+          market: marketPDA,
+          oracleManager: whitelister.publicKey,
+          timelockProposal: await queueProposal(whitelister, {
+            addPriceFeed: {
+              market: marketPDA,
+              tokenMint: usdcTokenMint,
+              priceFeedId: feedIdBytes(solMintPriceFeedIdHex),
+            },
+          }),
+        })
+        .signers([whitelister])
+        .rpc();
+      expect.fail("A mapped token cannot be mapped again");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("TokenPriceAlreadyExists");
+    }
+  });
+
+  it("TEST 11B: ----------------- ORACLE MANAGER MIGRATES AND CLEARS PRICE FEEDS  ------------------", async () => {
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [solTokenConfigPDA, solTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("token_config"), marketPDA.toBuffer(), solMint.toBuffer()],
+        program.programId
+      );
+
+    // A Malformed Feed ID Is Rejected When Stored
+    try {
+      await program.methods
+        .updatePrice(usdcTokenMint, "0xnot-a-feed")
+        .accounts({
+          market: marketPDA,
+          oracleManager: whitelister.publicKey,
+          timelockProposal: await queueProposal(whitelister, {
+            updatePriceFeed: {
+              market: marketPDA,
+              tokenMint: usdcTokenMint,
+              priceFeedId: feedIdBytes(usdcTokenPriceFeedIdHex),
+            },
+          }),
+        })
+        .signers([whitelister])
+        .rpc();
+      expect.fail("A malformed feed ID should be rejected");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidPriceFeedId");
+    }
+
+    // Migrate USDC To Another Feed And Back
+    for (const priceFeedIdHex of [solMintPriceFeedIdHex, usdcTokenPriceFeedIdHex]) {
+      await program.methods
+        .updatePrice(usdcTokenMint, priceFeedIdHex)
+        .accounts({
+          market: marketPDA,
+          oracleManager: whitelister.publicKey,
+          timelockProposal: await queueProposal(whitelister, {
+            updatePriceFeed: {
+              market: marketPDA,
+              tokenMint: usdcTokenMint,
+              priceFeedId: feedIdBytes(priceFeedIdHex),
+            },
+          }),
+        })
+        .signers([whitelister])
+        .rpc();
+
+      const usdcTokenConfigData = await program.account.tokenConfig.fetch(
+        usdcTokenConfigPDA
+      );
+      expect(
+        Buffer.from(usdcTokenConfigData.priceFeedId).toString("hex")
+      ).to.equal(priceFeedIdHex.replace(/^0x/, ""));
+    }
+
+    // Clear The SOL Feed, Then Map It Again
+    await program.methods
+      .removePrice(solMint)
+      .accounts({
+        market: marketPDA,
+        oracleManager: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          removePriceFeed: { market: marketPDA, tokenMint: solMint },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+    const clearedSolConfigData = await program.account.tokenConfig.fetch(
+      solTokenConfigPDA
+    );
+    expect(clearedSolConfigData.priceFeedId).to.be.null;
+
+    try {
+      await program.methods
+        .removePrice(solMint)
+        .accounts({
+          market: marketPDA,
+          oracleManager: whitelister.publicKey,
+          timelockProposal: await queueProposal(whitelister, {
+            removePriceFeed: { market: marketPDA, tokenMint: solMint },
+          }),
+        })
+        .signers([whitelister])
+        .rpc();
+      expect.fail("A token without a feed has nothing to remove");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("PriceFeedIDNotFound");
+    }
+
+    await program.methods
+      .addPrice(solMint, solMintPriceFeedIdHex)
+      .accounts({
+        market: marketPDA,
+        oracleManager: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          addPriceFeed: {
+            market: marketPDA,
+            tokenMint: solMint,
+            priceFeedId: feedIdBytes(solMintPriceFeedIdHex),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+
+    // Only The Oracle Manager May Change Feeds
+    try {
+      await program.methods
+        .removePrice(usdcTokenMint)
+        .accounts({
+          market: marketPDA,
+          oracleManager: lender1.publicKey,
+          timelockProposal: await queueProposal(whitelister, {
+            removePriceFeed: { market: marketPDA, tokenMint: usdcTokenMint },
+          }),
+        })
+        .signers([lender1])
+        .rpc();
+      expect.fail("Only the oracle manager can remove a feed");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("UnauthorizedPriceUpdater");
+    }
+  });
+
+  // -----------------     BORROWING A TOKEN        ------------------------
+  it("TEST 11C: ----------------- TOKEN HOLDERS VOTE A NEW COLLATERAL IN THROUGH THE TIMELOCK  ------------------", async () => {
+    const governanceMint = await createMint(
+      provider.connection,
+      whitelister,
+      whitelister.publicKey,
+      null,
+      6
+    );
+    const lender1GovernanceATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender1,
+      governanceMint,
+      lender1.publicKey
+    );
+    const lender2GovernanceATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender2,
+      governanceMint,
+      lender2.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      lender1,
+      governanceMint,
+      lender1GovernanceATA.address,
+      whitelister.publicKey,
+      1_000 * 10 ** 6,
+      [whitelister]
+    );
+    await mintTo(
+      provider.connection,
+      lender2,
+      governanceMint,
+      lender2GovernanceATA.address,
+      whitelister.publicKey,
+      200 * 10 ** 6,
+      [whitelister]
+    );
+
+    const [listingGovernancePDA, listingGovernanceBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("listing_governance")],
+        program.programId
+      );
+    const [governanceSignerPDA, governanceSignerBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("listing_governance_signer")],
+        program.programId
+      );
+
+    // Quorum Of 500 Tokens, Voting Open For Two Seconds
+    const quorum = new BN(500 * 10 ** 6);
+    const votingPeriod = new BN(2);
+    await program.methods
+      .configureListingGovernance(quorum, votingPeriod)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        governanceMint,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          configureListingGovernance: { governanceMint, quorum, votingPeriod },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+
+    // The Governance Signer Makes Listings Like Any Other Role Member, And Pays For Them
+    for (const role of [
+      { whitelister: {} },
+      { oracleManager: {} },
+      { riskManager: {} },
+    ]) {
+      await program.methods
+        .grantRole(role, governanceSignerPDA)
+        .accounts({
+          admin: lendanaAdmin.publicKey,
+          timelockProposal: await queueProposal(lendanaAdmin, {
+            grantRole: { role, member: governanceSignerPDA },
+          }),
+        })
+        .signers([lendanaAdmin])
+        .rpc();
+    }
+    await airdropSol(provider, governanceSignerPDA, 2);
+
+    // Lender1 Proposes Listing A New Token
+    const listedMint = await createMint(
+      provider.connection,
+      whitelister,
+      whitelister.publicKey,
+      null,
+      9
+    );
+    const listedRiskParameters = {
+      maxLtvBps: new BN(6000),
+      liquidationThresholdBps: new BN(7000),
+      liquidationBonusBps: new BN(500),
+      supplyCap: new BN(0),
+      borrowCap: new BN(0),
+      borrowableInIsolation: false,
+      reserveFactorBps: new BN(1000),
+      originationFeeBps: new BN(0),
+    };
+    const governanceData = await program.account.listingGovernance.fetch(
+      listingGovernancePDA
+    );
+    const [listingProposalPDA, listingProposalBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("listing_proposal"),
+          governanceData.nextListingId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    await program.methods
+      .proposeListing(solMintPriceFeedIdHex, listedRiskParameters, null)
+      .accounts({
+        market: marketPDA,
+        proposer: lender1.publicKey,
+        mintToken: listedMint,
+        //@ts-ignore
+        listingProposal: listingProposalPDA,
+      })
+      .signers([lender1])
+      .rpc();
+
+    // Lender1 Votes For With 1000 Tokens, Lender2 Against With 200
+    for (const [voter, voterATA, support, amount] of [
+      [lender1, lender1GovernanceATA.address, true, 1_000 * 10 ** 6],
+      [lender2, lender2GovernanceATA.address, false, 200 * 10 ** 6],
+    ] as [Keypair, PublicKey, boolean, number][]) {
+      await program.methods
+        .voteOnListing(support, new BN(amount))
+        .accounts({
+          voter: voter.publicKey,
+          voterTokenAccount: voterATA,
+          listingProposal: listingProposalPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([voter])
+        .rpc();
+    }
+
+    const votedListingData = await program.account.listingProposal.fetch(
+      listingProposalPDA
+    );
+    expect(votedListingData.votesFor.toNumber()).to.eq(1_000 * 10 ** 6);
+    expect(votedListingData.votesAgainst.toNumber()).to.eq(200 * 10 ** 6);
+
+    // The Timelock Proposals Are Created At The Timelock's Next Three Ids
+    const [timelockPDA, timelockBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("timelock")],
+      program.programId
+    );
+    const nextProposalId = (
+      await program.account.timelock.fetch(timelockPDA)
+    ).nextProposalId;
+    let [whitelistProposal, riskProposal, priceFeedProposal] = [0, 1, 2].map(
+      (offset) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("timelock_proposal"),
+            nextProposalId.addn(offset).toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0]
+    );
+    const queueListingAccounts = {
+      executor: lender2.publicKey,
+      listingProposal: listingProposalPDA,
+      whitelistProposal,
+      riskProposal,
+      priceFeedProposal,
+    };
+
+    // Cannot Be Queued While Voting Is Open
+    try {
+      await program.methods
+        .queueListing()
+        .accounts(queueListingAccounts)
+        .signers([lender2])
+        .rpc();
+      expect.fail("A listing cannot be queued while voting is open");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("ListingVotingOpen");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    await program.methods
+      .queueListing()
+      .accounts(queueListingAccounts)
+      .signers([lender2])
+      .rpc();
+
+    const queuedListingData = await program.account.listingProposal.fetch(
+      listingProposalPDA
+    );
+    expect(queuedListingData.status).to.deep.equal({ queued: {} });
+
+    // The Whitelister Cannot Run The Governance-Queued Whitelisting Itself
+    const listedTokenConfigAddress = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_config"), marketPDA.toBuffer(), listedMint.toBuffer()],
+      program.programId
+    )[0];
+    const listedEscrowAddress = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_escrow"), marketPDA.toBuffer(), listedMint.toBuffer()],
+      program.programId
+    )[0];
+    await expectFailureWithLogs(
+      program.methods
+        .whitelistToken(listedMint, null)
+        .accounts({
+          market: marketPDA,
+          whitelisterRole: whitelister.publicKey,
+          //@ts-ignore
+          tokenConfig: listedTokenConfigAddress,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          mintToken: listedMint,
+          tokenVault: getAssociatedTokenAddressSync(
+            listedMint,
+            listedEscrowAddress,
+            true,
+            TOKEN_PROGRAM_ID
+          ),
+          tokenEscrow: listedEscrowAddress,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          timelockProposal: whitelistProposal,
+        })
+        .signers([whitelister])
+        .rpc(),
+      "GovernanceQueuedProposal"
+    );
+
+    // A Listing Whose Proposals Are Still Live Cannot Be Queued Again
+    await expectFailureWithLogs(
+      program.methods
+        .queueListing()
+        .accounts({
+          ...queueListingAccounts,
+          staleProposal: whitelistProposal,
+        })
+        .signers([lender2])
+        .rpc(),
+      "ListingProposalsLive"
+    );
+
+    // Once One Is Cancelled, Anyone Queues The Listing Again As Three New Proposals
+    await program.methods
+      .cancelProposal()
+      .accounts({
+        canceller: lendanaAdmin.publicKey,
+        timelockProposal: whitelistProposal,
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+    const requeuedProposalId = (
+      await program.account.timelock.fetch(timelockPDA)
+    ).nextProposalId;
+    const staleProposal = whitelistProposal;
+    [whitelistProposal, riskProposal, priceFeedProposal] = [0, 1, 2].map(
+      (offset) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("timelock_proposal"),
+            requeuedProposalId.addn(offset).toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0]
+    );
+    await program.methods
+      .queueListing()
+      .accounts({
+        executor: lender2.publicKey,
+        listingProposal: listingProposalPDA,
+        whitelistProposal,
+        riskProposal,
+        priceFeedProposal,
+        staleProposal,
+      })
+      .signers([lender2])
+      .rpc();
+    const requeuedListingData = await program.account.listingProposal.fetch(
+      listingProposalPDA
+    );
+    expect(
+      requeuedListingData.timelockProposals.map((key) => key.toBase58())
+    ).to.deep.equal(
+      [whitelistProposal, riskProposal, priceFeedProposal].map((key) =>
+        key.toBase58()
+      )
+    );
+
+    // Anyone Executes The Listing, Made By The Governance Signer Through Whitelisting, Risk And Price Feed Instructions
+    const [listedTokenConfigPDA, listedTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          listedMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [listedEscrowPDA, listedEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_escrow"),
+          marketPDA.toBuffer(),
+          listedMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [listedTreasuryPDA, listedTreasuryBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury"), marketPDA.toBuffer(), listedMint.toBuffer()],
+        program.programId
+      );
+    await program.methods
+      .executeListing()
+      .accounts({
+        market: marketPDA,
+        executor: lender2.publicKey,
+        listingProposal: listingProposalPDA,
+        mintToken: listedMint,
+        tokenConfig: listedTokenConfigPDA,
+        tokenEscrow: listedEscrowPDA,
+        tokenVault: getAssociatedTokenAddressSync(
+          listedMint,
+          listedEscrowPDA,
+          true,
+          TOKEN_PROGRAM_ID
+        ),
+        treasury: listedTreasuryPDA,
+        treasuryVault: getAssociatedTokenAddressSync(
+          listedMint,
+          listedTreasuryPDA,
+          true,
+          TOKEN_PROGRAM_ID
+        ),
+        whitelistProposal,
+        riskProposal,
+        priceFeedProposal,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([lender2])
+      .rpc();
+
+    const listedTokenConfigData = await program.account.tokenConfig.fetch(
+      listedTokenConfigPDA
+    );
+    expect(listedTokenConfigData.isWhitelisted).to.be.true;
+    expect(listedTokenConfigData.priceFeedId).to.deep.equal(
+      feedIdBytes(solMintPriceFeedIdHex)
+    );
+    expect(listedTokenConfigData.riskParameters.maxLtvBps.toNumber()).to.eq(
+      6000
+    );
+
+    // Voters Take Back Their Locked Tokens
+    await program.methods
+      .withdrawListingVote()
+      .accounts({
+        voter: lender1.publicKey,
+        voterTokenAccount: lender1GovernanceATA.address,
+        listingProposal: listingProposalPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([lender1])
+      .rpc();
+
+    const lender1GovernanceAccount = await getAccount(
+      provider.connection,
+      lender1GovernanceATA.address
+    );
+    expect(Number(lender1GovernanceAccount.amount)).to.eq(1_000 * 10 ** 6);
+  });
+
+  it("TEST 11D: ----------------- ANYONE OPENS A CURATED MARKET, RUN BY ITS CURATOR ALONE  ------------------", async () => {
+    const curator = Keypair.generate();
+    await airdropSol(provider, curator.publicKey, 2);
+
+    const curatedMarketId = new BN(1);
+    const [curatedMarketPDA, curatedMarketBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("curated_market"),
+          curatedMarketId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+    // Only The Program's Supported Loan Durations Can Be Offered
+    try {
+      await program.methods
+        .createCuratedMarket(curatedMarketId, "Curated Market", curator.publicKey, [
+          new BN(86400),
+        ])
+        .accounts({ creator: borrower3.publicKey })
+        .signers([borrower3])
+        .rpc();
+      expect.fail("An unsupported loan duration should be rejected");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("InvalidLendingDurations");
+    }
+
+    // Borrower3 Opens It, Posting The 1 SOL Creation Deposit
+    await program.methods
+      .createCuratedMarket(curatedMarketId, "Curated Market", curator.publicKey, [
+        new BN(2592000),
+      ])
+      .accounts({ creator: borrower3.publicKey })
+      .signers([borrower3])
+      .rpc();
+
+    const curatedMarketData = await program.account.market.fetch(
+      curatedMarketPDA
+    );
+    expect(curatedMarketData.creator).to.deep.equal(borrower3.publicKey);
+    expect(curatedMarketData.curator).to.deep.equal(curator.publicKey);
+    expect(curatedMarketData.lendingDurations.map((d) => d.toNumber())).to.deep.equal([
+      2592000,
+    ]);
+    expect(curatedMarketData.creationDeposit.toNumber()).to.eq(LAMPORTS_PER_SOL);
+    const marketBalance = await provider.connection.getBalance(curatedMarketPDA);
+    expect(marketBalance).to.be.greaterThan(LAMPORTS_PER_SOL);
+
+    // The Protocol's Whitelister Cannot Propose For The Curated Market
+    const daiListing = {
+      whitelistToken: {
+        market: curatedMarketPDA,
+        tokenMint: daiTokenMint,
+        isolationDebtCeiling: null,
+      },
+    };
+    try {
+      await queueProposal(whitelister, daiListing, curatedMarketPDA);
+      expect.fail("A role holder should not propose for a curated market");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("NotAllowedToPropose");
+    }
+
+    // Nor Execute A Change It Queued Without The Market
+    try {
+      await program.methods
+        .whitelistToken(daiTokenMint, null)
+        .accounts({
+          market: curatedMarketPDA,
+          whitelisterRole: whitelister.publicKey,
+          mintToken: daiTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          timelockProposal: await queueProposal(whitelister, daiListing),
+        })
+        .signers([whitelister])
+        .rpc();
+      expect.fail("A role holder should not whitelist into a curated market");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("OnlyWhitelister");
+    }
+
+    // The Curator Lists DAI In Its Market Through The Timelock
+    await program.methods
+      .whitelistToken(daiTokenMint, null)
+      .accounts({
+        market: curatedMarketPDA,
+        whitelisterRole: curator.publicKey,
+        mintToken: daiTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        timelockProposal: await queueProposal(
+          curator,
+          daiListing,
+          curatedMarketPDA
+        ),
+      })
+      .signers([curator])
+      .rpc();
+
+    const [curatedDaiConfigPDA, curatedDaiConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          curatedMarketPDA.toBuffer(),
+          daiTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const curatedDaiConfigData = await program.account.tokenConfig.fetch(
+      curatedDaiConfigPDA
+    );
+    expect(curatedDaiConfigData.market).to.deep.equal(curatedMarketPDA);
+    expect(curatedDaiConfigData.isWhitelisted).to.be.true;
+    expect(
+      (await program.account.market.fetch(curatedMarketPDA)).listedTokens
+    ).to.eq(1);
+
+    // A Market With A Listed Token Cannot Be Closed
+    await expectFailureWithLogs(
+      program.methods
+        .closeCuratedMarket()
+        .accounts({ creator: borrower3.publicKey, market: curatedMarketPDA })
+        .signers([borrower3])
+        .rpc(),
+      "MarketHasListedTokens"
+    );
+
+    // Listing Governance Only Lists Into Markets Run By The Protocol's Roles
+    try {
+      await program.methods
+        .proposeListing(
+          solMintPriceFeedIdHex,
+          {
+            maxLtvBps: new BN(7000),
+            liquidationThresholdBps: new BN(7500),
+            liquidationBonusBps: new BN(500),
+            supplyCap: new BN(1_000),
+            borrowCap: new BN(500),
+            borrowableInIsolation: false,
+            reserveFactorBps: new BN(1000),
+            originationFeeBps: new BN(10),
+          },
+          null
+        )
+        .accounts({
+          market: curatedMarketPDA,
+          proposer: lender1.publicKey,
+          mintToken: ethTokenMint,
+        })
+        .signers([lender1])
+        .rpc();
+      expect.fail("Listing governance should not list into a curated market");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("CuratedMarketListing");
+    }
+
+    // Only The Curator Hands The Market Over
+    try {
+      await program.methods
+        .setMarketCurator(borrower3.publicKey)
+        .accounts({ curator: borrower3.publicKey, market: curatedMarketPDA })
+        .signers([borrower3])
+        .rpc();
+      expect.fail("Only the curator should hand the market over");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.eq("NotMarketCurator");
+    }
+    await program.methods
+      .setMarketCurator(borrower3.publicKey)
+      .accounts({ curator: curator.publicKey, market: curatedMarketPDA })
+      .signers([curator])
+      .rpc();
+    expect(
+      (await program.account.market.fetch(curatedMarketPDA)).curator
+    ).to.deep.equal(borrower3.publicKey);
+
+    // The New Curator Delists DAI; Anything Left In Its Vault Goes To The Curator, Not The Protocol Admin
+    await program.methods
+      .delistToken(daiTokenMint, null, new BN(0))
+      .accounts({
+        market: curatedMarketPDA,
+        whitelisterRole: borrower3.publicKey,
+        timelockProposal: await queueProposal(
+          borrower3,
+          {
+            delistToken: {
+              market: curatedMarketPDA,
+              tokenMint: daiTokenMint,
+              targetMaxLtvBps: null,
+              windDownDuration: new BN(0),
+            },
+          },
+          curatedMarketPDA
+        ),
+      })
+      .signers([borrower3])
+      .rpc();
+
+    const [curatedDaiEscrowPDA, curatedDaiEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_escrow"),
+          curatedMarketPDA.toBuffer(),
+          daiTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    await mintTo(
+      provider.connection,
+      whitelister,
+      daiTokenMint,
+      getAssociatedTokenAddressSync(daiTokenMint, curatedDaiEscrowPDA, true),
+      whitelister,
+      7
+    );
+    const adminDaiATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      whitelister,
+      daiTokenMint,
+      lendanaAdmin.publicKey
+    );
+    const curatorDaiATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower3,
+      daiTokenMint,
+      borrower3.publicKey
+    );
+
+    const finalizeDaiDelisting = (leftoversTokenAccount: PublicKey) =>
+      program.methods
+        .finalizeDelisting()
+        .accounts({
+          market: curatedMarketPDA,
+          whitelisterRole: borrower3.publicKey,
+          mintToken: daiTokenMint,
+          leftoversTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([borrower3])
+        .rpc();
+
+    await expectFailureWithLogs(
+      finalizeDaiDelisting(adminDaiATA.address),
+      "ConstraintTokenOwner"
+    );
+    const finalizeSignature = await finalizeDaiDelisting(curatorDaiATA.address);
+
+    expect(
+      Number((await getAccount(provider.connection, curatorDaiATA.address)).amount)
+    ).to.eq(7);
+    expect(
+      Number((await getAccount(provider.connection, adminDaiATA.address)).amount)
+    ).to.eq(0);
+    const [finalizedEvent] = await emittedEvents(finalizeSignature);
+    expect(finalizedEvent.name).to.eq("TokenDelistingFinalized");
+    expect(finalizedEvent.data.sweptAmount.toNumber()).to.eq(7);
+    expect(finalizedEvent.data.sweptTo).to.deep.equal(borrower3.publicKey);
+    expect(
+      (await program.account.market.fetch(curatedMarketPDA)).listedTokens
+    ).to.eq(0);
+
+    // A Market That Never Lists A Token Is Closed By Its Creator Alone, Refunding The Deposit
+    const emptyMarketId = new BN(2);
+    const [emptyMarketPDA, emptyMarketBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("curated_market"), emptyMarketId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .createCuratedMarket(emptyMarketId, "Empty Market", curator.publicKey, [
+        new BN(2592000),
+      ])
+      .accounts({ creator: borrower3.publicKey })
+      .signers([borrower3])
+      .rpc();
+
+    await expectFailureWithLogs(
+      program.methods
+        .closeCuratedMarket()
+        .accounts({ creator: curator.publicKey, market: emptyMarketPDA })
+        .signers([curator])
+        .rpc(),
+      "NotMarketCreator"
+    );
+
+    const creatorBalanceBefore = await provider.connection.getBalance(
+      borrower3.publicKey
+    );
+    await program.methods
+      .closeCuratedMarket()
+      .accounts({ creator: borrower3.publicKey, market: emptyMarketPDA })
+      .signers([borrower3])
+      .rpc();
+    const creatorBalanceAfter = await provider.connection.getBalance(
+      borrower3.publicKey
+    );
+    expect(creatorBalanceAfter - creatorBalanceBefore).to.be.closeTo(
+      LAMPORTS_PER_SOL,
+      10_000
+    );
+
+    // The Market Stays Behind As A Closed Tombstone, Its Deposit Paid Out Once
+    const closedMarketData = await program.account.market.fetch(emptyMarketPDA);
+    expect(closedMarketData.isClosed).to.be.true;
+    expect(closedMarketData.creationDeposit.toNumber()).to.eq(0);
+    await expectFailureWithLogs(
+      program.methods
+        .closeCuratedMarket()
+        .accounts({ creator: borrower3.publicKey, market: emptyMarketPDA })
+        .signers([borrower3])
+        .rpc(),
+      "MarketClosed"
+    );
+
+    // Nobody Can Reopen The Closed Id And Name Themselves Curator Of What It Still Seeds
+    await expectFailureWithLogs(
+      program.methods
+        .createCuratedMarket(emptyMarketId, "Reopened Market", lender1.publicKey, [
+          new BN(2592000),
+        ])
+        .accounts({ creator: lender1.publicKey })
+        .signers([lender1])
+        .rpc(),
+      "already in use"
+    );
+
+    // Nor Can Its Curator List Into It Again
+    const closedMarketListing = {
+      whitelistToken: {
+        market: emptyMarketPDA,
+        tokenMint: daiTokenMint,
+        isolationDebtCeiling: null,
+      },
+    };
+    await expectFailureWithLogs(
+      program.methods
+        .whitelistToken(daiTokenMint, null)
+        .accounts({
+          market: emptyMarketPDA,
+          whitelisterRole: curator.publicKey,
+          mintToken: daiTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          timelockProposal: await queueProposal(
+            curator,
+            closedMarketListing,
+            emptyMarketPDA
+          ),
+        })
+        .signers([curator])
+        .rpc(),
+      "MarketClosed"
+    );
+  });
+
+  it("TEST 12:  -----------------------  BORROWING A WHITELISTED USDC TOKEN WITH NATIVE SOL AS COLLATERAL   ---------", async () => {
+    // Get Required PDAs and Accounts
+    // Borrower Need To Have ATA for USDC token
+    const borrower1UsdcATAaddress = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower1,
+      usdcTokenMint,
+      borrower1.publicKey
+    );
+
+    const [BorrowerPositionCounterPDA, BorrowerPositionCounterBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("borrowers_position_id_counter"), marketPDA.toBuffer()],
+        program.programId
+      );
+
+    const [borrowingTokenEscrowPDA, borrowingTokenEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_escrow"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    const usdcTokenVaultAddress = getAssociatedTokenAddressSync(
+      usdcTokenMint,
+      borrowingTokenEscrowPDA,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const [borrower1PositionPDA, borrower1PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("borrower_position"),
+          marketPDA.toBuffer(),
+          borrower1.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    // SOL Collateral Is Held In An Escrow Of Its Own For Each Position
+    const [solCollateralEscrowPDA, solCollateralEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("sol_collateral"), borrower1PositionPDA.toBuffer()],
+        program.programId
+      );
+
+    // Call The Borrow Token Instruction
+    const borrowingLoanTerms = {
+      interestRate: new BN(700),
+      lendingDuration: new BN(15552000),
+    };
+
+    //Collateral And Borrowing Token Price Updates
+    const borrowingPriceUpdate = (
+      await priceServiceConnection.getLatestPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        { encoding: "base64" }
+      )
+    ).binary.data;
+
+    const collateralPriceUpdate = (
+      await priceServiceConnection.getLatestPriceUpdates(
+        [solMintPriceFeedIdHex],
+        { encoding: "base64" }
+      )
+    ).binary.data;
+
+    // All Price Updates
+    const allPriceUpdates = [...borrowingPriceUpdate, ...collateralPriceUpdate];
+
+
+    // Post Price Update To Solana
+    const pythSolanaReceiver = new PythSolanaReceiver({
+      connection: provider.connection,
+
+      wallet: provider.wallet as any,
+    });
+    // Create transaction builder
+    const transactionBuilder = pythSolanaReceiver.newTransactionBuilder({
+      closeUpdateAccounts: false,
+    });
+    // Add Both Price Updates To The Builder
+    await transactionBuilder.addPostPriceUpdates(allPriceUpdates);
+
+    // Add Borrow instruction to the same transaction
+    await transactionBuilder.addPriceConsumerInstructions(
+      async (getPriceUpdateAccount) => {
+        const borrowingPriceAccount = getPriceUpdateAccount(
+          usdcTokenPriceFeedIdHex
+        );
+        const collateralPriceAccount = getPriceUpdateAccount(
+          solMintPriceFeedIdHex
+        );
+
+        // Create your instruction with these accounts
+        const borrowTokenIx = await program.methods
+          .borrowToken(solMint, usdcTokenMint, new BN(200), borrowingLoanTerms)
+          .accounts({
+            market: marketPDA,
+            borrower: borrower1.publicKey,
+            tokenToBorrow: usdcTokenMint,
+            tokenCollateral: solMint,
+            collateralPriceUpdate: collateralPriceAccount,
+            borrowingPriceUpdate: borrowingPriceAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            //@ts-ignore
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            pythProgram: DEFAULT_RECEIVER_PROGRAM_ID,
+            solCollateralEscrow: solCollateralEscrowPDA,
+          })
+          .signers([borrower1])
+          .instruction();
+
+        return [
+          {
+            instruction: borrowTokenIx,
+            signers: [borrower1],
+          },
+        ];
+      }
+    );
+
+    // Build the transactions with explicit compute budget
+const transactions = await transactionBuilder.buildVersionedTransactions({
+  computeUnitPriceMicroLamports: 100000,  // Higher value from example
+  tightComputeBudget: true,               // Added from example
+});
+    // Send the transactions
+    try {
+  console.log(`Sending ${transactions.length} transaction(s)...`);
+  await pythSolanaReceiver.provider.sendAll(
+    transactions,
+    { skipPreflight: false }
+  );
+  console.log("Transactions sent successfully");
+} catch (error) {
+  console.error("Transaction error:", error);
+  if (error.logs) {
+    console.error("Transaction logs:", error.logs);
+  }
+  throw error;
+}
+  });
+
+  it("TEST 12B: ---------- CANCELLING A SOL-COLLATERAL ORDER LEAVES THE WSOL LENDERS' TOTAL UNTOUCHED ----------", async () => {
+    const [borrower1PositionPDA, borrower1PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("borrower_position"),
+          marketPDA.toBuffer(),
+          borrower1.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [solCollateralEscrowPDA, solCollateralEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("sol_collateral"), borrower1PositionPDA.toBuffer()],
+        program.programId
+      );
+    const [solTokenEscrowPDA, solTokenEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_escrow"),
+          marketPDA.toBuffer(),
+          solMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    // The Collateral ATA Is Part Of The Accounts Even Though SOL Comes Back Unwrapped
+    await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower1,
+      solMint,
+      borrower1.publicKey
+    );
+
+    const solEscrowBefore =
+      await program.account.lentBorrowedTokenEscrow.fetch(solTokenEscrowPDA);
+
+    await sendWithPriceUpdates(
+      [solMintPriceFeedIdHex, usdcTokenPriceFeedIdHex],
+      async ([collateralPriceAccount, borrowingPriceAccount]) => [
+        await program.methods
+          .cancelBorrowOrder()
+          .accounts({
+            market: marketPDA,
+            borrower: borrower1.publicKey,
+            tokenToBorrow: usdcTokenMint,
+            tokenCollateral: solMint,
+            collateralPriceUpdate: collateralPriceAccount,
+            borrowingPriceUpdate: borrowingPriceAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            //@ts-ignore
+            solCollateralEscrow: solCollateralEscrowPDA,
+          })
+          .instruction(),
+      ],
+      [borrower1]
+    );
+
+    // The Position And Its SOL Escrow Are Closed, And wSOL Lenders Keep Their Total
+    expect(
+      await provider.connection.getAccountInfo(borrower1PositionPDA)
+    ).to.eq(null);
+    expect(
+      await provider.connection.getAccountInfo(solCollateralEscrowPDA)
+    ).to.eq(null);
+    const solEscrowAfter =
+      await program.account.lentBorrowedTokenEscrow.fetch(solTokenEscrowPDA);
+    expect(solEscrowAfter.totalLentTokens.toString()).to.eq(
+      solEscrowBefore.totalLentTokens.toString()
+    );
+  });
+
+  it("TEST 12C: ---------- E-MODE LENDS A HIGHER LTV ONLY TO TOKENS IN THE SAME CATEGORY ----------", async () => {
+    // A Second Stablecoin, Priced Off The USDC Feed, That Borrower2 Posts As Collateral
+    stableTokenMint = await createMint(
+      provider.connection,
+      whitelister,
+      whitelister.publicKey,
+      null,
+      6
+    );
+    const [borrower2PositionPDA, borrower2PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("borrower_position"),
+          marketPDA.toBuffer(),
+          borrower2.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    await program.methods
+      .whitelistToken(stableTokenMint, null)
+      .accounts({
+        market: marketPDA,
+        whitelisterRole: whitelister.publicKey,
+        mintToken: stableTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        timelockProposal: await queueProposal(whitelister, {
+          whitelistToken: {
+            market: marketPDA,
+            tokenMint: stableTokenMint,
+            isolationDebtCeiling: null,
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+    await program.methods
+      .addPrice(stableTokenMint, usdcTokenPriceFeedIdHex)
+      .accounts({
+        market: marketPDA,
+        oracleManager: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          addPriceFeed: {
+            market: marketPDA,
+            tokenMint: stableTokenMint,
+            priceFeedId: feedIdBytes(usdcTokenPriceFeedIdHex),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+    await setRiskParameters(stableTokenMint, {
+      maxLtvBps: new BN(7000),
+      liquidationThresholdBps: new BN(7500),
+      liquidationBonusBps: new BN(500),
+      supplyCap: new BN(0),
+      borrowCap: new BN(0),
+      borrowableInIsolation: true,
+      reserveFactorBps: new BN(1000),
+      originationFeeBps: new BN(0),
+    });
+
+    // The Risk Manager Sets Up The E-Mode Categories
+    async function setEModeCategory(categoryId: number, name: string, emodeParameters, tokenMints: PublicKey[]) {
+      const [emodeCategoryPDA, emodeCategoryBump] =
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("emode_category"),
+            marketPDA.toBuffer(),
+            Buffer.from([categoryId]),
+          ],
+          program.programId
+        );
+      await program.methods
+        .setEmodeCategory(categoryId, name, emodeParameters, tokenMints)
+        .accounts({
+          market: marketPDA,
+          riskManagerRole: riskManager.publicKey,
+          //@ts-ignore
+          emodeCategory: emodeCategoryPDA,
+          timelockProposal: await queueProposal(riskManager, {
+            setEModeCategory: {
+              market: marketPDA,
+              categoryId,
+              name,
+              emodeParameters,
+              tokenMints,
+            },
+          }),
+        })
+        .signers([riskManager])
+        .rpc();
+      return emodeCategoryPDA;
+    }
+
+    const emodeParameters = {
+      maxLtvBps: new BN(9000),
+      liquidationThresholdBps: new BN(9300),
+      liquidationBonusBps: new BN(300),
+    };
+    const usdcDaiCategoryPDA = await setEModeCategory(1, "usdc-dai", emodeParameters, [usdcTokenMint, daiTokenMint]);
+    const stablecoinsCategoryPDA = await setEModeCategory(2, "stablecoins", emodeParameters, [stableTokenMint, usdcTokenMint]);
+
+    const emodeCategoryData = await program.account.eModeCategory.fetch(stablecoinsCategoryPDA);
+    expect(emodeCategoryData.categoryId).to.eq(2);
+    expect(emodeCategoryData.name).to.eq("stablecoins");
+    expect(emodeCategoryData.emodeParameters.maxLtvBps.toNumber()).to.eq(9000);
+
+    // Borrower2 Holds 100 Of The Stablecoin And A USDC Account To Receive The Loan
+    const borrower2StableATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower2,
+      stableTokenMint,
+      borrower2.publicKey
+    );
+    const borrower2UsdcATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower2,
+      usdcTokenMint,
+      borrower2.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      borrower2,
+      stableTokenMint,
+      borrower2StableATA.address,
+      whitelister.publicKey,
+      100 * 10 ** 6,
+      [whitelister]
+    );
+
+    // Cancelling Refunds The Full Borrowed Amount, So Borrower2 Also Needs To Cover The Origination Fee
+    await mintTo(
+      provider.connection,
+      borrower2,
+      usdcTokenMint,
+      borrower2UsdcATA.address,
+      whitelister.publicKey,
+      1 * 10 ** 6,
+      [whitelister]
+    );
+
+    const borrowingAmount = new BN(10 * 10 ** 6);
+    const borrowingLoanTerms = {
+      interestRate: new BN(700),
+      lendingDuration: new BN(15552000),
+    };
+
+    const borrowUsdcAgainstStable = (emodeCategory: PublicKey | null) =>
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([priceAccount]) => [
+          await program.methods
+            .borrowToken(stableTokenMint, usdcTokenMint, borrowingAmount, borrowingLoanTerms)
+            .accounts({
+              market: marketPDA,
+              borrower: borrower2.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenCollateral: stableTokenMint,
+              collateralPriceUpdate: priceAccount,
+              borrowingPriceUpdate: priceAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              emodeCategory,
+              solCollateralEscrow: null,
+            })
+            .instruction(),
+        ],
+        [borrower2]
+      );
+
+    const cancelBorrowOrder = () =>
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([priceAccount]) => [
+          await program.methods
+            .cancelBorrowOrder()
+            .accounts({
+              market: marketPDA,
+              borrower: borrower2.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenCollateral: stableTokenMint,
+              collateralPriceUpdate: priceAccount,
+              borrowingPriceUpdate: priceAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              solCollateralEscrow: null,
+            })
+            .instruction(),
+        ],
+        [borrower2]
+      );
+
+    // The New Stablecoin Is Not In The USDC/DAI Category, So That One Cannot Be Opted Into
+    await expectFailureWithLogs(
+      borrowUsdcAgainstStable(usdcDaiCategoryPDA),
+      "NotInEModeCategory"
+    );
+
+    // Outside E-Mode The Collateral Is Sized To The Stablecoin's Own 70% Max LTV
+    await borrowUsdcAgainstStable(null);
+    const basePosition = await program.account.borrowerPosition.fetch(borrower2PositionPDA);
+    expect(basePosition.emodeCategory).to.eq(0);
+    await cancelBorrowOrder();
+
+    // Inside The Stablecoins Category It Is Sized To The Category's 90%, So Less Collateral Is Locked
+    await borrowUsdcAgainstStable(stablecoinsCategoryPDA);
+    const emodePosition = await program.account.borrowerPosition.fetch(borrower2PositionPDA);
+    expect(emodePosition.emodeCategory).to.eq(2);
+    expect(emodePosition.collateralAmount.lt(basePosition.collateralAmount)).to.eq(true);
+
+    // Both Orders Are Priced Moments Apart, So Their Collateral Scales By 7000 / 9000 Within A Small Margin
+    const expectedCollateral = basePosition.collateralAmount.toNumber() * 7000 / 9000;
+    expect(emodePosition.collateralAmount.toNumber()).to.be.closeTo(expectedCollateral, expectedCollateral * 0.02);
+
+    // A Position Opened In E-Mode Is Only Modified With Its Own Category
+    await expectFailureWithLogs(
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([priceAccount]) => [
+          await program.methods
+            .updateBorrowerPosition(borrowingLoanTerms, new BN(1 * 10 ** 6))
+            .accounts({
+              market: marketPDA,
+              borrower: borrower2.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenCollateral: stableTokenMint,
+              collateralPriceUpdate: priceAccount,
+              borrowingPriceUpdate: priceAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              emodeCategory: null,
+              solCollateralEscrow: null,
+            })
+            .instruction(),
+        ],
+        [borrower2]
+      ),
+      "EModeCategoryMismatch"
+    );
+
+    await cancelBorrowOrder();
+    expect(
+      await provider.connection.getAccountInfo(borrower2PositionPDA)
+    ).to.eq(null);
+  });
+
+  it("TEST 12D: ---------- AN ISOLATED COLLATERAL BACKS ONLY ISOLATION-BORROWABLE TOKENS, UP TO ITS DEBT CEILING ----------", async () => {
+    // A Long-Tail Token Listed In Isolation, Priced Off The SOL Feed
+    const isolatedMint = await createMint(
+      provider.connection,
+      whitelister,
+      whitelister.publicKey,
+      null,
+      9
+    );
+    const [isolatedEscrowPDA, isolatedEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_escrow"),
+          marketPDA.toBuffer(),
+          isolatedMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [borrower3PositionPDA, borrower3PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("borrower_position"),
+          marketPDA.toBuffer(),
+          borrower3.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+
+    // Listed With A 5 USD Debt Ceiling
+    await program.methods
+      .whitelistToken(isolatedMint, new BN(5 * 10 ** 6))
+      .accounts({
+        market: marketPDA,
+        whitelisterRole: whitelister.publicKey,
+        mintToken: isolatedMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        timelockProposal: await queueProposal(whitelister, {
+          whitelistToken: {
+            market: marketPDA,
+            tokenMint: isolatedMint,
+            isolationDebtCeiling: new BN(5 * 10 ** 6),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+    await program.methods
+      .addPrice(isolatedMint, solMintPriceFeedIdHex)
+      .accounts({
+        market: marketPDA,
+        oracleManager: whitelister.publicKey,
+        timelockProposal: await queueProposal(whitelister, {
+          addPriceFeed: {
+            market: marketPDA,
+            tokenMint: isolatedMint,
+            priceFeedId: feedIdBytes(solMintPriceFeedIdHex),
+          },
+        }),
+      })
+      .signers([whitelister])
+      .rpc();
+    await setRiskParameters(isolatedMint, {
+      maxLtvBps: new BN(5000),
+      liquidationThresholdBps: new BN(6000),
+      liquidationBonusBps: new BN(1000),
+      supplyCap: new BN(0),
+      borrowCap: new BN(0),
+      borrowableInIsolation: false,
+      reserveFactorBps: new BN(1000),
+      originationFeeBps: new BN(0),
+    });
+
+    let isolatedEscrowData =
+      await program.account.lentBorrowedTokenEscrow.fetch(isolatedEscrowPDA);
+    expect(isolatedEscrowData.isolationMode).to.be.true;
+    expect(isolatedEscrowData.isolationDebtCeiling.toNumber()).to.eq(
+      5 * 10 ** 6
+    );
+    expect(isolatedEscrowData.isolationDebt.toNumber()).to.eq(0);
+
+    // Borrower3 Holds 100 Of The Isolated Token And A USDC Account To Receive The Loan
+    const borrower3UsdcATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower3,
+      usdcTokenMint,
+      borrower3.publicKey
+    );
+    const borrower3IsolatedATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower3,
+      isolatedMint,
+      borrower3.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      borrower3,
+      isolatedMint,
+      borrower3IsolatedATA.address,
+      whitelister.publicKey,
+      100 * 10 ** 9,
+      [whitelister]
+    );
+
+    // Cancelling Refunds The Full Borrowed Amount, So Borrower3 Also Needs To Cover The Origination Fee
+    await mintTo(
+      provider.connection,
+      borrower3,
+      usdcTokenMint,
+      borrower3UsdcATA.address,
+      whitelister.publicKey,
+      1 * 10 ** 6,
+      [whitelister]
+    );
+
+    const borrowingLoanTerms = {
+      interestRate: new BN(700),
+      lendingDuration: new BN(15552000),
+    };
+
+    const borrowUsdcAgainstIsolated = (borrowingAmount: BN) =>
+      sendWithPriceUpdates(
+        [solMintPriceFeedIdHex, usdcTokenPriceFeedIdHex],
+        async ([collateralPriceAccount, borrowingPriceAccount]) => [
+          await program.methods
+            .borrowToken(
+              isolatedMint,
+              usdcTokenMint,
+              borrowingAmount,
+              borrowingLoanTerms
+            )
+            .accounts({
+              market: marketPDA,
+              borrower: borrower3.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenCollateral: isolatedMint,
+              collateralPriceUpdate: collateralPriceAccount,
+              borrowingPriceUpdate: borrowingPriceAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              solCollateralEscrow: null,
+            })
+            .instruction(),
+        ],
+        [borrower3]
+      );
+
+    // About 10 USD Of USDC Would Take The Isolated Debt Over The Ceiling
+    await expectFailureWithLogs(
+      borrowUsdcAgainstIsolated(new BN(10 * 10 ** 6)),
+      "IsolationDebtCeilingExceeded"
+    );
+
+    // A Token Not Borrowable In Isolation Cannot Be Borrowed Against It At All, However Small The Borrow
+    const usdcRiskParameters = (
+      await program.account.tokenConfig.fetch(usdcTokenConfigPDA)
+    ).riskParameters;
+    await setRiskParameters(usdcTokenMint, {
+      ...usdcRiskParameters,
+      borrowableInIsolation: false,
+    });
+    await expectFailureWithLogs(
+      borrowUsdcAgainstIsolated(new BN(2 * 10 ** 6)),
+      "NotBorrowableInIsolation"
+    );
+    await setRiskParameters(usdcTokenMint, usdcRiskParameters);
+
+    // Within The Ceiling The Borrow Goes Through And Its USD Value Counts Towards The Isolated Debt
+    await borrowUsdcAgainstIsolated(new BN(2 * 10 ** 6));
+    const borrower3Position = await program.account.borrowerPosition.fetch(
+      borrower3PositionPDA
+    );
+    isolatedEscrowData =
+      await program.account.lentBorrowedTokenEscrow.fetch(isolatedEscrowPDA);
+    expect(borrower3Position.isolatedDebt.toNumber()).to.be.greaterThan(0);
+    expect(isolatedEscrowData.isolationDebt.toString()).to.eq(
+      borrower3Position.isolatedDebt.toString()
+    );
+
+    // Cancelling The Order Releases Its Share Of The Ceiling
+    await sendWithPriceUpdates(
+      [solMintPriceFeedIdHex, usdcTokenPriceFeedIdHex],
+      async ([collateralPriceAccount, borrowingPriceAccount]) => [
+        await program.methods
+          .cancelBorrowOrder()
+          .accounts({
+            market: marketPDA,
+            borrower: borrower3.publicKey,
+            tokenToBorrow: usdcTokenMint,
+            tokenCollateral: isolatedMint,
+            collateralPriceUpdate: collateralPriceAccount,
+            borrowingPriceUpdate: borrowingPriceAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            //@ts-ignore
+            solCollateralEscrow: null,
+          })
+          .instruction(),
+      ],
+      [borrower3]
+    );
+    isolatedEscrowData =
+      await program.account.lentBorrowedTokenEscrow.fetch(isolatedEscrowPDA);
+    expect(isolatedEscrowData.isolationDebt.toNumber()).to.eq(0);
+
+    // Lifting Isolation Leaves The Token Free To Back Any Borrow
+    await program.methods
+      .setIsolationMode(isolatedMint, null)
+      .accounts({
+        market: marketPDA,
+        riskManagerRole: riskManager.publicKey,
+        timelockProposal: await queueProposal(riskManager, {
+          setIsolationMode: {
+            market: marketPDA,
+            tokenMint: isolatedMint,
+            isolationDebtCeiling: null,
+          },
+        }),
+      })
+      .signers([riskManager])
+      .rpc();
+    isolatedEscrowData =
+      await program.account.lentBorrowedTokenEscrow.fetch(isolatedEscrowPDA);
+    expect(isolatedEscrowData.isolationMode).to.be.false;
+    expect(isolatedEscrowData.isolationDebtCeiling.toNumber()).to.eq(0);
+  });
+
+  it("TEST 12E: ---------- AN UNHEALTHY SOL-COLLATERAL ORDER IS LIQUIDATED FOR ITS DEBT PLUS THE BONUS ----------", async () => {
+    const [borrower1PositionPDA, borrower1PositionBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("borrower_position"),
+          marketPDA.toBuffer(),
+          borrower1.publicKey.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [solCollateralEscrowPDA, solCollateralEscrowBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("sol_collateral"), borrower1PositionPDA.toBuffer()],
+        program.programId
+      );
+    const [solTokenConfigPDA, solTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("token_config"), marketPDA.toBuffer(), solMint.toBuffer()],
+        program.programId
+      );
+
+    // Borrower1 Borrows 10 USDC Against SOL
+    await sendWithPriceUpdates(
+      [solMintPriceFeedIdHex, usdcTokenPriceFeedIdHex],
+      async ([collateralPriceAccount, borrowingPriceAccount]) => [
+        await program.methods
+          .borrowToken(solMint, usdcTokenMint, new BN(10 * 10 ** 6), {
+            interestRate: new BN(700),
+            lendingDuration: new BN(15552000),
+          })
+          .accounts({
+            market: marketPDA,
+            borrower: borrower1.publicKey,
+            tokenToBorrow: usdcTokenMint,
+            tokenCollateral: solMint,
+            collateralPriceUpdate: collateralPriceAccount,
+            borrowingPriceUpdate: borrowingPriceAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            //@ts-ignore
+            solCollateralEscrow: solCollateralEscrowPDA,
+          })
+          .instruction(),
+      ],
+      [borrower1]
+    );
+    const borrower1Position =
+      await program.account.borrowerPosition.fetch(borrower1PositionPDA);
+
+    // Lender1 Liquidates, Repaying From Its USDC And Receiving SOL; Its wSOL ATA Is Set Up Beforehand
+    const lender1UsdcATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender1,
+      usdcTokenMint,
+      lender1.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      lender1,
+      usdcTokenMint,
+      lender1UsdcATA.address,
+      whitelister.publicKey,
+      20 * 10 ** 6,
+      [whitelister]
+    );
+    await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender1,
+      solMint,
+      lender1.publicKey
+    );
+
+    const liquidatePosition = () =>
+      sendWithPriceUpdates(
+        [solMintPriceFeedIdHex, usdcTokenPriceFeedIdHex],
+        async ([collateralPriceAccount, borrowingPriceAccount]) => [
+          await program.methods
+            .liquidatePosition()
+            .accounts({
+              liquidator: lender1.publicKey,
+              market: marketPDA,
+              borrower: borrower1.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenCollateral: solMint,
+              collateralPriceUpdate: collateralPriceAccount,
+              borrowingPriceUpdate: borrowingPriceAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              solCollateralEscrow: solCollateralEscrowPDA,
+            })
+            .instruction(),
+        ],
+        [lender1]
+      );
+
+    // Sized At SOL's 70% Max LTV, The Order Sits Above Its 75% Liquidation Threshold
+    await expectFailureWithLogs(liquidatePosition(), "PositionNotLiquidatable");
+
+    // Live Pyth Prices Cannot Be Moved Here, So SOL's Threshold Is Cut Instead, Leaving The Same Collateral Below It
+    const solRiskParameters = (
+      await program.account.tokenConfig.fetch(solTokenConfigPDA)
+    ).riskParameters;
+    await setRiskParameters(solMint, {
+      ...solRiskParameters,
+      maxLtvBps: new BN(100),
+      liquidationThresholdBps: new BN(200),
+    });
+
+    const liquidatorBalanceBefore = await provider.connection.getBalance(
+      lender1.publicKey
+    );
+    const signatures = await liquidatePosition();
+    const liquidatorBalanceAfter = await provider.connection.getBalance(
+      lender1.publicKey
+    );
+    await setRiskParameters(solMint, solRiskParameters);
+
+    const [liquidation] = await emittedEvents(signatures[signatures.length - 1]);
+    expect(liquidation.name).to.eq("PositionLiquidated");
+    expect(liquidation.data.debtRepaid.toNumber()).to.be.at.least(10 * 10 ** 6);
+
+    // Seized Collateral Is Worth The Repaid Debt Times (1 + 8% Bonus), At The Prices The Program Used
+    const usdValue = (amount: number, decimals: number, price) =>
+      (amount / 10 ** decimals) * price.price.toNumber() * 10 ** price.exponent;
+    const seizedValue = usdValue(liquidation.data.collateralSeized.toNumber(), 9, liquidation.data.collateralPrice);
+    const expectedSeizedValue =
+      usdValue(liquidation.data.debtRepaid.toNumber(), 6, liquidation.data.borrowingPrice) *
+      (1 + solRiskParameters.liquidationBonusBps.toNumber() / 10_000);
+    expect(seizedValue).to.be.closeTo(expectedSeizedValue, expectedSeizedValue * 1e-6);
+    expect(liquidation.data.collateralSeized.toNumber()).to.be.lessThan(
+      borrower1Position.collateralAmount.toNumber()
+    );
+
+    // The Seized SOL Reaches The Liquidator, And The Position And Its Escrow Are Closed To The Borrower
+    expect(liquidatorBalanceAfter - liquidatorBalanceBefore).to.eq(
+      liquidation.data.collateralSeized.toNumber()
+    );
+    expect(
+      await provider.connection.getAccountInfo(borrower1PositionPDA)
+    ).to.eq(null);
+    expect(
+      await provider.connection.getAccountInfo(solCollateralEscrowPDA)
+    ).to.eq(null);
+  });
+
+  it("TEST 13: ---------- BORROWER2 BORROWS USDC AGAINST AN OBLIGATION, KEPT WITHIN ITS BORROW LIMIT ----------", async () => {
+    const [obligationPDA, obligationBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("obligation"),
+        marketPDA.toBuffer(),
+        borrower2.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [stableTokenConfigPDA, stableTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          stableTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    // Each Deposit Is Priced From A [Token Config, Price Update, Mint] Triple
+    const stableHealthAccounts = (stablePriceAccount: PublicKey) =>
+      [stableTokenConfigPDA, stablePriceAccount, stableTokenMint].map(
+        (pubkey) => ({ pubkey, isSigner: false, isWritable: false })
+      );
+    const loanTerms = {
+      interestRate: new BN(700),
+      lendingDuration: new BN(15552000),
+    };
+
+    // Borrower2 Deposits The 100 Stablecoins From TEST 12C Into A New Obligation
+    await program.methods
+      .depositCollateral(new BN(100 * 10 ** 6))
+      .accounts({
+        market: marketPDA,
+        borrower: borrower2.publicKey,
+        tokenCollateral: stableTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        //@ts-ignore
+        solCollateralEscrow: null,
+      })
+      .signers([borrower2])
+      .rpc();
+
+    const depositedObligation = await program.account.borrowerObligation.fetch(
+      obligationPDA
+    );
+    expect(depositedObligation.collateralDeposits.length).to.eq(1);
+    expect(depositedObligation.collateralDeposits[0].amount.toNumber()).to.eq(
+      100 * 10 ** 6
+    );
+
+    // The Stablecoin Shares The USDC Feed, So One Price Update Values Both Sides
+    const borrowFromObligation = (amount: BN, terms: typeof loanTerms) =>
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([usdcPriceAccount]) => [
+          await program.methods
+            .borrowFromObligation(amount, terms)
+            .accounts({
+              market: marketPDA,
+              borrower: borrower2.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              borrowingPriceUpdate: usdcPriceAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              isolatedCollateralEscrow: null,
+            })
+            .remainingAccounts(stableHealthAccounts(usdcPriceAccount))
+            .instruction(),
+        ],
+        [borrower2]
+      );
+
+    // 20 USDC Against 100 Stablecoins Fits Well Within Their 70% Max LTV
+    await borrowFromObligation(new BN(20 * 10 ** 6), loanTerms);
+    const borrowedObligation = await program.account.borrowerObligation.fetch(
+      obligationPDA
+    );
+    expect(borrowedObligation.borrowingAmount.toNumber()).to.eq(20 * 10 ** 6);
+    expect(borrowedObligation.borrowingToken).to.deep.equal(usdcTokenMint);
+
+    // Topping Up Under Other Terms Is Rejected
+    await expectFailureWithLogs(
+      borrowFromObligation(new BN(10 ** 6), {
+        interestRate: new BN(500),
+        lendingDuration: new BN(7776000),
+      }),
+      "LoanTermsMismatch"
+    );
+
+    // A Borrow Taking The Debt Past The Basket's Borrow Limit Is Rejected
+    await expectFailureWithLogs(
+      borrowFromObligation(new BN(60 * 10 ** 6), loanTerms),
+      "ObligationBorrowLimitExceeded"
+    );
+
+    // Topping Up Under The Same Terms Keeps The Loan's Start
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await borrowFromObligation(new BN(5 * 10 ** 6), loanTerms);
+    const toppedUpObligation = await program.account.borrowerObligation.fetch(
+      obligationPDA
+    );
+    expect(toppedUpObligation.borrowingAmount.toNumber()).to.eq(25 * 10 ** 6);
+    expect(toppedUpObligation.borrowingStart.toNumber()).to.eq(
+      borrowedObligation.borrowingStart.toNumber()
+    );
+
+    // Withdrawing Almost All The Collateral Would Leave The Debt Uncovered
+    const withdrawFromObligation = (amount: BN) =>
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([usdcPriceAccount]) => [
+          await program.methods
+            .withdrawCollateral(amount)
+            .accounts({
+              market: marketPDA,
+              borrower: borrower2.publicKey,
+              tokenCollateral: stableTokenMint,
+              borrowingPriceUpdate: usdcPriceAccount,
+              tokenToBorrow: usdcTokenMint,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              borrowingTokenConfig: usdcTokenConfigPDA,
+              solCollateralEscrow: null,
+            })
+            .remainingAccounts(stableHealthAccounts(usdcPriceAccount))
+            .instruction(),
+        ],
+        [borrower2]
+      );
+    await expectFailureWithLogs(
+      withdrawFromObligation(new BN(95 * 10 ** 6)),
+      "ObligationBorrowLimitExceeded"
+    );
+
+    // A Small Withdrawal Keeps The Obligation Healthy
+    await withdrawFromObligation(new BN(10 * 10 ** 6));
+    expect(
+      (await program.account.borrowerObligation.fetch(obligationPDA))
+        .collateralDeposits[0].amount.toNumber()
+    ).to.eq(90 * 10 ** 6);
+
+    // Part Of The Debt Is Repaid, Interest First
+    await program.methods
+      .repayObligation(new BN(5 * 10 ** 6))
+      .accounts({
+        market: marketPDA,
+        borrower: borrower2.publicKey,
+        tokenToBorrow: usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        //@ts-ignore
+        isolatedCollateralEscrow: null,
+      })
+      .signers([borrower2])
+      .rpc();
+    const repaidObligation = await program.account.borrowerObligation.fetch(
+      obligationPDA
+    );
+    expect(repaidObligation.borrowingAmount.toNumber()).to.be.lessThan(
+      25 * 10 ** 6
+    );
+    expect(repaidObligation.borrowingAmount.toNumber()).to.be.greaterThan(
+      20 * 10 ** 6 - 1
+    );
+
+    const liquidateObligation = () =>
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([usdcPriceAccount]) => [
+          await program.methods
+            .liquidateObligationPosition(new BN(10 * 10 ** 6))
+            .accounts({
+              market: marketPDA,
+              liquidator: lender1.publicKey,
+              borrower: borrower2.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenCollateral: stableTokenMint,
+              borrowingPriceUpdate: usdcPriceAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              solCollateralEscrow: null,
+            })
+            .remainingAccounts(stableHealthAccounts(usdcPriceAccount))
+            .instruction(),
+        ],
+        [lender1]
+      );
+
+    // A Healthy Obligation Cannot Be Liquidated
+    await expectFailureWithLogs(
+      liquidateObligation(),
+      "PositionNotLiquidatable"
+    );
+
+    // Once The Stablecoin's Liquidation Threshold Is Cut, Lender1 Repays 10 USDC And Seizes Collateral Worth That Plus The Bonus
+    const stableRiskParameters = (
+      await program.account.tokenConfig.fetch(stableTokenConfigPDA)
+    ).riskParameters;
+    await setRiskParameters(stableTokenMint, {
+      ...stableRiskParameters,
+      maxLtvBps: new BN(100),
+      liquidationThresholdBps: new BN(200),
+    });
+    await liquidateObligation();
+    const liquidatedObligation =
+      await program.account.borrowerObligation.fetch(obligationPDA);
+    expect(liquidatedObligation.borrowingAmount.toNumber()).to.be.lessThan(
+      repaidObligation.borrowingAmount.toNumber()
+    );
+    expect(
+      liquidatedObligation.collateralDeposits[0].amount.toNumber()
+    ).to.be.lessThan(repaidObligation.collateralDeposits[0].amount.toNumber());
+
+    await setRiskParameters(stableTokenMint, stableRiskParameters);
+  });
+
+  it("TEST 13B: ---------- BORROWER3 BORROWS USDC FROM A MARGIN ACCOUNT, KEPT WITHIN ITS BORROW LIMIT ----------", async () => {
+    const [marginAccountPDA, marginAccountBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("margin_account"),
+          marketPDA.toBuffer(),
+          borrower3.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+    const [stableTokenConfigPDA, stableTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          stableTokenMint.toBuffer(),
+        ],
+        program.programId
+      );
+    // A [Token Config, Price Update, Mint] Triple Per Deposit, Then A [Price Update, Mint] Pair Per Borrow
+    const marginHealthAccounts = (usdcPriceAccount: PublicKey) =>
+      [
+        stableTokenConfigPDA,
+        usdcPriceAccount,
+        stableTokenMint,
+        usdcPriceAccount,
+        usdcTokenMint,
+      ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }));
+    const loanTerms = {
+      interestRate: new BN(300),
+      lendingDuration: new BN(2592000),
+    };
+
+    // Borrower3 Deposits 100 Of The Stablecoin From TEST 12C
+    const borrower3StableATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower3,
+      stableTokenMint,
+      borrower3.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      borrower3,
+      stableTokenMint,
+      borrower3StableATA.address,
+      whitelister.publicKey,
+      100 * 10 ** 6,
+      [whitelister]
+    );
+
+    await program.methods
+      .marginDeposit(new BN(100 * 10 ** 6))
+      .accounts({
+        market: marketPDA,
+        borrower: borrower3.publicKey,
+        tokenCollateral: stableTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        //@ts-ignore
+        solCollateralEscrow: null,
+      })
+      .signers([borrower3])
+      .rpc();
+
+    const borrowFromMargin = (amount: BN, terms: typeof loanTerms) =>
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([usdcPriceAccount]) => [
+          await program.methods
+            .marginBorrow(amount, terms)
+            .accounts({
+              market: marketPDA,
+              borrower: borrower3.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .remainingAccounts(marginHealthAccounts(usdcPriceAccount))
+            .instruction(),
+        ],
+        [borrower3]
+      );
+
+    await borrowFromMargin(new BN(20 * 10 ** 6), loanTerms);
+    const borrowedMargin = await program.account.marginAccount.fetch(
+      marginAccountPDA
+    );
+    expect(borrowedMargin.borrows.length).to.eq(1);
+    expect(borrowedMargin.borrows[0].borrowingAmount.toNumber()).to.eq(
+      20 * 10 ** 6
+    );
+
+    // More Of The Same Token Must Be Borrowed Under The Borrow's Own Terms
+    await expectFailureWithLogs(
+      borrowFromMargin(new BN(10 ** 6), {
+        interestRate: new BN(700),
+        lendingDuration: new BN(15552000),
+      }),
+      "LoanTermsMismatch"
+    );
+
+    // All Borrows Together Must Stay Within The Basket's Borrow Limit
+    await expectFailureWithLogs(
+      borrowFromMargin(new BN(60 * 10 ** 6), loanTerms),
+      "MarginBorrowLimitExceeded"
+    );
+
+    // Borrowing More Of The Same Token Adds To The Existing Borrow And Keeps Its Start
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    await borrowFromMargin(new BN(5 * 10 ** 6), loanTerms);
+    const toppedUpMargin = await program.account.marginAccount.fetch(
+      marginAccountPDA
+    );
+    expect(toppedUpMargin.borrows.length).to.eq(1);
+    expect(toppedUpMargin.borrows[0].borrowingAmount.toNumber()).to.eq(
+      25 * 10 ** 6
+    );
+    expect(toppedUpMargin.borrows[0].borrowingStart.toNumber()).to.eq(
+      borrowedMargin.borrows[0].borrowingStart.toNumber()
+    );
+
+    // Withdrawing Almost All The Collateral Is Rejected While The Borrows Are Open
+    await expectFailureWithLogs(
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([usdcPriceAccount]) => [
+          await program.methods
+            .marginWithdraw(new BN(95 * 10 ** 6))
+            .accounts({
+              market: marketPDA,
+              borrower: borrower3.publicKey,
+              tokenCollateral: stableTokenMint,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              solCollateralEscrow: null,
+            })
+            .remainingAccounts(marginHealthAccounts(usdcPriceAccount))
+            .instruction(),
+        ],
+        [borrower3]
+      ),
+      "MarginBorrowLimitExceeded"
+    );
+
+    // Part Of The Borrow Is Repaid
+    await program.methods
+      .marginRepay(new BN(5 * 10 ** 6))
+      .accounts({
+        market: marketPDA,
+        borrower: borrower3.publicKey,
+        tokenToBorrow: usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([borrower3])
+      .rpc();
+    const repaidMargin = await program.account.marginAccount.fetch(
+      marginAccountPDA
+    );
+    expect(repaidMargin.borrows[0].borrowingAmount.toNumber()).to.be.lessThan(
+      25 * 10 ** 6
+    );
+
+    const liquidateMarginAccount = () =>
+      sendWithPriceUpdates(
+        [usdcTokenPriceFeedIdHex],
+        async ([usdcPriceAccount]) => [
+          await program.methods
+            .liquidateMarginPosition(new BN(10 * 10 ** 6))
+            .accounts({
+              market: marketPDA,
+              liquidator: lender1.publicKey,
+              borrower: borrower3.publicKey,
+              tokenToBorrow: usdcTokenMint,
+              tokenCollateral: stableTokenMint,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              //@ts-ignore
+              solCollateralEscrow: null,
+            })
+            .remainingAccounts(marginHealthAccounts(usdcPriceAccount))
+            .instruction(),
+        ],
+        [lender1]
+      );
+
+    // A Healthy Margin Account Cannot Be Liquidated
+    await expectFailureWithLogs(
+      liquidateMarginAccount(),
+      "PositionNotLiquidatable"
+    );
+
+    // Once The Stablecoin's Liquidation Threshold Is Cut, Lender1 Repays 10 USDC Of The Borrow And Seizes Collateral
+    const stableRiskParameters = (
+      await program.account.tokenConfig.fetch(stableTokenConfigPDA)
+    ).riskParameters;
+    await setRiskParameters(stableTokenMint, {
+      ...stableRiskParameters,
+      maxLtvBps: new BN(100),
+      liquidationThresholdBps: new BN(200),
+    });
+    await liquidateMarginAccount();
+    const liquidatedMargin = await program.account.marginAccount.fetch(
+      marginAccountPDA
+    );
+    expect(
+      liquidatedMargin.borrows[0].borrowingAmount.toNumber()
+    ).to.be.lessThan(repaidMargin.borrows[0].borrowingAmount.toNumber());
+    expect(
+      liquidatedMargin.collateralDeposits[0].amount.toNumber()
+    ).to.be.lessThan(repaidMargin.collateralDeposits[0].amount.toNumber());
+
+    await setRiskParameters(stableTokenMint, stableRiskParameters);
+  });
+
+  it("TEST 13C: ---------- ONLY THE TREASURER WITHDRAWS COLLECTED USDC FEES, AND NO MORE THAN WERE COLLECTED ----------", async () => {
+    const treasurer = Keypair.generate();
+    const [usdcEscrowPDA, usdcEscrowBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("token_escrow"),
+        marketPDA.toBuffer(),
+        usdcTokenMint.toBuffer(),
+      ],
+      program.programId
+    );
+    const [usdcTreasuryPDA, usdcTreasuryBump] =
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury"), marketPDA.toBuffer(), usdcTokenMint.toBuffer()],
+        program.programId
+      );
+    const usdcTreasuryVault = getAssociatedTokenAddressSync(
+      usdcTokenMint,
+      usdcTreasuryPDA,
+      true
+    );
+    const recipientATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender1,
+      usdcTokenMint,
+      treasurer.publicKey
+    );
+
+    const withdrawTreasury = (signer: Keypair, amount: BN) =>
+      program.methods
+        .withdrawTreasury(amount)
+        .accounts({
+          treasurer: signer.publicKey,
+          market: marketPDA,
+          tokenMint: usdcTokenMint,
+          recipientTokenAccount: recipientATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+
+    // Flash Loan, Origination And Reserve Fees Have Been Collected By Now
+    const collectedFees = (
+      await program.account.lentBorrowedTokenEscrow.fetch(usdcEscrowPDA)
+    ).treasuryFees;
+    expect(collectedFees.toNumber()).to.be.greaterThan(0);
+    const treasuryVaultBefore = Number(
+      (await getAccount(provider.connection, usdcTreasuryVault)).amount
+    );
+
+    // Nobody Holds The Treasurer Role Yet
+    try {
+      await withdrawTreasury(treasurer, new BN(1));
+      expect.fail("A non-treasurer should not withdraw fees");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("OnlyTreasurer");
+    }
+
+    await program.methods
+      .grantRole({ treasurer: {} }, treasurer.publicKey)
+      .accounts({
+        admin: lendanaAdmin.publicKey,
+        timelockProposal: await queueProposal(lendanaAdmin, {
+          grantRole: { role: { treasurer: {} }, member: treasurer.publicKey },
+        }),
+      })
+      .signers([lendanaAdmin])
+      .rpc();
+
+    // Withdrawals Are Bounded By The Fees Recorded, Not The Vault Balance
+    try {
+      await withdrawTreasury(treasurer, collectedFees.addn(1));
+      expect.fail("Withdrawing more than the collected fees should fail");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InsufficientTreasuryFees");
+    }
+
+    // Half The Fees Move From The Treasury Vault To The Recipient
+    const withdrawn = collectedFees.divn(2);
+    await withdrawTreasury(treasurer, withdrawn);
+
+    expect(
+      Number((await getAccount(provider.connection, recipientATA.address)).amount)
+    ).to.eq(withdrawn.toNumber());
+    expect(
+      Number((await getAccount(provider.connection, usdcTreasuryVault)).amount)
+    ).to.eq(treasuryVaultBefore - withdrawn.toNumber());
+    expect(
+      (
+        await program.account.lentBorrowedTokenEscrow.fetch(usdcEscrowPDA)
+      ).treasuryFees.toString()
+    ).to.eq(collectedFees.sub(withdrawn).toString());
+  });
+
+  it("TEST 13D: ---------- LENDER2 BORROWS RAW SOL AGAINST USDC AND REPAYS IT IN RAW SOL ----------", async () => {
+    const [solEscrowPDA, solEscrowBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_escrow"), marketPDA.toBuffer(), solMint.toBuffer()],
+      program.programId
+    );
+    const solVaultAddress = getAssociatedTokenAddressSync(
+      solMint,
+      solEscrowPDA,
+      true,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const [obligationPDA, obligationBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("obligation"),
+        marketPDA.toBuffer(),
+        lender2.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [usdcTokenConfigPDA, usdcTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          usdcTokenMint.toBuffer(),
+        ],
         program.programId
       );
+    const lender2WsolAddress = getAssociatedTokenAddressSync(
+      solMint,
+      lender2.publicKey,
+      false,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const loanTerms = {
+      interestRate: new BN(400),
+      lendingDuration: new BN(2592000),
+    };
 
-    const [priceFeedsRegistryPDA, priceFeedsRegistryBump] =
+    // Borrower1 Lends 1 Raw SOL For Lender2 To Borrow
+    const [borrower1SolPositionPDA, borrower1SolPositionBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("price_feed_registry")],
+        [
+          Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
+          borrower1.publicKey.toBuffer(),
+          solMint.toBuffer(),
+        ],
         program.programId
       );
+    await program.methods
+      .lendToken(new BN(LAMPORTS_PER_SOL), loanTerms)
+      .accounts({
+        market: marketPDA,
+        lender: borrower1.publicKey,
+        tokenToLend: solMint,
+        //@ts-ignore
+        lenderAta: getAssociatedTokenAddressSync(solMint, borrower1.publicKey),
+        tokenEscrow: solEscrowPDA,
+        tokenVault: solVaultAddress,
+        lenderPosition: borrower1SolPositionPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([borrower1])
+      .rpc();
+
+    // Lender2 Posts 100 USDC As Obligation Collateral
+    const lender2UsdcATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender2,
+      usdcTokenMint,
+      lender2.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      lender2,
+      usdcTokenMint,
+      lender2UsdcATA.address,
+      whitelister.publicKey,
+      100 * 10 ** 6,
+      [whitelister]
+    );
+    await program.methods
+      .depositCollateral(new BN(100 * 10 ** 6))
+      .accounts({
+        market: marketPDA,
+        borrower: lender2.publicKey,
+        tokenCollateral: usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        //@ts-ignore
+        solCollateralEscrow: null,
+      })
+      .signers([lender2])
+      .rpc();
+
+    // Borrowed SOL Arrives As Lamports, The wSOL Account Opened For The Transfer Closed Again
+    expect(await provider.connection.getAccountInfo(lender2WsolAddress)).to.eq(
+      null
+    );
+    const borrowedSolBefore = (
+      await program.account.lentBorrowedTokenEscrow.fetch(solEscrowPDA)
+    ).totalBorrowedTokens.toNumber();
+    const solVaultBefore = Number(
+      (await getAccount(provider.connection, solVaultAddress)).amount
+    );
+    const lamportsBeforeBorrow = await provider.connection.getBalance(
+      lender2.publicKey
+    );
+    await sendWithPriceUpdates(
+      [usdcTokenPriceFeedIdHex, solMintPriceFeedIdHex],
+      async ([usdcPriceAccount, solPriceAccount]) => [
+        await program.methods
+          .borrowFromObligation(new BN(0.1 * LAMPORTS_PER_SOL), loanTerms)
+          .accounts({
+            market: marketPDA,
+            borrower: lender2.publicKey,
+            tokenToBorrow: solMint,
+            borrowingPriceUpdate: solPriceAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            //@ts-ignore
+            isolatedCollateralEscrow: null,
+          })
+          .remainingAccounts(
+            [usdcTokenConfigPDA, usdcPriceAccount, usdcTokenMint].map(
+              (pubkey) => ({ pubkey, isSigner: false, isWritable: false })
+            )
+          )
+          .instruction(),
+      ],
+      [lender2]
+    );
+    const lamportsAfterBorrow = await provider.connection.getBalance(
+      lender2.publicKey
+    );
+    expect(lamportsAfterBorrow - lamportsBeforeBorrow).to.eq(
+      0.1 * LAMPORTS_PER_SOL
+    );
+    expect(await provider.connection.getAccountInfo(lender2WsolAddress)).to.eq(
+      null
+    );
+    expect(
+      Number((await getAccount(provider.connection, solVaultAddress)).amount)
+    ).to.eq(solVaultBefore - 0.1 * LAMPORTS_PER_SOL);
+
+    // Repaying Wraps Lamports Straight From Lender2, Capped At The Outstanding Debt
+    await program.methods
+      .repayObligation(new BN(LAMPORTS_PER_SOL))
+      .accounts({
+        market: marketPDA,
+        borrower: lender2.publicKey,
+        tokenToBorrow: solMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        //@ts-ignore
+        isolatedCollateralEscrow: null,
+      })
+      .signers([lender2])
+      .rpc();
+    const lamportsAfterRepay = await provider.connection.getBalance(
+      lender2.publicKey
+    );
+
+    const repaidObligation = await program.account.borrowerObligation.fetch(
+      obligationPDA
+    );
+    expect(repaidObligation.borrowingAmount.toNumber()).to.eq(0);
+    expect(repaidObligation.interestOwed.toNumber()).to.eq(0);
+    expect(
+      (
+        await program.account.lentBorrowedTokenEscrow.fetch(solEscrowPDA)
+      ).totalBorrowedTokens.toNumber()
+    ).to.eq(borrowedSolBefore);
+
+    // Lender2 Paid The Principal, A Little Interest And The wSOL Account's Rent, All In Lamports
+    const paid = lamportsAfterBorrow - lamportsAfterRepay;
+    expect(paid).to.be.greaterThan(0.1 * LAMPORTS_PER_SOL);
+    expect(paid).to.be.lessThan(0.11 * LAMPORTS_PER_SOL);
+    expect(
+      Number((await getAccount(provider.connection, lender2WsolAddress)).amount)
+    ).to.eq(0);
+  });
 
-    const [solCollateralVaultPDA, solCollateralVaultBump] =
+  it("TEST 13E: ---------- A TRANSFER-FEE MINT IS CREDITED TO LENDERS ONLY FOR WHAT REACHES THE VAULT ----------", async () => {
+    // 1% Transfer Fee, Capped Well Above Anything Sent Here
+    const feeMint = await createToken2022Mint(
+      [ExtensionType.TransferFeeConfig],
+      (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          whitelister.publicKey,
+          whitelister.publicKey,
+          100,
+          BigInt(1_000 * 10 ** 6),
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ]
+    );
+    await listToken(feeMint, TOKEN_2022_PROGRAM_ID, usdcTokenPriceFeedIdHex, {
+      maxLtvBps: new BN(8000),
+      liquidationThresholdBps: new BN(8500),
+      liquidationBonusBps: new BN(500),
+      supplyCap: new BN(0),
+      borrowCap: new BN(0),
+      borrowableInIsolation: false,
+      reserveFactorBps: new BN(1000),
+      originationFeeBps: new BN(0),
+    });
+
+    const [feeEscrowPDA, feeEscrowBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_escrow"), marketPDA.toBuffer(), feeMint.toBuffer()],
+      program.programId
+    );
+    const feeVaultAddress = getAssociatedTokenAddressSync(
+      feeMint,
+      feeEscrowPDA,
+      true,
+      TOKEN_2022_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const [lender1FeePositionPDA, lender1FeePositionBump] =
       PublicKey.findProgramAddressSync(
-        [Buffer.from("sol_collateral_vault")],
+        [
+          Buffer.from("lender_position"),
+          marketPDA.toBuffer(),
+          lender1.publicKey.toBuffer(),
+          feeMint.toBuffer(),
+        ],
         program.programId
       );
 
-    // Call The Borrow Token Instruction
-    const borrowingLoanTerms = {
-      interestRate: new BN(700),
-      lendingDuration: new BN(15552000),
-    };
+    const lender1FeeATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      lender1,
+      feeMint,
+      lender1.publicKey,
+      false,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      lender1,
+      feeMint,
+      lender1FeeATA.address,
+      whitelister.publicKey,
+      1_000 * 10 ** 6,
+      [whitelister],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
 
-    //Collateral And Borrowing Token Price Updates
-    const borrowingPriceUpdate = (
-      await priceServiceConnection.getLatestPriceUpdates(
-        [usdcTokenPriceFeedIdHex],
-        { encoding: "base64" }
-      )
-    ).binary.data;
+    const lendSignature = await program.methods
+      .lendToken(new BN(1_000 * 10 ** 6), {
+        interestRate: new BN(400),
+        lendingDuration: new BN(2592000),
+      })
+      .accounts({
+        market: marketPDA,
+        lender: lender1.publicKey,
+        tokenToLend: feeMint,
+        //@ts-ignore
+        lenderAta: lender1FeeATA.address,
+        tokenEscrow: feeEscrowPDA,
+        tokenVault: feeVaultAddress,
+        lenderPosition: lender1FeePositionPDA,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([lender1])
+      .rpc();
 
-    const collateralPriceUpdate = (
-      await priceServiceConnection.getLatestPriceUpdates(
-        [solMintPriceFeedIdHex],
-        { encoding: "base64" }
-      )
-    ).binary.data;
+    // The Vault Receives 990 Tokens, The Other 10 Withheld By The Mint
+    const received = 990 * 10 ** 6;
+    const feeVault = await getAccount(
+      provider.connection,
+      feeVaultAddress,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(Number(feeVault.amount)).to.eq(received);
+    expect(Number(getTransferFeeAmount(feeVault).withheldAmount)).to.eq(
+      10 * 10 ** 6
+    );
 
-    // All Price Updates
-    const allPriceUpdates = [...borrowingPriceUpdate, ...collateralPriceUpdate];
+    // Lenders Are Credited Only What Reached The Vault, So The Books Match Its Balance
+    const feeEscrow = await program.account.lentBorrowedTokenEscrow.fetch(
+      feeEscrowPDA
+    );
+    expect(feeEscrow.totalLentTokens.toNumber()).to.eq(received);
+    const lender1FeePosition = await program.account.lenderPosition.fetch(
+      lender1FeePositionPDA
+    );
+    expect(lender1FeePosition.lendingAmount.toNumber()).to.eq(received);
 
+    const lendCreated = (await emittedEvents(lendSignature)).find(
+      (event) => event.name === "LendOrderCreated"
+    );
+    expect(lendCreated.data.lendingAmount.toNumber()).to.eq(received);
+  });
 
-    // Post Price Update To Solana
-    const pythSolanaReceiver = new PythSolanaReceiver({
-      connection: provider.connection,
+  it("TEST 13F: ---------- INTEREST-BEARING COLLATERAL IS VALUED AT ITS ACCRUED SCALE, NOT ITS RAW ORACLE PRICE ----------", async () => {
+    // The Highest Rate Token-2022 Accepts, So Even A Few Seconds Of Accrual Moves The Price
+    const interestRateBps = 32_767;
+    const interestMint = await createToken2022Mint(
+      [ExtensionType.InterestBearingConfig],
+      (mint) => [
+        createInitializeInterestBearingMintInstruction(
+          mint,
+          whitelister.publicKey,
+          interestRateBps,
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ]
+    );
+    // Priced Off The USDC Feed, So The Borrowed USDC's Price Is The Same Raw Oracle Price
+    await listToken(
+      interestMint,
+      TOKEN_2022_PROGRAM_ID,
+      usdcTokenPriceFeedIdHex,
+      {
+        maxLtvBps: new BN(8000),
+        liquidationThresholdBps: new BN(8500),
+        liquidationBonusBps: new BN(500),
+        supplyCap: new BN(0),
+        borrowCap: new BN(0),
+        borrowableInIsolation: false,
+        reserveFactorBps: new BN(1000),
+        originationFeeBps: new BN(0),
+      }
+    );
 
-      wallet: provider.wallet as any,
-    });
-    // Create transaction builder
-    const transactionBuilder = pythSolanaReceiver.newTransactionBuilder({
-      closeUpdateAccounts: false,
-    });
-    // Add Both Price Updates To The Builder
-    await transactionBuilder.addPostPriceUpdates(allPriceUpdates);
+    const [interestTokenConfigPDA, interestTokenConfigBump] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_config"),
+          marketPDA.toBuffer(),
+          interestMint.toBuffer(),
+        ],
+        program.programId
+      );
 
-    // Add Borrow instruction to the same transaction
-    await transactionBuilder.addPriceConsumerInstructions(
-      async (getPriceUpdateAccount) => {
-        const borrowingPriceAccount = getPriceUpdateAccount(
-          usdcTokenPriceFeedIdHex
-        );
-        const collateralPriceAccount = getPriceUpdateAccount(
-          solMintPriceFeedIdHex
-        );
+    // Borrower3 Posts 100 Interest-Bearing Tokens As Obligation Collateral
+    const collateralAmount = new BN(100 * 10 ** 6);
+    const borrower3InterestATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      borrower3,
+      interestMint,
+      borrower3.publicKey,
+      false,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      borrower3,
+      interestMint,
+      borrower3InterestATA.address,
+      whitelister.publicKey,
+      collateralAmount.toNumber(),
+      [whitelister],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await program.methods
+      .depositCollateral(collateralAmount)
+      .accounts({
+        market: marketPDA,
+        borrower: borrower3.publicKey,
+        tokenCollateral: interestMint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        //@ts-ignore
+        solCollateralEscrow: null,
+      })
+      .signers([borrower3])
+      .rpc();
 
-        // Create your instruction with these accounts
-        const borrowTokenIx = await program.methods
-          .borrowToken(solMint, usdcTokenMint, new BN(200), borrowingLoanTerms)
+    const signatures = await sendWithPriceUpdates(
+      [usdcTokenPriceFeedIdHex],
+      async ([usdcPriceAccount]) => [
+        await program.methods
+          .borrowFromObligation(new BN(10 * 10 ** 6), {
+            interestRate: new BN(400),
+            lendingDuration: new BN(2592000),
+          })
           .accounts({
-            borrower: borrower1.publicKey,
+            market: marketPDA,
+            borrower: borrower3.publicKey,
             tokenToBorrow: usdcTokenMint,
-            tokenCollateral: solMint,
-            collateralPriceUpdate: collateralPriceAccount,
-            borrowingPriceUpdate: borrowingPriceAccount,
+            borrowingPriceUpdate: usdcPriceAccount,
             tokenProgram: TOKEN_PROGRAM_ID,
             //@ts-ignore
-            systemProgram: SystemProgram.programId,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            pythProgram: DEFAULT_RECEIVER_PROGRAM_ID,
+            isolatedCollateralEscrow: null,
           })
-          .signers([borrower1])
-          .instruction();
+          .remainingAccounts(
+            [interestTokenConfigPDA, usdcPriceAccount, interestMint].map(
+              (pubkey) => ({ pubkey, isSigner: false, isWritable: false })
+            )
+          )
+          .instruction(),
+      ],
+      [borrower3]
+    );
+    const borrowed = (
+      await emittedEvents(signatures[signatures.length - 1])
+    ).find((event) => event.name === "ObligationBorrowed").data;
 
-        return [
-          {
-            instruction: borrowTokenIx,
-            signers: [borrower1],
-          },
-        ];
-      }
+    // Token-2022 Scales The Mint By e^(rate x elapsed) Over A 365.24-Day Year
+    const interestConfig = getInterestBearingMintConfigState(
+      await getMint(
+        provider.connection,
+        interestMint,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    const elapsed =
+      borrowed.timestamp.toNumber() -
+      Number(interestConfig.initializationTimestamp);
+    const scale = Math.exp(
+      (interestRateBps * elapsed) / (10_000 * 31_556_736)
     );
 
-    // Build the transactions with explicit compute budget
-const transactions = await transactionBuilder.buildVersionedTransactions({
-  computeUnitPriceMicroLamports: 100000,  // Higher value from example
-  tightComputeBudget: true,               // Added from example
-});
-    // Send the transactions
-    try {
-  console.log(`Sending ${transactions.length} transaction(s)...`);
-  await pythSolanaReceiver.provider.sendAll(
-    transactions,
-    { skipPreflight: false }
-  );
-  console.log("Transactions sent successfully");
-} catch (error) {
-  console.error("Transaction error:", error);
-  if (error.logs) {
-    console.error("Transaction logs:", error.logs);
-  }
-  throw error;
-}
+    const rawPrice = borrowed.borrowingPrice.price;
+    const scaledPrice = borrowed.collateralPrices[0].price;
+    expect(scaledPrice.gt(rawPrice)).to.be.true;
+    expect(scaledPrice.toNumber()).to.be.closeTo(
+      Math.floor(rawPrice.toNumber() * scale),
+      1
+    );
+
+    // The Borrow Limit Is 80% Of The Collateral Valued At The Scaled Price; The Mint Has 6 Decimals Like USD Values
+    const borrowLimitAt = (price) =>
+      collateralAmount
+        .mul(price)
+        .div(
+          new BN(10).pow(new BN(-borrowed.collateralPrices[0].exponent))
+        )
+        .muln(8000)
+        .divn(10_000);
+    expect(borrowed.borrowLimit.toString()).to.eq(
+      borrowLimitAt(scaledPrice).toString()
+    );
+    expect(borrowed.borrowLimit.gt(borrowLimitAt(rawPrice))).to.be.true;
   });
 });